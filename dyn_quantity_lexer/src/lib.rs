@@ -27,11 +27,24 @@ impl Exponents {
 #[logos(extras = (usize, usize))]
 #[logos(skip r"[ \t\n\f]+")] // Ignore this regex pattern between tokens
 pub enum Token {
-    #[regex(r"(\d*)((\.)?\d+)", |lex| lex.slice().parse(), priority = 3)]
+    #[regex(r"\d[\d_]*(\.[\d_]*)?([eE][+-]?[\d_]+)?", parse_real, priority = 3)]
+    #[regex(r"\.\d[\d_]*([eE][+-]?[\d_]+)?", parse_real, priority = 3)]
+    #[regex(r"0[xX][0-9a-fA-F_]+", |lex| parse_radix(lex, 16))]
+    #[regex(r"0[bB][0-9_]+", |lex| parse_radix(lex, 2))]
+    #[token("NaN", |_| f64::NAN)]
+    #[token("nan", |_| f64::NAN)]
+    #[token("NAN", |_| f64::NAN)]
+    #[token(".nan", |_| f64::NAN)]
+    #[token(".NaN", |_| f64::NAN)]
+    #[token(".NAN", |_| f64::NAN)]
     Real(f64),
 
-    #[regex(r"(\d*)(\.\d+)? ?i", |lex| parse_imag(lex), priority = 4)]
-    #[regex(r"(\d*)(\.\d+)? ?j", |lex| parse_imag(lex), priority = 4)]
+    // The space is only allowed after a number, otherwise e.g. "2 + inf" would
+    // be lexed into "2", "+", " i" and "nf".
+    #[regex(r"(\d+|\d*\.\d+) ?i", |lex| parse_imag(lex), priority = 4)]
+    #[regex(r"(\d+|\d*\.\d+) ?j", |lex| parse_imag(lex), priority = 4)]
+    #[token("i", |_| 1.0)]
+    #[token("j", |_| 1.0)]
     Imag(f64),
 
     #[regex(r"\* ?10\^-?\d+", |lex| parse_power_of_ten(lex))]
@@ -82,6 +95,9 @@ pub enum Token {
     #[token("%")]
     Percent,
 
+    #[token("^")]
+    Pow,
+
     #[regex(r"[a-zA-Zµ]?s", |lex| parse_exponents_and_prefix(lex, "s"))]
     #[regex(r"[a-zA-Zµ]?s\^-?\d+", |lex| parse_exponents_and_prefix(lex, "s"))]
     Second(Exponents),
@@ -134,6 +150,10 @@ pub enum Token {
     #[regex(r"[a-zA-Zµ]?J\^-?\d+", |lex| parse_exponents_and_prefix(lex, "J"))]
     Joule(Exponents),
 
+    #[regex(r"[a-zA-Zµ]?Pa", |lex| parse_exponents_and_prefix(lex, "Pa"))]
+    #[regex(r"[a-zA-Zµ]?Pa\^-?\d+", |lex| parse_exponents_and_prefix(lex, "Pa"))]
+    Pascal(Exponents),
+
     #[regex(r"[a-zA-Zµ]?Hz", |lex| parse_exponents_and_prefix(lex, "Hz"))]
     #[regex(r"[a-zA-Zµ]?Hz\^-?\d+", |lex| parse_exponents_and_prefix(lex, "Hz"))]
     Hertz(Exponents),
@@ -158,6 +178,14 @@ pub enum Token {
     #[regex(r"[a-zA-Zµ]?S\^-?\d+", |lex| parse_exponents_and_prefix(lex, "S"))]
     Siemens(Exponents),
 
+    #[regex(r"[a-zA-Zµ]?C", |lex| parse_exponents_and_prefix(lex, "C"))]
+    #[regex(r"[a-zA-Zµ]?C\^-?\d+", |lex| parse_exponents_and_prefix(lex, "C"))]
+    Coulomb(Exponents),
+
+    #[regex(r"[a-zA-Zµ]?F", |lex| parse_exponents_and_prefix(lex, "F"))]
+    #[regex(r"[a-zA-Zµ]?F\^-?\d+", |lex| parse_exponents_and_prefix(lex, "F"))]
+    Farad(Exponents),
+
     #[regex(r"[a-zA-Zµ]?t", |lex| parse_exponents_and_prefix(lex, "t"))]
     #[regex(r"[a-zA-Zµ]?t\^-?\d+", |lex| parse_exponents_and_prefix(lex, "t"))]
     Ton(Exponents),
@@ -172,6 +200,30 @@ pub enum Token {
     #[regex(r"[a-zA-Zµ]?Ω\^-?\d+", |lex| parse_exponents_and_prefix(lex, "Ω"))]
     Omega(Exponents),
 
+    #[regex(r"min", |lex| parse_exponents_and_prefix(lex, "min"))]
+    #[regex(r"min\^-?\d+", |lex| parse_exponents_and_prefix(lex, "min"))]
+    Minute(Exponents),
+
+    #[regex(r"h", |lex| parse_exponents_and_prefix(lex, "h"))]
+    #[regex(r"h\^-?\d+", |lex| parse_exponents_and_prefix(lex, "h"))]
+    Hour(Exponents),
+
+    #[regex(r"d", |lex| parse_exponents_and_prefix(lex, "d"))]
+    #[regex(r"d\^-?\d+", |lex| parse_exponents_and_prefix(lex, "d"))]
+    Day(Exponents),
+
+    #[regex(r"ft", |lex| parse_exponents_and_prefix(lex, "ft"))]
+    #[regex(r"ft\^-?\d+", |lex| parse_exponents_and_prefix(lex, "ft"))]
+    Foot(Exponents),
+
+    #[regex(r"yd", |lex| parse_exponents_and_prefix(lex, "yd"))]
+    #[regex(r"yd\^-?\d+", |lex| parse_exponents_and_prefix(lex, "yd"))]
+    Yard(Exponents),
+
+    #[regex(r"in", |lex| parse_exponents_and_prefix(lex, "in"))]
+    #[regex(r"in\^-?\d+", |lex| parse_exponents_and_prefix(lex, "in"))]
+    Inch(Exponents),
+
     #[regex(r"[a-zA-Zµ]?(pi|π|PI|Pi)", |lex| parse_pi(lex), priority = 2) ]
     #[regex(r"[a-zA-Zµ]?(pi|π|PI|Pi)\^-?\d+", |lex| parse_pi(lex), priority = 3)]
     Pi(Exponents),
@@ -180,33 +232,114 @@ pub enum Token {
     #[regex(r"[a-zA-Zµ]?(degree|°|Degree|deg|Deg)\^-?\d+", |lex| parse_degree(lex), priority = 3)]
     Degree(Exponents),
 
+    #[regex(r"('|′)", |lex| parse_exponents_and_prefix(lex, "'"))]
+    #[regex(r"('|′)\^-?\d+", |lex| parse_exponents_and_prefix(lex, "'"))]
+    ArcMinute(Exponents),
+
+    #[regex(r#"("|″)"#, |lex| parse_exponents_and_prefix(lex, "\""))]
+    #[regex(r#"("|″)\^-?\d+"#, |lex| parse_exponents_and_prefix(lex, "\""))]
+    ArcSecond(Exponents),
+
     #[regex(r"[a-zA-Zµ]?(rad|radians|Rad|Radians)", |lex| parse_radians(lex), priority = 2) ]
     #[regex(r"[a-zA-Zµ]?(rad|radians|Rad|Radians)\^-?\d+", |lex| parse_radians(lex), priority = 3)]
     Radians(Exponents),
 }
 
+/**
+Splits `input` into its tokens together with their spans, like
+`Token::lexer(input).spanned()`. In addition, an imaginary number whose
+imaginary unit starts a longer token (e.g. `3 in` or `2 inf`) is split into a
+real number and this token. Otherwise, `3 in` would be lexed as `3 i` followed
+by `n`, since the lexer always prefers the longest match. Imaginary numbers
+followed by any other word (e.g. `3 iV`) are not affected.
+ */
+pub fn lex(input: &str) -> Vec<(Result<Token, LexingError>, Span)> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    'lexer: loop {
+        for (token, span) in Token::lexer(&input[offset..]).spanned() {
+            let span = span.start + offset..span.end + offset;
+            let slice = &input[span.clone()];
+            if matches!(token, Ok(Token::Imag(_)))
+                && slice.len() > 1
+                && starts_longer_token(&input[span.end - 1..])
+            {
+                let number = slice[..slice.len() - 1].trim_end();
+                let real = number
+                    .parse()
+                    .map(Token::Real)
+                    .map_err(|_| LexingError::InvalidFloat(number.to_owned()));
+                tokens.push((real, span.start..span.start + number.len()));
+                offset = span.end - 1;
+                continue 'lexer;
+            }
+            tokens.push((token, span));
+        }
+        return tokens;
+    }
+}
+
+/**
+Checks whether the imaginary unit at the start of `input` is part of a longer
+token such as `in` or `inf`.
+ */
+fn starts_longer_token(input: &str) -> bool {
+    return match Token::lexer(input).spanned().next() {
+        Some((Ok(_), span)) => span.len() > 1,
+        _ => false,
+    };
+}
+
 fn parse_imag(lex: &mut Lexer<Token>) -> Option<f64> {
     // An imaginary number is a number followed by (possibly) a space and then either an "i" or an "j".
     // Since we're interested in the number, the space and the "i" or "j" need to be filtered out.
     let slice = lex.slice();
     match slice.find(' ') {
-        Some(byte_offset) => {
-            if byte_offset == 0 {
-                return Some(1.0);
-            } else {
-                return slice[..byte_offset].parse().ok();
-            }
-        }
+        Some(byte_offset) => return slice[..byte_offset].parse().ok(),
         None => {
-            if slice.len() == 1 {
-                return Some(1.0);
-            } else {
-                // If no space is in the string, we just need to remove the last byte (which is the i or the j)
-                let bytes_number = slice.len() - 1;
-                return slice[..bytes_number].parse().ok();
-            }
+            // If no space is in the string, we just need to remove the last byte (which is the i or the j)
+            let bytes_number = slice.len() - 1;
+            return slice[..bytes_number].parse().ok();
+        }
+    }
+}
+
+fn parse_real(lex: &mut Lexer<Token>) -> Result<f64, LexingError> {
+    // Digit separators are removed before parsing the number, so that e.g.
+    // "1_000.5" is parsed with full precision.
+    let slice = lex.slice();
+    let invalid = || LexingError::InvalidFloat(slice.to_owned());
+    let number = remove_digit_separators(slice, 10).ok_or_else(invalid)?;
+    return number.parse().map_err(|_| invalid());
+}
+
+fn parse_radix(lex: &mut Lexer<Token>, radix: u32) -> Result<f64, LexingError> {
+    // Ignore the prefix "0x" or "0b"
+    let slice = lex.slice();
+    let invalid = || LexingError::InvalidInt(slice.to_owned());
+    let digits = remove_digit_separators(&slice[2..], radix).ok_or_else(invalid)?;
+    let value = u128::from_str_radix(&digits, radix).map_err(|_| invalid())?;
+    return Ok(value as f64);
+}
+
+/**
+Removes the digit separators `_` from `number`. Returns `None` if a separator
+is not placed between two digits of the given `radix` (e.g. in "1__000", "1_"
+or "1_.5").
+ */
+fn remove_digit_separators(number: &str, radix: u32) -> Option<String> {
+    let chars: Vec<char> = number.chars().collect();
+    for (index, c) in chars.iter().enumerate() {
+        if *c != '_' {
+            continue;
+        }
+        let before = index > 0 && chars[index - 1].is_digit(radix);
+        let after = chars.get(index + 1).is_some_and(|c| c.is_digit(radix));
+        if !(before && after) {
+            return None;
         }
     }
+    return Some(number.replace('_', ""));
 }
 
 fn parse_power_of_ten(lex: &mut Lexer<Token>) -> Option<i32> {
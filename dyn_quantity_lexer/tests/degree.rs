@@ -97,3 +97,47 @@ fn test_parse_rad() {
         );
     }
 }
+
+#[test]
+fn test_parse_dms() {
+    {
+        let mut lex = Token::lexer("12°30'15.5\"");
+
+        assert_eq!(lex.next(), Some(Ok(Token::Real(12.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Degree(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(30.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::ArcMinute(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(15.5))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::ArcSecond(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+    {
+        let mut lex = Token::lexer("45° 30.25′ 2″");
+
+        assert_eq!(lex.next(), Some(Ok(Token::Real(45.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Degree(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(30.25))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::ArcMinute(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::ArcSecond(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+}
@@ -288,6 +288,46 @@ fn test_parse_torque() {
     }
 }
 
+#[test]
+fn test_parse_pressure_charge_capacitance() {
+    {
+        let mut lex = Token::lexer("3 kPa 2 mC^2 4.7 µF");
+        assert_eq!(lex.next(), Some(Ok(Token::Real(3.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Pascal(Exponents { unit: 1, prefix: 3 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Coulomb(Exponents {
+                unit: 2,
+                prefix: -3
+            })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(4.7))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Farad(Exponents {
+                unit: 1,
+                prefix: -6
+            })))
+        );
+    }
+    {
+        // "C" must not swallow the Celsius symbol
+        let mut lex = Token::lexer("Pa °C");
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Pascal(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Celsius(Exponents { unit: 1, prefix: 0 })))
+        );
+    }
+}
+
 #[test]
 fn test_parse_greek_unit() {
     {
@@ -395,6 +435,78 @@ fn test_parse_power_of_10() {
     }
 }
 
+#[test]
+fn test_parse_numeric_literals() {
+    for (input, value) in [
+        ("1.5E3", 1.5e3),
+        ("1.5e-3", 1.5e-3),
+        ("2e+3", 2e3),
+        ("0.1e-3", 0.1e-3),
+        ("1_000_000", 1e6),
+        ("1_000.000_5", 1000.0005),
+        ("5.", 5.0),
+        ("5.e2", 500.0),
+        (".5E1", 5.0),
+        ("0x1F", 31.0),
+        ("0XfF_fF", 65535.0),
+        ("0b1010", 10.0),
+        ("0B1111_0000", 240.0),
+    ] {
+        let mut lex = Token::lexer(input);
+        assert_eq!(lex.next(), Some(Ok(Token::Real(value))), "{input}");
+        assert_eq!(lex.next(), None, "{input}");
+    }
+    for input in ["NaN", "nan", "NAN", ".nan", ".NaN", ".NAN"] {
+        let mut lex = Token::lexer(input);
+        assert!(matches!(lex.next(), Some(Ok(Token::Real(value))) if value.is_nan()));
+        assert_eq!(lex.next(), None, "{input}");
+    }
+    {
+        let mut lex = Token::lexer("2e-3 m");
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2e-3))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Meter(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+    {
+        // Exa prefix instead of exponent
+        let mut lex = Token::lexer("2Em");
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Meter(Exponents {
+                unit: 1,
+                prefix: 18
+            })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+}
+
+#[test]
+fn test_parse_malformed_numbers() {
+    for input in ["1__000", "1_", "1_.5", "1._5", "1e_3", "1e3_"] {
+        let mut lex = Token::lexer(input);
+        assert_eq!(
+            lex.next(),
+            Some(Err(LexingError::InvalidFloat(input.to_owned()))),
+            "{input}"
+        );
+        assert_eq!(lex.next(), None, "{input}");
+    }
+    for input in ["0b102", "0x_1", "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"] {
+        let mut lex = Token::lexer(input);
+        assert_eq!(
+            lex.next(),
+            Some(Err(LexingError::InvalidInt(input.to_owned()))),
+            "{input}"
+        );
+        assert_eq!(lex.next(), None, "{input}");
+    }
+}
+
 #[test]
 fn test_parse_infinite() {
     {
@@ -439,4 +551,120 @@ fn test_parse_infinite() {
         );
         assert_eq!(lex.next(), None);
     }
+    {
+        // The space in front of "inf" must not be lexed as part of an imaginary unit
+        let mut lex = Token::lexer("2 + inf i");
+
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(lex.next(), Some(Ok(Token::Add)));
+        assert_eq!(lex.next(), Some(Ok(Token::Infinity)));
+        assert_eq!(lex.next(), Some(Ok(Token::Imag(1.0))));
+        assert_eq!(lex.next(), None);
+    }
+}
+
+#[test]
+fn test_parse_non_si_units() {
+    {
+        let mut lex = Token::lexer("1 h 30 min 2 d");
+
+        assert_eq!(lex.next(), Some(Ok(Token::Real(1.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Hour(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(30.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Minute(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Day(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+    {
+        let mut lex = Token::lexer("ft^2 yd");
+
+        // "ft" must not be interpreted as femtotonne
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Foot(Exponents { unit: 2, prefix: 0 })))
+        );
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Yard(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+    {
+        let mut lex = Token::lexer("in in^2");
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Inch(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Inch(Exponents { unit: 2, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), None);
+    }
+}
+
+#[test]
+fn test_lex_imaginary_unit_followed_by_letter() {
+    // The lexer alone interprets "3 i" as imaginary number
+    let mut lexer = Token::lexer("3 in");
+    assert_eq!(lexer.next(), Some(Ok(Token::Imag(3.0))));
+    assert_eq!(lexer.next(), Some(Err(LexingError::CouldNotParse)));
+
+    let tokens = lex("5 ft 3 in");
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[2], (Ok(Token::Real(3.0)), 5..6));
+    assert_eq!(
+        tokens[3],
+        (Ok(Token::Inch(Exponents { unit: 1, prefix: 0 })), 7..9)
+    );
+
+    let tokens = lex("2.5inf");
+    assert_eq!(tokens[0], (Ok(Token::Real(2.5)), 0..3));
+    assert_eq!(tokens[1], (Ok(Token::Infinity), 3..6));
+
+    // Imaginary numbers are not affected
+    assert_eq!(lex("3 i V")[0], (Ok(Token::Imag(3.0)), 0..3));
+    assert_eq!(lex("3 i")[0], (Ok(Token::Imag(3.0)), 0..3));
+    assert_eq!(lex("3 iV")[0], (Ok(Token::Imag(3.0)), 0..3));
+}
+
+#[test]
+fn test_parse_pow() {
+    {
+        let mut lex = Token::lexer("2^-10");
+
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(lex.next(), Some(Ok(Token::Pow)));
+        assert_eq!(lex.next(), Some(Ok(Token::Sub)));
+        assert_eq!(lex.next(), Some(Ok(Token::Real(10.0))));
+        assert_eq!(lex.next(), None);
+    }
+    {
+        let mut lex = Token::lexer("(2 m)^(1/2)");
+
+        assert_eq!(lex.next(), Some(Ok(Token::LeftBracket)));
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(
+            lex.next(),
+            Some(Ok(Token::Meter(Exponents { unit: 1, prefix: 0 })))
+        );
+        assert_eq!(lex.next(), Some(Ok(Token::RightBracket(1))));
+        assert_eq!(lex.next(), Some(Ok(Token::Pow)));
+        assert_eq!(lex.next(), Some(Ok(Token::LeftBracket)));
+        assert_eq!(lex.next(), Some(Ok(Token::Real(1.0))));
+        assert_eq!(lex.next(), Some(Ok(Token::Div)));
+        assert_eq!(lex.next(), Some(Ok(Token::Real(2.0))));
+        assert_eq!(lex.next(), Some(Ok(Token::RightBracket(1))));
+        assert_eq!(lex.next(), None);
+    }
 }
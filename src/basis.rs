@@ -0,0 +1,149 @@
+/*!
+This module contains the [`UnitBasis`] struct, which expresses an [`Unit`] as a
+product of powers of user-chosen basis units instead of the SI base units. For
+example, electrical engineers often prefer the basis `V`, `A`, `s` and `m`,
+where a mass is written as `V A s^3 m^-2` and a power as `V A`.
+
+The exponents are calculated exactly via integer linear algebra and can be
+fractional, e.g. a length is `(m^2)^(1/2)` in a basis consisting of an area.
+
+# Examples
+
+```
+use num::rational::Ratio;
+use dyn_quantity::{BasisError, NamedUnit, PredefUnit, UnitBasis};
+
+let basis = UnitBasis::new([
+    NamedUnit::Volt.unit(),
+    PredefUnit::ElectricCurrent.into(),
+    PredefUnit::Time.into(),
+    PredefUnit::Length.into(),
+]).expect("basis units are independent");
+
+// Power = V A
+let exponents = basis.express(PredefUnit::Power).unwrap();
+assert_eq!(exponents, [1, 1, 0, 0].map(Ratio::from_integer).to_vec());
+
+// Mass = V A s^3 m^-2
+let exponents = basis.express(PredefUnit::Mass).unwrap();
+assert_eq!(exponents, [1, 1, 3, -2].map(Ratio::from_integer).to_vec());
+
+// A temperature cannot be expressed in this basis
+assert!(matches!(
+    basis.express(PredefUnit::Temperature),
+    Err(BasisError::NotRepresentable { .. })
+));
+```
+*/
+
+use num::rational::Ratio;
+
+use crate::error::BasisError;
+use crate::linalg::{exponent_matrix, row_reduce};
+use crate::unit::Unit;
+
+/**
+A list of linearly independent units which serves as the basis for expressing
+other units, see the [module docs](crate::basis). The units are linearly
+independent if none of them can be written as a product of powers of the
+others, e.g. `V`, `A` and `W` are not independent since `W = V A`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitBasis {
+    units: Vec<Unit>,
+}
+
+impl UnitBasis {
+    /**
+    Returns a new basis consisting of `units`. Returns
+    [`BasisError::LinearlyDependent`] if one of the units is a product of
+    powers of the preceding units (this includes dimensionless units) and
+    [`BasisError::ExponentOutOfRange`] if the exponents of the units are too
+    large for the integer arithmetic.
+
+    # Examples
+    ```
+    use dyn_quantity::{BasisError, NamedUnit, UnitBasis};
+
+    assert!(UnitBasis::new([NamedUnit::Volt.unit(), NamedUnit::Ohm.unit()]).is_ok());
+
+    let error = UnitBasis::new([
+        NamedUnit::Volt.unit(),
+        NamedUnit::Ohm.unit(),
+        NamedUnit::Siemens.unit(),
+    ])
+    .unwrap_err();
+    assert_eq!(
+        error,
+        BasisError::LinearlyDependent {
+            index: 2,
+            unit: NamedUnit::Siemens.unit()
+        }
+    );
+    ```
+     */
+    pub fn new<U: Into<Unit>, I: IntoIterator<Item = U>>(units: I) -> Result<Self, BasisError> {
+        let units: Vec<Unit> = units.into_iter().map(Into::into).collect();
+        let mut rows = exponent_matrix(&units);
+        let pivots = row_reduce(&mut rows, units.len()).ok_or(BasisError::ExponentOutOfRange)?;
+        if let Some(index) = (0..units.len()).find(|index| !pivots.contains(index)) {
+            return Err(BasisError::LinearlyDependent {
+                index,
+                unit: units[index],
+            });
+        }
+        return Ok(Self { units });
+    }
+
+    /// Returns the units of the basis.
+    pub fn units(&self) -> &[Unit] {
+        return &self.units;
+    }
+
+    /**
+    Returns the exponents of the basis units (in the order of
+    [`UnitBasis::units`]) whose product equals `unit`. Returns
+    [`BasisError::NotRepresentable`] if no such product exists and
+    [`BasisError::ExponentOutOfRange`] if an exponent cannot be represented by
+    a [`Ratio<i32>`] or is too large for the integer arithmetic.
+
+    # Examples
+    ```
+    use num::rational::Ratio;
+    use dyn_quantity::{PredefUnit, UnitBasis};
+
+    let basis = UnitBasis::new([PredefUnit::Area, PredefUnit::Time]).unwrap();
+    let exponents = basis.express(PredefUnit::Velocity).unwrap();
+    assert_eq!(exponents, vec![Ratio::new(1, 2), Ratio::from_integer(-1)]);
+    ```
+     */
+    pub fn express<U: Into<Unit>>(&self, unit: U) -> Result<Vec<Ratio<i32>>, BasisError> {
+        let unit = unit.into();
+        let mut columns = self.units.clone();
+        columns.push(unit);
+        let mut rows = exponent_matrix(&columns);
+        let pivots =
+            row_reduce(&mut rows, self.units.len()).ok_or(BasisError::ExponentOutOfRange)?;
+
+        // The remaining rows must be zero, otherwise the system is inconsistent
+        let last = self.units.len();
+        if rows[pivots.len()..].iter().any(|row| row[last] != 0) {
+            return Err(BasisError::NotRepresentable { unit });
+        }
+
+        // Since the basis units are linearly independent, each of them has a
+        // pivot and the pivot of the ith basis unit is in the ith row.
+        let mut exponents = Vec::with_capacity(pivots.len());
+        for (row, pivot) in rows.iter().zip(pivots) {
+            let exponent = Ratio::new(row[last], row[pivot]);
+            let (Ok(numer), Ok(denom)) = (
+                i32::try_from(*exponent.numer()),
+                i32::try_from(*exponent.denom()),
+            ) else {
+                return Err(BasisError::ExponentOutOfRange);
+            };
+            exponents.push(Ratio::new_raw(numer, denom));
+        }
+        return Ok(exponents);
+    }
+}
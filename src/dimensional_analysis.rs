@@ -0,0 +1,208 @@
+/*!
+This module implements dimensional analysis according to the
+[Buckingham π theorem](https://en.wikipedia.org/wiki/Buckingham_%CF%80_theorem):
+Given a list of named variables with their [`Unit`]s, [`pi_groups`] returns a
+basis of independent dimensionless products of powers of these variables (the
+π-groups), e.g. the Reynolds number for density, velocity, length and dynamic
+viscosity. Each [`PiGroup`] is represented by the exponents of the variables
+and can be evaluated for concrete [`DynQuantity`] values.
+
+The number of π-groups equals the number of variables minus the number of
+independent units among them. Going through the variables in the given order,
+each variable whose unit is independent of the preceding ones is used as a
+"repeating variable". Each remaining variable yields one π-group, in which it
+has a positive exponent. Hence, the order of the variables determines which
+of the (infinitely many) equivalent sets of π-groups is returned.
+
+# Examples
+
+```
+use dyn_quantity::{DynQuantity, PredefUnit};
+use dyn_quantity::dimensional_analysis::pi_groups;
+
+let variables = [
+    ("mu", DynQuantity::new(1e-3, PredefUnit::DynamicViscosity)),
+    ("rho", DynQuantity::new(1000.0, PredefUnit::MassDensity)),
+    ("v", DynQuantity::new(2.0, PredefUnit::Velocity)),
+    ("L", DynQuantity::new(0.1, PredefUnit::Length)),
+];
+
+// The Reynolds number is the only π-group
+let groups = pi_groups(&variables).unwrap();
+assert_eq!(groups.len(), 1);
+assert_eq!(groups[0].to_string(), "mu^-1 rho v L");
+
+let reynolds = groups[0].evaluate(&variables).expect("all variables are given");
+assert!((reynolds - 2e5).abs() < 1e-6);
+```
+*/
+
+use crate::error::ConversionError;
+use crate::linalg::{exponent_matrix, null_space, row_reduce};
+use crate::quantity::{DynQuantity, F64RealOrComplex};
+use crate::unit::Unit;
+
+/**
+A dimensionless product of powers of variables, see the
+[module docs](crate::dimensional_analysis).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiGroup {
+    /**
+    Names of the variables and their exponents within the product. Variables
+    with an exponent of zero are omitted; the order is the one of the
+    variables given to [`pi_groups`].
+     */
+    pub exponents: Vec<(String, i32)>,
+}
+
+impl PiGroup {
+    /**
+    Returns the exponent of the variable `name` (zero if the variable is not
+    part of the group).
+     */
+    pub fn exponent(&self, name: &str) -> i32 {
+        return self
+            .exponents
+            .iter()
+            .find(|(variable, _)| variable == name)
+            .map(|(_, exponent)| *exponent)
+            .unwrap_or(0);
+    }
+
+    /**
+    Evaluates the π-group for the given variable values. Returns
+    [`ConversionError::Custom`] if a variable of the group is missing in
+    `variables` and [`ConversionError::UnitMismatch`] if the product is not
+    dimensionless (which happens if the units of `variables` differ from the
+    ones used to calculate the group).
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit};
+    use dyn_quantity::dimensional_analysis::pi_groups;
+
+    let groups = pi_groups(&[
+        ("rho", PredefUnit::MassDensity),
+        ("v", PredefUnit::Velocity),
+        ("A", PredefUnit::Area),
+        ("F", PredefUnit::Force),
+    ])
+    .unwrap();
+    assert_eq!(groups[0].to_string(), "rho^-1 v^-2 A^-1 F");
+
+    // Drag coefficient (without the factor 2)
+    let value = groups[0].evaluate(&[
+        ("rho", DynQuantity::new(1.25, PredefUnit::MassDensity)),
+        ("v", DynQuantity::new(2.0, PredefUnit::Velocity)),
+        ("A", DynQuantity::new(0.5, PredefUnit::Area)),
+        ("F", DynQuantity::new(10.0, PredefUnit::Force)),
+    ]).unwrap();
+    assert_eq!(value, 4.0);
+
+    assert!(groups[0].evaluate(&[("F", DynQuantity::new(10.0, PredefUnit::Force))]).is_err());
+    ```
+     */
+    pub fn evaluate<S: AsRef<str>, V: F64RealOrComplex>(
+        &self,
+        variables: &[(S, DynQuantity<V>)],
+    ) -> Result<V, ConversionError> {
+        let mut product = DynQuantity::new(V::from_f64(1.0), Unit::default());
+        for (name, exponent) in self.exponents.iter() {
+            let Some((_, quantity)) = variables
+                .iter()
+                .find(|(variable, _)| variable.as_ref() == name)
+            else {
+                return Err(ConversionError::custom(format!(
+                    "value of variable {name} is missing"
+                )));
+            };
+            product *= quantity.powi(*exponent);
+        }
+        if !product.unit.is_dimensionless() {
+            return Err(ConversionError::UnitMismatch {
+                expected: Unit::default(),
+                found: product.unit,
+            });
+        }
+        return Ok(product.value);
+    }
+}
+
+impl std::fmt::Display for PiGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (name, exponent)) in self.exponents.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            if *exponent == 1 {
+                write!(f, "{name}")?;
+            } else {
+                write!(f, "{name}^{exponent}")?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+/**
+Returns a basis of independent dimensionless products of powers of the given
+`variables` (pairs of a name and anything convertible into an [`Unit`], such as
+a [`DynQuantity`] or a [`PredefUnit`](crate::PredefUnit)). See the
+[module docs](crate::dimensional_analysis) for details and examples. Returns
+[`ConversionError::Custom`] if an exponent of a π-group does not fit into an
+[`i32`] or an intermediate result overflows, which can only happen for units
+with very large exponents.
+
+# Examples
+```
+use dyn_quantity::PredefUnit;
+use dyn_quantity::dimensional_analysis::pi_groups;
+
+// Pipe flow: pressure drop, density, velocity, diameter, viscosity and length
+let groups = pi_groups(&[
+    ("rho", PredefUnit::MassDensity),
+    ("v", PredefUnit::Velocity),
+    ("d", PredefUnit::Length),
+    ("dp", PredefUnit::Pressure),
+    ("mu", PredefUnit::DynamicViscosity),
+    ("l", PredefUnit::Length),
+])
+.unwrap();
+let groups: Vec<String> = groups.iter().map(ToString::to_string).collect();
+assert_eq!(groups, ["rho^-1 v^-2 dp", "rho^-1 v^-1 d^-1 mu", "d^-1 l"]);
+```
+ */
+pub fn pi_groups<S: AsRef<str>, U: Into<Unit> + Clone>(
+    variables: &[(S, U)],
+) -> Result<Vec<PiGroup>, ConversionError> {
+    let units: Vec<Unit> = variables
+        .iter()
+        .map(|(_, unit)| unit.clone().into())
+        .collect();
+    let overflow = || {
+        return ConversionError::custom(
+            "exponents of the variables are out of range of the integer arithmetic",
+        );
+    };
+    let mut rows = exponent_matrix(&units);
+    let pivots = row_reduce(&mut rows, units.len()).ok_or_else(overflow)?;
+    let mut groups = Vec::new();
+    for vector in null_space(&rows, units.len(), &pivots).ok_or_else(overflow)? {
+        let mut exponents = Vec::new();
+        for ((name, _), exponent) in variables.iter().zip(vector) {
+            if exponent == 0 {
+                continue;
+            }
+            let Ok(exponent) = i32::try_from(exponent) else {
+                return Err(ConversionError::custom(format!(
+                    "exponent {exponent} of variable {} does not fit into i32",
+                    name.as_ref()
+                )));
+            };
+            exponents.push((name.as_ref().to_owned(), exponent));
+        }
+        groups.push(PiGroup { exponents });
+    }
+    return Ok(groups);
+}
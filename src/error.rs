@@ -8,7 +8,7 @@ use std::fmt::Display;
 
 use num::Complex;
 
-use crate::Unit;
+use crate::{PredefUnit, Unit};
 
 /**
 Error representing unequality of units.
@@ -29,6 +29,104 @@ impl Display for UnitsNotEqual {
 
 impl Error for UnitsNotEqual {}
 
+/**
+Error representing unequality of quantity kinds.
+
+Quantities such as torque and energy have identical units, but are
+nevertheless different kinds of quantities which must not be added. If both
+quantities involved in an operation are tagged with a kind (see
+[`DynQuantity::kind`](crate::DynQuantity::kind)), this struct holds both
+kinds for further inspection.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindsNotEqual(pub PredefUnit, pub PredefUnit);
+
+impl Display for KindsNotEqual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "kind {} not equal to kind {}",
+            self.0.name(),
+            self.1.name()
+        )
+    }
+}
+
+impl Error for KindsNotEqual {}
+
+/**
+Error returned by the fallible addition and subtraction of two
+[`DynQuantity`](crate::DynQuantity)s, e.g. via
+[`DynQuantity::try_add`](crate::DynQuantity::try_add).
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdditionError {
+    /// See docstring of [`UnitsNotEqual`].
+    UnitsNotEqual(UnitsNotEqual),
+    /// See docstring of [`KindsNotEqual`].
+    KindsNotEqual(KindsNotEqual),
+}
+
+impl Display for AdditionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdditionError::UnitsNotEqual(err) => err.fmt(f),
+            AdditionError::KindsNotEqual(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<UnitsNotEqual> for AdditionError {
+    fn from(value: UnitsNotEqual) -> Self {
+        return Self::UnitsNotEqual(value);
+    }
+}
+
+impl From<KindsNotEqual> for AdditionError {
+    fn from(value: KindsNotEqual) -> Self {
+        return Self::KindsNotEqual(value);
+    }
+}
+
+impl Error for AdditionError {}
+
+/**
+Error returned by the constructors of [`QuantityRange`](crate::QuantityRange),
+e.g. [`QuantityRange::new`](crate::QuantityRange::new).
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeError {
+    /// The units or the kinds of the quantities are not identical.
+    AdditionError(AdditionError),
+    /// The nominal value or one of the bounds is NaN.
+    NotANumber,
+    /// The nominal value does not lie between the lower and the upper bound.
+    NominalOutOfBounds,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::AdditionError(err) => err.fmt(f),
+            RangeError::NotANumber => write!(f, "nominal value and bounds must not be NaN"),
+            RangeError::NominalOutOfBounds => {
+                write!(
+                    f,
+                    "nominal value must lie between the lower and the upper bound"
+                )
+            }
+        }
+    }
+}
+
+impl From<AdditionError> for RangeError {
+    fn from(value: AdditionError) -> Self {
+        return Self::AdditionError(value);
+    }
+}
+
+impl Error for RangeError {}
+
 /**
 Error representing a failed attempt to calculate the `n`th root of an [`Unit`].
 
@@ -117,8 +215,61 @@ pub enum ParseErrorReason {
     string, e.g. "3 A + 2 V".
      */
     UnitsNotEqual(UnitsNotEqual),
+    /**
+    An addition / subtraction of two quantities of different kinds, see
+    [`KindsNotEqual`].
+     */
+    KindsNotEqual(KindsNotEqual),
     /// See docstring of [`NotConvertibleFromComplexF64`].
     NotConvertibleFromComplexF64(NotConvertibleFromComplexF64),
+    /**
+    The parts of a quantity in compound notation are not given in descending
+    size, e.g. "30 min 1 h". See
+    [`DynQuantity::from_str_compound`](crate::quantity::DynQuantity::from_str_compound).
+     */
+    CompoundNotDescending,
+    /**
+    The exponent following the exponentiation symbol `^` is neither a number
+    nor a dimensionless, real expression in brackets (e.g. "2^V" or
+    "2^(1 V)"), it cannot be converted into a fraction (e.g. "2^(1/0)") or the
+    exponents of the resulting unit overflow (e.g. "(m^50000)^50000").
+     */
+    InvalidExponent,
+    /**
+    The unit of a quantity cannot be raised to a non-integer power, e.g.
+    "m^(1/2)". See docstring of [`RootError`].
+     */
+    RootError(RootError),
+    /**
+    A string which is parsed into an [`Unit`] contains a scale factor, e.g.
+    "mm" or "2 m". See the [`FromStr`](std::str::FromStr) implementation of
+    [`Unit`].
+     */
+    UnitWithScaleFactor,
+    /**
+    An identifier in an equation is neither a declared variable nor a unit,
+    e.g. `x` in "y = 2 x" if only `y` is declared. See
+    [`check_equation`](crate::quantity::equation::check_equation).
+     */
+    UnknownIdentifier,
+    /**
+    A string which is parsed as an equation does not contain exactly one
+    equals sign, e.g. "F - m a" or "a = b = c". See
+    [`check_equation`](crate::quantity::equation::check_equation).
+     */
+    NotAnEquation,
+    /**
+    The unit of the unknown variable of an equation cannot be determined,
+    e.g. because it does not appear in the equation or cancels out as in
+    "y = x / x". See [`solve_unit`](crate::quantity::equation::solve_unit).
+     */
+    UndeterminedUnit,
+    /**
+    A number literal is malformed, e.g. "1__0", "1_" or "0b102". The inner
+    [`LexingError`](dyn_quantity_lexer::LexingError) describes the literal.
+     */
+    #[cfg(feature = "from_str")]
+    InvalidNumber(dyn_quantity_lexer::LexingError),
     /// Generic fallback error for all other parsing failures
     #[default]
     CouldNotParse,
@@ -150,10 +301,41 @@ impl std::fmt::Display for ParseErrorReason {
                 )
             }
             ParseErrorReason::UnitsNotEqual(inner) => inner.fmt(f),
+            ParseErrorReason::KindsNotEqual(inner) => inner.fmt(f),
             ParseErrorReason::MustNotStartWith => {
                 write!(f, "input must not start with this token")
             }
             ParseErrorReason::NotConvertibleFromComplexF64(err) => err.fmt(f),
+            ParseErrorReason::CompoundNotDescending => {
+                write!(
+                    f,
+                    "parts of a compound quantity must be given in descending size"
+                )
+            }
+            ParseErrorReason::InvalidExponent => {
+                write!(
+                    f,
+                    "exponent must be a number or a dimensionless, real expression in brackets"
+                )
+            }
+            ParseErrorReason::RootError(err) => err.fmt(f),
+            ParseErrorReason::UnitWithScaleFactor => {
+                write!(
+                    f,
+                    "expected a unit without scale factor (e.g. m instead of mm)"
+                )
+            }
+            ParseErrorReason::UnknownIdentifier => {
+                write!(f, "identifier is neither a declared variable nor a unit")
+            }
+            ParseErrorReason::NotAnEquation => {
+                write!(f, "expected an equation with exactly one equals sign")
+            }
+            ParseErrorReason::UndeterminedUnit => {
+                write!(f, "the unit of the unknown variable cannot be determined")
+            }
+            #[cfg(feature = "from_str")]
+            ParseErrorReason::InvalidNumber(err) => err.fmt(f),
         }
     }
 }
@@ -164,6 +346,45 @@ impl From<UnitsNotEqual> for ParseErrorReason {
     }
 }
 
+impl From<AdditionError> for ParseErrorReason {
+    fn from(value: AdditionError) -> Self {
+        match value {
+            AdditionError::UnitsNotEqual(err) => return Self::UnitsNotEqual(err),
+            AdditionError::KindsNotEqual(err) => return Self::KindsNotEqual(err),
+        }
+    }
+}
+
+/**
+Mismatching units and kinds are converted into the corresponding variants, all
+other errors into [`ParseErrorReason::CouldNotParse`].
+ */
+impl From<RangeError> for ParseErrorReason {
+    fn from(value: RangeError) -> Self {
+        match value {
+            RangeError::AdditionError(err) => return err.into(),
+            RangeError::NotANumber | RangeError::NominalOutOfBounds => {
+                return Self::CouldNotParse;
+            }
+        }
+    }
+}
+
+/**
+Malformed number literals (e.g. "1__0") are converted into
+[`ParseErrorReason::InvalidNumber`], all other lexing errors into
+[`ParseErrorReason::UnexpectedToken`].
+ */
+#[cfg(feature = "from_str")]
+impl From<dyn_quantity_lexer::LexingError> for ParseErrorReason {
+    fn from(value: dyn_quantity_lexer::LexingError) -> Self {
+        match value {
+            dyn_quantity_lexer::LexingError::CouldNotParse => return Self::UnexpectedToken,
+            err => return Self::InvalidNumber(err),
+        }
+    }
+}
+
 impl std::error::Error for ParseErrorReason {}
 
 /**
@@ -215,6 +436,17 @@ pub enum ConversionError {
         /// Unit of measurement which was found.
         found: Unit,
     },
+    /**
+    The unit of measurement matches, but the quantity is tagged with a kind
+    (see [`DynQuantity::kind`](crate::DynQuantity::kind)) which does not match
+    the kind of the target.
+     */
+    KindMismatch {
+        /// Kind which was expected, if it is known.
+        expected: Option<PredefUnit>,
+        /// Kind which was found.
+        found: PredefUnit,
+    },
     /// Fallback case for all other errors.
     Custom(String),
 }
@@ -233,6 +465,19 @@ impl std::fmt::Display for ConversionError {
             ConversionError::UnitMismatch { expected, found } => {
                 write!(f, "expected {}, found {}", expected, found)
             }
+            ConversionError::KindMismatch { expected, found } => match expected {
+                Some(expected) => write!(
+                    f,
+                    "expected kind {}, found kind {}",
+                    expected.name(),
+                    found.name()
+                ),
+                None => write!(
+                    f,
+                    "kind {} does not match the kind of the target",
+                    found.name()
+                ),
+            },
             ConversionError::Custom(string) => {
                 write!(f, "{string}")
             }
@@ -241,3 +486,48 @@ impl std::fmt::Display for ConversionError {
 }
 
 impl std::error::Error for ConversionError {}
+
+/**
+Error describing a failed attempt to create a
+[`UnitBasis`](crate::basis::UnitBasis) or to express an [`Unit`] in it.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum BasisError {
+    /// A unit of the basis is a product of powers of the preceding units.
+    LinearlyDependent {
+        /// Index of the unit within the basis.
+        index: usize,
+        /// The linearly dependent unit.
+        unit: Unit,
+    },
+    /// The unit is not a product of powers of the basis units.
+    NotRepresentable {
+        /// Unit which could not be expressed in the basis.
+        unit: Unit,
+    },
+    /**
+    An exponent of a unit in the basis does not fit into a
+    [`Ratio<i32>`](num::rational::Ratio) or an intermediate result of the
+    calculation overflows.
+     */
+    ExponentOutOfRange,
+}
+
+impl std::fmt::Display for BasisError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BasisError::LinearlyDependent { index, unit } => write!(
+                f,
+                "basis unit {unit} (index {index}) is a product of powers of the preceding basis units"
+            ),
+            BasisError::NotRepresentable { unit } => {
+                write!(f, "unit {unit} cannot be expressed in the basis")
+            }
+            BasisError::ExponentOutOfRange => {
+                write!(f, "exponents are out of range of the integer arithmetic")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BasisError {}
@@ -18,16 +18,44 @@ spline with optional smooth extrapolation and derivative calculation.
 #![doc = include_str!("../docs/main.md")]
 #![deny(missing_docs)]
 
+pub mod basis;
+pub mod dimensional_analysis;
 pub mod error;
+mod linalg;
 pub mod quantity;
+pub mod range;
 pub mod unit;
 
+pub use basis::UnitBasis;
 pub use error::*;
 pub use quantity::DynQuantity;
-pub use unit::{PredefUnit, Unit, UnitFromType};
+pub use quantity::dms::Dms;
+pub use quantity::format::{FormattedQuantity, Markup, Notation, QuantityFormatter, ScaledSeries};
+pub use quantity::rkm::Rkm;
+pub use quantity::scaled_unit::ScaledUnit;
+pub use range::QuantityRange;
+pub use unit::{BaseUnit, NamedUnit, PredefUnit, Unit, UnitFromType};
 
 #[cfg(feature = "uom")]
 pub use uom;
 
 #[cfg(feature = "serde")]
 pub use quantity::serde_impl::*;
+
+#[cfg(feature = "from_str")]
+pub use dyn_quantity_lexer::LexingError;
+
+#[cfg(feature = "from_str")]
+pub use quantity::complete::{Completion, complete};
+
+#[cfg(feature = "from_str")]
+pub use quantity::compound::Compound;
+
+#[cfg(feature = "from_str")]
+pub use quantity::equation::{check_equation, expression_unit, solve_unit};
+
+#[cfg(feature = "from_str")]
+pub use quantity::find::find_quantities;
+
+#[cfg(feature = "from_str")]
+pub use quantity::tokenize::{Bracket, Operator, TokenKind, UnitToken, tokenize};
@@ -0,0 +1,116 @@
+/*!
+Crate-internal exact integer linear algebra on unit exponents. Matrices are
+stored row by row as `i64` vectors and reduced without fractions (each row is
+divided by the greatest common divisor of its entries after every step), so
+the results are exact. Since the entries can still grow for units with large
+exponents, all functions return `None` if an entry overflows.
+*/
+
+use num::Integer;
+
+/**
+Brings `rows` into reduced row echelon form, considering only the first
+`columns` columns for pivots. Each pivot is positive and the only nonzero
+entry of its column. Rows are scaled to integers with a greatest common divisor
+of one. Returns the pivot columns in ascending order; the pivot of the `i`th
+returned column is in row `i`.
+ */
+pub(crate) fn row_reduce(rows: &mut [Vec<i64>], columns: usize) -> Option<Vec<usize>> {
+    let mut pivots = Vec::new();
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot_row) = (row..rows.len()).find(|r| rows[*r][column] != 0) else {
+            continue;
+        };
+        rows.swap(row, pivot_row);
+        if rows[row][column] < 0 {
+            for entry in rows[row].iter_mut() {
+                *entry = entry.checked_neg()?;
+            }
+        }
+        normalize(&mut rows[row])?;
+
+        for other in 0..rows.len() {
+            let factor = rows[other][column];
+            if other == row || factor == 0 {
+                continue;
+            }
+            let pivot = rows[row][column];
+            let gcd = pivot.gcd(&factor);
+            let (pivot, factor) = (pivot / gcd, factor / gcd);
+            for index in 0..rows[other].len() {
+                rows[other][index] = rows[other][index]
+                    .checked_mul(pivot)?
+                    .checked_sub(rows[row][index].checked_mul(factor)?)?;
+            }
+            normalize(&mut rows[other])?;
+        }
+        pivots.push(column);
+    }
+    return Some(pivots);
+}
+
+/**
+Divides all entries of `row` by their greatest common divisor. Returns `None`
+if an entry is `i64::MIN`, whose absolute value does not fit into an `i64`.
+ */
+fn normalize(row: &mut [i64]) -> Option<()> {
+    if row.contains(&i64::MIN) {
+        return None;
+    }
+    let gcd = row.iter().fold(0, |gcd: i64, entry| gcd.gcd(entry));
+    if gcd > 1 {
+        row.iter_mut().for_each(|entry| *entry /= gcd);
+    }
+    return Some(());
+}
+
+/**
+Returns a matrix with one row per base unit and one column per unit in
+`units`, i.e. the `j`th column contains the exponents of the `j`th unit.
+ */
+pub(crate) fn exponent_matrix(units: &[crate::Unit]) -> Vec<Vec<i64>> {
+    let mut rows: Vec<Vec<i64>> = (0..7).map(|_| Vec::with_capacity(units.len())).collect();
+    for unit in units {
+        let exponents: [i32; 7] = (*unit).into();
+        for (row, exponent) in rows.iter_mut().zip(exponents) {
+            row.push(exponent.into());
+        }
+    }
+    return rows;
+}
+
+/**
+Returns an integer basis of the null space of the matrix `rows` with `columns`
+columns. The matrix must be in the form returned by [`row_reduce`] with the
+given `pivots`. Each basis vector belongs to one non-pivot column, whose entry
+is positive, and has entries with a greatest common divisor of one.
+ */
+pub(crate) fn null_space(
+    rows: &[Vec<i64>],
+    columns: usize,
+    pivots: &[usize],
+) -> Option<Vec<Vec<i64>>> {
+    let mut basis = Vec::new();
+    for free in (0..columns).filter(|column| !pivots.contains(column)) {
+        // Common multiple of all pivots which is needed to get integer entries
+        let mut multiple: i64 = 1;
+        for (row, pivot) in pivots.iter().enumerate() {
+            if rows[row][free] != 0 {
+                let gcd = multiple.gcd(&rows[row][*pivot]);
+                multiple = (multiple / gcd).checked_mul(rows[row][*pivot])?;
+            }
+        }
+        let mut vector = vec![0; columns];
+        vector[free] = multiple;
+        for (row, pivot) in pivots.iter().enumerate() {
+            // The pivot divides the multiple, hence the product cannot overflow
+            // if the quotient is calculated first
+            let quotient = multiple / rows[row][*pivot];
+            vector[*pivot] = rows[row][free].checked_mul(quotient)?.checked_neg()?;
+        }
+        normalize(&mut vector)?;
+        basis.push(vector);
+    }
+    return Some(basis);
+}
@@ -0,0 +1,143 @@
+/*!
+This module provides autocompletion of units of measurement for partially typed
+quantity strings via the [`complete`] function. Like the
+[`tokenize`](crate::quantity::tokenize) module, it is meant for editor tooling
+(e.g. input fields of a GUI) and is only available if the `from_str` feature is
+enabled.
+
+The completion candidates are built from the units and SI prefixes recognized
+by the lexer, hence every candidate is guaranteed to be accepted by the
+[`FromStr`](std::str::FromStr) implementation of
+[`DynQuantity`](crate::DynQuantity).
+
+# Examples
+
+```
+use dyn_quantity::{complete, PredefUnit, Unit};
+
+// Without an expected unit, all units starting with "k" are offered
+let texts: Vec<_> = complete("12 k", 4, None).into_iter().map(|c| c.text).collect();
+for text in ["kV", "kW", "kN", "kg", "kHz"] {
+    assert!(texts.contains(&text.to_string()));
+}
+
+// Restrict the candidates to a certain dimension
+let completions = complete("12 k", 4, Some(PredefUnit::Power.into()));
+assert_eq!(completions.len(), 1);
+let completion = &completions[0];
+assert_eq!(completion.text, "kW");
+assert_eq!(completion.span, 3..4);
+assert_eq!(completion.unit.unit, Unit::from(PredefUnit::Power));
+assert_eq!(completion.unit.factor, 1e3);
+```
+*/
+
+use std::iter::once;
+use std::ops::Range;
+
+use super::tokenize::{PREFIXES, RADIANS, TokenKind, UNITS, UnitToken, tokenize};
+use crate::unit::Unit;
+
+/**
+Spellings of units which can be typed on a standard keyboard, in addition to
+the canonical symbols of the lexer (e.g. `Ohm` for `Ω`). The boolean specifies
+whether the unit can be combined with a SI prefix.
+ */
+const ADDITIONAL_SYMBOLS: [(&str, bool); 2] = [("Ohm", true), ("deg", false)];
+
+/**
+A completion candidate returned by [`complete`].
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    /// Text of the candidate, including prefix (e.g. `kV`).
+    pub text: String,
+    /**
+    Span of the input string which should be replaced by `text`. This is the
+    partially typed unit in front of the cursor.
+     */
+    pub span: Range<usize>,
+    /// The unit the candidate resolves to, including SI representation and
+    /// scale factor.
+    pub unit: UnitToken,
+}
+
+/**
+Returns completion candidates for the partially typed unit in front of the
+byte position `cursor` of `input`. If `expected` is given, only candidates
+whose SI representation equals `expected` are returned.
+
+The partially typed unit consists of all letters (including `°` and `µ`)
+directly in front of the cursor. If it is empty, all units are candidates.
+No candidates are returned if `cursor` is not located at a character boundary
+of `input` or if the partially typed unit cannot start a unit (e.g. because it
+directly follows an exponent as in `2 m^`).
+
+See the [module-level documentation](crate::quantity::complete) for an
+example.
+ */
+pub fn complete(input: &str, cursor: usize, expected: Option<Unit>) -> Vec<Completion> {
+    let mut completions: Vec<Completion> = Vec::new();
+
+    let Some(before_cursor) = input.get(..cursor) else {
+        return completions;
+    };
+    let start = before_cursor
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphabetic() || *c == '°')
+        .last()
+        .map(|(index, _)| index)
+        .unwrap_or(cursor);
+    let partial = &before_cursor[start..];
+
+    // Units can only follow whitespace, numbers, operators or brackets
+    let previous = before_cursor[..start].chars().next_back();
+    if previous.is_some_and(|c| !(c.is_whitespace() || c.is_ascii_digit() || "+-*/()".contains(c)))
+    {
+        return completions;
+    }
+
+    // Units with a non-decimal factor or an offset are not combined with prefixes
+    let symbols = UNITS
+        .iter()
+        .chain(once(&RADIANS))
+        .map(|definition| {
+            (
+                definition.symbol,
+                definition.factor == 1.0 && definition.offset == 0.0,
+            )
+        })
+        .chain(ADDITIONAL_SYMBOLS);
+
+    for (symbol, prefixable) in symbols {
+        let prefixes = if prefixable { &PREFIXES[..] } else { &[] };
+        let texts = once(symbol.to_string()).chain(
+            prefixes
+                .iter()
+                .map(|(prefix, _)| format!("{prefix}{symbol}")),
+        );
+        for text in texts {
+            if !text.starts_with(partial) || completions.iter().any(|c| c.text == text) {
+                continue;
+            }
+
+            // Make sure the lexer interprets the candidate as a single unit
+            let mut tokens: Vec<_> = tokenize(&text).collect();
+            let unit = match tokens.pop() {
+                Some((TokenKind::Unit(unit), _)) if tokens.is_empty() => unit,
+                _ => continue,
+            };
+            if expected.is_some_and(|expected| unit.unit != expected) {
+                continue;
+            }
+
+            completions.push(Completion {
+                text,
+                span: start..cursor,
+                unit,
+            });
+        }
+    }
+    return completions;
+}
@@ -0,0 +1,307 @@
+/*!
+This module implements the compound (mixed-unit) notation for
+[`DynQuantity`], in which a quantity is written as a sum of several
+same-dimension parts without `+` signs, e.g. `1 h 30 min`, `2 d 4 h` or
+`5 ft 3 in`. Like [`from_str_impl`](crate::quantity::from_str_impl), this module
+is only available if the `from_str` feature is enabled.
+
+The [`FromStr`] implementation of [`DynQuantity`] inserts implicit
+multiplications between numbers and units, hence `1 h 30 min` is interpreted
+as `1 h * 30 min` there. The compound notation therefore needs to be parsed
+explicitly via [`DynQuantity::from_str_compound`]. The inverse operation,
+splitting a quantity into parts, is provided by [`DynQuantity::compound`].
+
+# Examples
+
+```
+use dyn_quantity::{DynQuantity, PredefUnit};
+
+let duration = DynQuantity::<f64>::from_str_compound("1 h 30 min 15 s").expect("valid");
+assert_eq!(duration.value, 5415.0);
+assert_eq!(duration.unit, PredefUnit::Time.into());
+
+// Formatting the quantity as hours, minutes and seconds
+let compound = duration.compound(&["h", "min", "s"]).expect("valid units");
+assert_eq!(compound.to_string(), "1 h 30 min 15 s");
+```
+*/
+
+use std::str::FromStr;
+
+use super::tokenize::{Operator, TokenKind, tokenize};
+use super::{DynQuantity, F64RealOrComplex};
+use crate::error::{ParseError, ParseErrorReason, UnitsNotEqual};
+
+impl<V: F64RealOrComplex> DynQuantity<V> {
+    /**
+    Parses a string in compound notation such as `1 h 30 min` or `5 ft 3 in`
+    into a [`DynQuantity`]. The string is split into parts, where a new part
+    starts at each number which directly follows a unit. Each part is parsed
+    using the [`FromStr`] implementation and the parts are added up. A leading
+    minus sign applies to all parts, i.e. `-1 h 30 min` equals `-90 min`.
+
+    Returns an error if the parts do not have the same unit or if their units
+    are not given in descending size (e.g. `30 min 1 h`). A string consisting
+    of a single part is parsed just like with [`FromStr`].
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, ParseErrorReason};
+
+    let length = DynQuantity::<f64>::from_str_compound("3 yd 2 ft").expect("valid");
+    assert!((length.value - 3.3528).abs() < 1e-12);
+
+    let duration = DynQuantity::<f64>::from_str_compound("-1 h 30 min").expect("valid");
+    assert_eq!(duration.value, -5400.0);
+
+    // Parts with different units
+    let error = DynQuantity::<f64>::from_str_compound("1 h 30 m").unwrap_err();
+    assert_eq!(error.substring, "30 m");
+    assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+
+    // Parts in ascending order
+    let error = DynQuantity::<f64>::from_str_compound("30 min 1 h").unwrap_err();
+    assert_eq!(error.substring, "1 h");
+    assert_eq!(error.span, 7..10);
+    assert_eq!(error.reason, ParseErrorReason::CompoundNotDescending);
+    ```
+     */
+    pub fn from_str_compound(s: &str) -> Result<Self, ParseError> {
+        let tokens: Vec<_> = tokenize(s).collect();
+
+        // Find the start of all parts: Each number directly following a unit
+        // starts a new part. The span of the unit expression of each part is
+        // stored as well in order to compare the sizes of the parts.
+        let mut starts = vec![0];
+        let mut unit_starts: Vec<Option<usize>> = vec![None];
+        for (index, (kind, span)) in tokens.iter().enumerate() {
+            match kind {
+                TokenKind::Number(_)
+                    if index > 0 && matches!(tokens[index - 1].0, TokenKind::Unit(_)) =>
+                {
+                    starts.push(span.start);
+                    unit_starts.push(None);
+                }
+                TokenKind::Unit(_) => {
+                    let unit_start = unit_starts.last_mut().expect("never empty");
+                    if unit_start.is_none() {
+                        *unit_start = Some(span.start);
+                    }
+                }
+                _ => (),
+            }
+        }
+        if starts.len() == 1 {
+            return Self::from_str(s);
+        }
+
+        let negative = matches!(
+            tokens.first(),
+            Some((TokenKind::Operator(Operator::Sub), _))
+        );
+
+        let mut sum: Option<Self> = None;
+        let mut previous_size: Option<f64> = None;
+        for (index, start) in starts.iter().copied().enumerate() {
+            let end = starts.get(index + 1).copied().unwrap_or(s.len());
+            let part = s[start..end].trim_end();
+            let span = start..(start + part.len());
+            let with_offset = |mut error: ParseError| {
+                error.span = (error.span.start + start)..(error.span.end + start);
+                error
+            };
+
+            let mut quantity = Self::from_str(part).map_err(with_offset)?;
+            let error = |reason: ParseErrorReason| ParseError {
+                substring: part.to_owned(),
+                span: span.clone(),
+                reason,
+            };
+            let unit = sum.as_ref().map_or(quantity.unit, |sum| sum.unit);
+            if unit != quantity.unit {
+                return Err(error(UnitsNotEqual(unit, quantity.unit).into()));
+            }
+
+            // The size of a part is the value of its unit expression, e.g.
+            // 60 s for "30 min".
+            let Some(unit_start) = unit_starts[index] else {
+                return Err(error(ParseErrorReason::CouldNotParse));
+            };
+            let size = DynQuantity::<f64>::from_str(&s[unit_start..span.end])
+                .map_err(|_| error(ParseErrorReason::CouldNotParse))?
+                .value
+                .abs();
+            if previous_size.is_some_and(|previous| size >= previous) {
+                return Err(error(ParseErrorReason::CompoundNotDescending));
+            }
+            previous_size = Some(size);
+
+            match sum.as_mut() {
+                Some(sum) => {
+                    if negative {
+                        quantity.value = -quantity.value;
+                    }
+                    sum.value += quantity.value;
+                }
+                None => sum = Some(quantity),
+            }
+        }
+        return Ok(sum.expect("at least two parts"));
+    }
+}
+
+impl DynQuantity<f64> {
+    /**
+    Returns a [`Compound`] which formats `self` as a sum of the given `units`
+    (e.g. `["h", "min", "s"]`) via its [`Display`](std::fmt::Display)
+    implementation. The units need to be given in descending size and each
+    unit needs to be parsable by the [`FromStr`] implementation of
+    [`DynQuantity`].
+
+    Returns an error if a unit cannot be parsed, if its dimension differs from
+    that of `self` or if the units are not given in descending size. The span
+    of the error refers to the offending element of `units`.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::DynQuantity;
+
+    let length = DynQuantity::<f64>::from_str("2.1336 m").expect("valid");
+    let compound = length.compound(&["yd", "ft"]).expect("valid units");
+    assert_eq!(format!("{compound:.1}"), "2 yd 1.0 ft");
+
+    // Zero parts are omitted
+    let duration = DynQuantity::<f64>::from_str("7205 s").expect("valid");
+    assert_eq!(duration.compound(&["h", "min", "s"]).unwrap().to_string(), "2 h 5 s");
+
+    // Units of different dimension
+    assert!(duration.compound(&["h", "m"]).is_err());
+    ```
+     */
+    pub fn compound<'a>(&self, units: &[&'a str]) -> Result<Compound<'a>, ParseError> {
+        let mut parts: Vec<(&'a str, f64)> = Vec::with_capacity(units.len());
+        for unit in units.iter().copied() {
+            let size = DynQuantity::<f64>::from_str(unit)?;
+            if size.unit != self.unit {
+                return Err(ParseError {
+                    substring: unit.to_owned(),
+                    span: 0..unit.len(),
+                    reason: ParseErrorReason::UnitsNotEqual(UnitsNotEqual(self.unit, size.unit)),
+                });
+            }
+            let size = size.value.abs();
+            if parts.last().is_some_and(|(_, previous)| size >= *previous) {
+                return Err(ParseError {
+                    substring: unit.to_owned(),
+                    span: 0..unit.len(),
+                    reason: ParseErrorReason::CompoundNotDescending,
+                });
+            }
+            parts.push((unit, size));
+        }
+        if parts.is_empty() {
+            return Err(ParseError {
+                substring: String::new(),
+                span: 0..0,
+                reason: ParseErrorReason::InputIsEmpty,
+            });
+        }
+        return Ok(Compound {
+            value: self.value,
+            parts,
+        });
+    }
+}
+
+/**
+A [`DynQuantity<f64>`] split into several parts of descending size, e.g. a
+duration given in hours, minutes and seconds. This struct is created by
+[`DynQuantity::compound`] and formats the quantity in compound notation via
+its [`Display`](std::fmt::Display) implementation.
+
+All parts except for the last one are integer multiples of their unit, the
+last part contains the remainder. Parts which are zero are omitted, except if
+the quantity itself is zero. The precision of the formatter (e.g. `{:.2}`) is
+applied to the last part. The output can be parsed again via
+[`DynQuantity::from_str_compound`].
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Compound<'a> {
+    value: f64,
+    parts: Vec<(&'a str, f64)>,
+}
+
+impl<'a> Compound<'a> {
+    /**
+    Returns the parts of the quantity as pairs of unit and value in that unit,
+    including parts which are zero. For example, `5415 s` split into
+    `["h", "min", "s"]` results in `[("h", 1.0), ("min", 30.0), ("s", 15.0)]`.
+    The sign of the quantity is applied to all parts.
+     */
+    pub fn parts(&self) -> Vec<(&'a str, f64)> {
+        return self.split(None);
+    }
+
+    /**
+    Splits the value into parts. If `precision` is given, the value is rounded
+    to the given number of decimal places of the last part beforehand, so that
+    the last part cannot be rounded up to the size of the second-to-last part
+    (e.g. "59.99 s" to "60 s").
+     */
+    fn split(&self, precision: Option<usize>) -> Vec<(&'a str, f64)> {
+        let (_, smallest) = self.parts.last().expect("never empty");
+        let mut remainder = self.value.abs() / smallest;
+        if let Some(precision) = precision {
+            let scale = 10f64.powi(precision.min(i32::MAX as usize) as i32);
+            remainder = (remainder * scale).round() / scale;
+        }
+
+        // Calculate everything in multiples of the smallest unit. A small
+        // tolerance avoids e.g. 7200 s being split into 1 h 59.999... min.
+        let tolerance = remainder * 4.0 * f64::EPSILON;
+        let sign = self.value.signum();
+        let mut parts = Vec::with_capacity(self.parts.len());
+        for (index, (unit, size)) in self.parts.iter().copied().enumerate() {
+            if index + 1 == self.parts.len() {
+                parts.push((unit, sign * remainder * smallest / size));
+            } else {
+                let ratio = size / smallest;
+                let count = ((remainder + tolerance) / ratio).floor();
+                remainder = (remainder - count * ratio).max(0.0);
+                parts.push((unit, sign * count));
+            }
+        }
+        return parts;
+    }
+}
+
+impl std::fmt::Display for Compound<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts = self.split(f.precision());
+        let last = parts.len() - 1;
+        let mut first = true;
+        for (index, (unit, value)) in parts.into_iter().enumerate() {
+            // Omit zero parts unless all parts are zero
+            if value == 0.0 && !(index == last && first) {
+                continue;
+            }
+            if !first {
+                write!(f, " ")?;
+            }
+
+            // All parts after the first one are written without sign
+            let value = if first { value } else { value.abs() };
+            if index == last {
+                match f.precision() {
+                    Some(precision) => write!(f, "{value:.precision$} {unit}")?,
+                    None => write!(f, "{value} {unit}")?,
+                }
+            } else {
+                write!(f, "{value} {unit}")?;
+            }
+            first = false;
+        }
+        return Ok(());
+    }
+}
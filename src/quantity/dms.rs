@@ -0,0 +1,125 @@
+/*!
+This module contains the [`Dms`] struct, which formats an angle given as a
+[`DynQuantity`] in degree-minute-second notation, e.g. `12°30'15"`. Angles in
+this notation (as well as in degree-decimal-minute notation such as
+`45° 30.5'`) can be parsed via the [`FromStr`](std::str::FromStr)
+implementation of [`DynQuantity`], see the
+[`from_str_impl`](crate::quantity::from_str_impl) module documentation.
+*/
+
+use super::DynQuantity;
+use crate::error::UnitsNotEqual;
+use crate::unit::Unit;
+
+impl DynQuantity<f64> {
+    /**
+    Returns a [`Dms`] which formats `self` in degree-minute-second notation via
+    its [`Display`](std::fmt::Display) implementation. Since angles are
+    represented in radians, `self` must be dimensionless, otherwise an error
+    is returned.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::DynQuantity;
+
+    let angle = DynQuantity::<f64>::from_str("12.504166666 deg").expect("valid");
+    let dms = angle.to_dms().expect("dimensionless");
+    assert_eq!(dms.to_string(), "12°30'15\"");
+    assert_eq!(format!("{dms:.2}"), "12°30'15.00\"");
+
+    // Only dimensionless quantities can be formatted as angle
+    let length = DynQuantity::<f64>::from_str("1 m").expect("valid");
+    assert!(length.to_dms().is_err());
+    ```
+     */
+    pub fn to_dms(&self) -> Result<Dms, UnitsNotEqual> {
+        if !self.unit.is_dimensionless() {
+            return Err(UnitsNotEqual(Unit::default(), self.unit));
+        }
+        return Ok(Dms {
+            radians: self.value,
+        });
+    }
+}
+
+/**
+An angle formatted in degree-minute-second notation via its
+[`Display`](std::fmt::Display) implementation, e.g. `12°30'15"` or
+`-0°0'1.5"`. This struct is created by [`DynQuantity::to_dms`].
+
+The precision of the formatter (e.g. `{:.2}`) specifies the number of decimal
+places of the arcseconds. If no precision is given, the arcseconds are rounded
+to an integer. Rounding is carried over into arcminutes and degrees, hence
+`59.9999"` is formatted as `1'0"` instead of `0'60"`. The output can be parsed
+again via the [`FromStr`](std::str::FromStr) implementation of
+[`DynQuantity`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dms {
+    radians: f64,
+}
+
+impl Dms {
+    /**
+    Returns the degrees, arcminutes and arcseconds of the angle. Degrees and
+    arcminutes are integers, the arcseconds contain the remainder. The sign of
+    the angle is applied to all parts.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, Unit};
+
+    let angle = DynQuantity::new(-45.5f64.to_radians(), Unit::default());
+    let (degrees, minutes, seconds) = angle.to_dms().unwrap().parts();
+    assert_eq!(degrees, -45.0);
+    assert_eq!(minutes, -30.0);
+    assert!(seconds.abs() < 1e-9);
+    ```
+     */
+    pub fn parts(&self) -> (f64, f64, f64) {
+        return self.split(None);
+    }
+
+    /**
+    Splits the angle into degrees, arcminutes and arcseconds. If `precision`
+    is given, the arcseconds are rounded to the given number of decimal places
+    beforehand.
+     */
+    fn split(&self, precision: Option<usize>) -> (f64, f64, f64) {
+        let mut seconds = self.radians.to_degrees().abs() * 3600.0;
+        if let Some(precision) = precision {
+            let scale = 10f64.powi(precision.min(i32::MAX as usize) as i32);
+            seconds = (seconds * scale).round() / scale;
+        }
+
+        // A small tolerance avoids e.g. 45.5° being split into 45°29'59.99...".
+        let tolerance = seconds * 4.0 * f64::EPSILON;
+        let degrees = ((seconds + tolerance) / 3600.0).floor();
+        seconds = (seconds - degrees * 3600.0).max(0.0);
+        let minutes = ((seconds + tolerance) / 60.0).floor();
+        seconds = (seconds - minutes * 60.0).max(0.0);
+
+        let sign = self.radians.signum();
+        return (sign * degrees, sign * minutes, sign * seconds);
+    }
+}
+
+impl std::fmt::Display for Dms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let (degrees, minutes, seconds) = self.split(Some(precision));
+        let sign = if self.radians < 0.0 && degrees + minutes + seconds != 0.0 {
+            "-"
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "{sign}{}°{}'{:.precision$}\"",
+            degrees.abs(),
+            minutes.abs(),
+            seconds.abs()
+        )
+    }
+}
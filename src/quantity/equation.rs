@@ -0,0 +1,1150 @@
+/*!
+This module checks formulas such as `P = U * I * cos(phi)` or `F = m * a` for
+dimensional consistency. Like [`from_str_impl`](crate::quantity::from_str_impl),
+it is only available if the `from_str` feature is enabled.
+
+The variables of a formula are declared as pairs of a name and anything
+convertible into an [`Unit`] (such as a [`PredefUnit`](crate::PredefUnit) or a
+[`DynQuantity`]). [`check_equation`] then determines the units of both sides of
+the equation and returns their common unit. If the units do not agree, the
+returned [`ParseError`] points to the sub-expression whose unit is off.
+
+If the unit of one variable is unknown, [`solve_unit`] determines the unit
+which makes the equation dimensionally consistent, e.g. the unit of a
+coefficient within an empirical formula.
+
+# Examples
+
+```
+use dyn_quantity::{
+    ParseErrorReason, PredefUnit, Unit, UnitsNotEqual, check_equation, solve_unit,
+};
+
+let variables = [
+    ("P", PredefUnit::Power),
+    ("U", PredefUnit::ElectricVoltage),
+    ("I", PredefUnit::ElectricCurrent),
+    ("phi", PredefUnit::Angle),
+];
+let unit = check_equation(&variables, "P = U * I * cos(phi)").expect("consistent");
+assert_eq!(unit, Unit::from(PredefUnit::Power));
+
+// The right-hand side has the unit of an energy
+let equation = "P = U * I * 2 h";
+let error = check_equation(&variables, equation).unwrap_err();
+assert_eq!(&equation[error.span.clone()], "U * I * 2 h");
+assert_eq!(
+    error.reason,
+    ParseErrorReason::UnitsNotEqual(UnitsNotEqual(
+        PredefUnit::Power.into(),
+        PredefUnit::Energy.into()
+    ))
+);
+
+// The argument of the cosine is not dimensionless
+let error = check_equation(&variables, "P = U * I * cos(U)").unwrap_err();
+assert_eq!(error.substring, "U");
+assert_eq!(error.span, 16..17);
+
+// Which unit does the coefficient k need?
+let unit = solve_unit(&variables, "P = k * U^2", "k").expect("solvable");
+assert_eq!(unit, Unit::from(PredefUnit::ElectricConductance));
+```
+
+# Syntax
+
+The syntax is the one of the [`FromStr`](std::str::FromStr) implementation of
+[`DynQuantity`], extended by identifiers and functions:
+
+- Numbers, constants, units and operators are recognized by the same lexer as
+  in [`FromStr`](std::str::FromStr), hence every quantity string accepted
+  there (e.g. `30'`, `0x10 m`, `1_000 mV` or `2 m^2^3`) can be used within an
+  equation as well.
+- Identifiers consist of letters, digits and underscores and must not start
+  with a digit (e.g. `phi`, `U_0` or `x2`). They must be separated from a
+  preceding number (`2 x` instead of `2x`), since e.g. `0x10` is a
+  hexadecimal number. Declared variables take precedence over units, i.e. a
+  variable named `m` shadows the unit meter. The names of the functions listed
+  below are identifiers if they are followed by a bracket.
+- The operators `+`, `-`, `*`, `/` and `^` as well as brackets can be used.
+  Multiplication signs can be omitted (e.g. `m a` or `2 pi f`). In contrast to
+  [`FromStr`](std::str::FromStr), the usual operator precedence applies, i.e.
+  a sign after a multiplication negates the following factor (`a * -b`).
+- An exponent must be dimensionless. If the base has a unit, the exponent must
+  be a constant number (e.g. `r^2` or `A^(1/2)`).
+- The functions `sqrt` and `cbrt` calculate the square and cubic root of their
+  argument and `abs` its absolute value. The arguments of all other functions
+  (`sin`, `cos`, `tan`, `asin`, `acos`, `atan`, `sinh`, `cosh`, `tanh`,
+  `exp`, `ln`, `log`, `log2` and `log10`) must be dimensionless.
+*/
+
+use std::ops::Range;
+
+use dyn_quantity_lexer::{LexingError, Token};
+use num::rational::Ratio;
+use num::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Complex, Integer};
+
+use super::DynQuantity;
+use super::from_str_impl::{apply_token, base_factor};
+use super::tokenize::unit_definition;
+use crate::error::{ParseError, ParseErrorReason, RootError, UnitsNotEqual};
+use crate::unit::Unit;
+
+/**
+Checks whether both sides of `equation` have the same unit and returns this
+unit. The units of the variables used within the equation are given by
+`variables`. See the [module-level documentation](crate::quantity::equation)
+for the syntax.
+
+If the equation is dimensionally inconsistent, the returned error contains
+[`ParseErrorReason::UnitsNotEqual`] and its span points to the offending
+sub-expression:
+- Addition and subtraction: the first summand whose unit differs from the one
+  of the first summand.
+- Equation: the right-hand side.
+- Functions requiring a dimensionless argument: the argument.
+- Exponentiation: the exponent, if it is not dimensionless.
+
+# Examples
+```
+use dyn_quantity::{ParseErrorReason, PredefUnit, check_equation};
+
+let variables = [
+    ("F", PredefUnit::Force),
+    ("m", PredefUnit::Mass),
+    ("a", PredefUnit::Acceleration),
+    ("v", PredefUnit::Velocity),
+    ("t", PredefUnit::Time),
+];
+assert!(check_equation(&variables, "F = m a").is_ok());
+assert!(check_equation(&variables, "F = m * (a + v / t)").is_ok());
+assert!(check_equation(&variables, "F = 0.5 m v / t + 2 m a").is_ok());
+
+let equation = "F = m * (a + v)";
+let error = check_equation(&variables, equation).unwrap_err();
+assert_eq!(error.substring, "v");
+assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+
+let error = check_equation(&variables, "F = m b").unwrap_err();
+assert_eq!(error.substring, "b");
+assert_eq!(error.reason, ParseErrorReason::UnknownIdentifier);
+
+let error = check_equation(&variables, "F - m a").unwrap_err();
+assert_eq!(error.reason, ParseErrorReason::NotAnEquation);
+```
+ */
+pub fn check_equation<S: AsRef<str>, U: Into<Unit> + Clone>(
+    variables: &[(S, U)],
+    equation: &str,
+) -> Result<Unit, ParseError> {
+    let (names, units) = split_variables(variables);
+    let (lhs, rhs) = Parser::new(equation, &names)?.parse_equation()?;
+    let checker = Checker {
+        input: equation,
+        units: &units,
+    };
+    let lhs_unit = checker.check(&lhs)?.unit;
+    let rhs_unit = checker.check(&rhs)?.unit;
+    if lhs_unit != rhs_unit {
+        return Err(checker.error(rhs.span, UnitsNotEqual(lhs_unit, rhs_unit).into()));
+    }
+    return Ok(lhs_unit);
+}
+
+/**
+Returns the unit of `expression`, which uses the same syntax as the sides of
+an equation in [`check_equation`]. Returns an error if the expression cannot
+be parsed or is dimensionally inconsistent.
+
+# Examples
+```
+use dyn_quantity::{PredefUnit, Unit, expression_unit};
+
+let variables = [("U", PredefUnit::ElectricVoltage), ("R", PredefUnit::ElectricResistance)];
+assert_eq!(expression_unit(&variables, "U^2 / R").unwrap(), Unit::from(PredefUnit::Power));
+assert_eq!(expression_unit(&variables, "U / R * 1 s").unwrap(), Unit::from(PredefUnit::ElectricCharge));
+assert!(expression_unit(&variables, "U + R").is_err());
+```
+ */
+pub fn expression_unit<S: AsRef<str>, U: Into<Unit> + Clone>(
+    variables: &[(S, U)],
+    expression: &str,
+) -> Result<Unit, ParseError> {
+    let (names, units) = split_variables(variables);
+    let expr = Parser::new(expression, &names)?.parse_expression()?;
+    let checker = Checker {
+        input: expression,
+        units: &units,
+    };
+    return Ok(checker.check(&expr)?.unit);
+}
+
+/**
+Returns the unit of the variable `unknown` which makes `equation`
+dimensionally consistent. The units of all other variables are given by
+`variables`; a declaration of `unknown` within `variables` is ignored. See the
+[module-level documentation](crate::quantity::equation) for the syntax.
+
+Since multiplying quantities adds the exponents of their units, the exponents
+of each sub-expression are an affine function of the unknown exponents (e.g.
+`x^2 * s` has the exponents `2 x + [1, 0, 0, 0, 0, 0, 0]`). Each sum, equation,
+function argument and exponent therefore yields a linear equation for the
+unknown exponents. The first of these equations which contains the unknown
+determines the solution. Afterwards, the whole equation is checked with
+[`check_equation`] using this solution.
+
+Returns an error if:
+- the unknown does not appear in the equation or cancels out (e.g. `y = x / x`):
+  [`ParseErrorReason::UndeterminedUnit`],
+- the solution has fractional exponents (e.g. `x^2 = m`):
+  [`ParseErrorReason::RootError`],
+- the equation is inconsistent for the solution (e.g. `x = s + m`) or cannot
+  be parsed: see [`check_equation`].
+
+# Examples
+```
+use dyn_quantity::{ParseErrorReason, PredefUnit, Unit, solve_unit};
+
+assert_eq!(
+    solve_unit::<&str, Unit>(&[], "x * 3 s = 5 m", "x").unwrap(),
+    Unit::from(PredefUnit::Velocity)
+);
+
+// Spring constant
+let variables = [("F", PredefUnit::Force), ("s", PredefUnit::Length)];
+assert_eq!(
+    solve_unit(&variables, "F = c s", "c").unwrap(),
+    Unit::from([-2, 0, 1, 0, 0, 0, 0])
+);
+
+// Roots of the unknown are fine, fractional exponents of the solution are not
+assert_eq!(
+    solve_unit(&variables, "s = sqrt(A)", "A").unwrap(),
+    Unit::from(PredefUnit::Area)
+);
+let error = solve_unit(&variables, "s = A^2", "A").unwrap_err();
+assert!(matches!(error.reason, ParseErrorReason::RootError(_)));
+
+let error = solve_unit(&variables, "F = k / k * F", "k").unwrap_err();
+assert_eq!(error.reason, ParseErrorReason::UndeterminedUnit);
+```
+ */
+pub fn solve_unit<S: AsRef<str>, U: Into<Unit> + Clone>(
+    variables: &[(S, U)],
+    equation: &str,
+    unknown: &str,
+) -> Result<Unit, ParseError> {
+    // The unknown is the first variable, so it shadows a declaration of the
+    // same name within `variables`.
+    let (mut names, mut units) = split_variables(variables);
+    names.insert(0, unknown);
+    units.insert(0, Unit::default());
+
+    let (lhs, rhs) = Parser::new(equation, &names)?.parse_equation()?;
+    let mut solver = Solver {
+        solution: None,
+        overflow: None,
+    };
+    let lhs_exponents = solver.exponents(&lhs, &units);
+    let rhs_exponents = solver.exponents(&rhs, &units);
+    solver.constrain(&lhs_exponents, &rhs_exponents, 0..equation.len());
+    if let Some(span) = solver.overflow {
+        return Err(error(equation, span, ParseErrorReason::InvalidExponent));
+    }
+
+    let Some((solution, span)) = solver.solution else {
+        return Err(error(
+            equation,
+            0..equation.len(),
+            ParseErrorReason::UndeterminedUnit,
+        ));
+    };
+    units[0] = solution
+        .into_unit()
+        .map_err(|reason| error(equation, span, reason))?;
+
+    let variables: Vec<(&str, Unit)> = names.into_iter().zip(units).collect();
+    check_equation(&variables, equation)?;
+    return Ok(variables[0].1);
+}
+
+fn split_variables<S: AsRef<str>, U: Into<Unit> + Clone>(
+    variables: &[(S, U)],
+) -> (Vec<&str>, Vec<Unit>) {
+    return variables
+        .iter()
+        .map(|(name, unit)| (name.as_ref(), unit.clone().into()))
+        .unzip();
+}
+
+fn error(input: &str, span: Range<usize>, reason: ParseErrorReason) -> ParseError {
+    return ParseError {
+        substring: input[span.clone()].to_owned(),
+        span,
+        reason,
+    };
+}
+
+/// Functions which can be used within an equation.
+#[derive(Debug, Clone, Copy)]
+enum Function {
+    /// Function whose argument and result are dimensionless.
+    Dimensionless(fn(f64) -> f64),
+    /// `n`th root.
+    Root(i32),
+    /// Absolute value.
+    Abs,
+}
+
+const FUNCTIONS: [(&str, Function); 17] = [
+    ("sin", Function::Dimensionless(f64::sin)),
+    ("cos", Function::Dimensionless(f64::cos)),
+    ("tan", Function::Dimensionless(f64::tan)),
+    ("asin", Function::Dimensionless(f64::asin)),
+    ("acos", Function::Dimensionless(f64::acos)),
+    ("atan", Function::Dimensionless(f64::atan)),
+    ("sinh", Function::Dimensionless(f64::sinh)),
+    ("cosh", Function::Dimensionless(f64::cosh)),
+    ("tanh", Function::Dimensionless(f64::tanh)),
+    ("exp", Function::Dimensionless(f64::exp)),
+    ("ln", Function::Dimensionless(f64::ln)),
+    ("log", Function::Dimensionless(f64::ln)),
+    ("log2", Function::Dimensionless(f64::log2)),
+    ("log10", Function::Dimensionless(f64::log10)),
+    ("sqrt", Function::Root(2)),
+    ("cbrt", Function::Root(3)),
+    ("abs", Function::Abs),
+];
+
+/// Tokens of an equation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Symbol {
+    /// A number, constant or unit, e.g. `2`, `pi`, `km` or `%`.
+    Constant(DynQuantity<Complex<f64>>),
+    /// A declared variable or a function name.
+    Identifier,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Open,
+    Close,
+    Equals,
+}
+
+/// Node of the syntax tree of an expression together with its span.
+#[derive(Debug, Clone)]
+struct Expr {
+    kind: ExprKind,
+    span: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum ExprKind {
+    /// A number or unit, e.g. `2`, `km` or `%`.
+    Constant(DynQuantity<Complex<f64>>),
+    /// Index of a declared variable.
+    Variable(usize),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    Function(Function, Box<Expr>),
+}
+
+/**
+Splits `input` into its tokens. Declared variables (`names`) and functions
+followed by a bracket are returned as identifiers. The text between them is
+split by the lexer of the [`FromStr`](std::str::FromStr) implementation of
+[`DynQuantity`], so numbers, constants, units and operators are recognized
+exactly like there.
+ */
+fn lex(input: &str, names: &[&str]) -> Result<Vec<(Symbol, Range<usize>)>, ParseError> {
+    let mut lexer = Lexer {
+        input,
+        tokens: Vec::new(),
+    };
+
+    // Start of the text which has not been passed to the lexer yet
+    let mut text_start = 0;
+    let mut previous: Option<char> = None;
+    let mut start = 0;
+    while let Some(c) = input[start..].chars().next() {
+        if c == '=' {
+            lexer.lex_text(text_start..start)?;
+            lexer.tokens.push((Symbol::Equals, start..start + 1));
+            text_start = start + 1;
+        }
+
+        // Identifiers must not be glued to a preceding number or symbol, so
+        // that e.g. "0x10", "1e3" and "°C" are left to the lexer.
+        let is_identifier_start = (c.is_alphabetic() || c == '_')
+            && !previous.is_some_and(|previous| is_identifier_char(previous) || previous == '°');
+        if !is_identifier_start {
+            previous = Some(c);
+            start += c.len_utf8();
+            continue;
+        }
+        let end = input[start..]
+            .find(|c: char| !is_identifier_char(c))
+            .map_or(input.len(), |length| start + length);
+        let word = &input[start..end];
+        let is_function = FUNCTIONS.iter().any(|(function, _)| *function == word)
+            && input[end..].trim_start().starts_with('(');
+        if names.contains(&word) || is_function {
+            lexer.lex_text(text_start..start)?;
+            lexer.tokens.push((Symbol::Identifier, start..end));
+            text_start = end;
+        }
+        previous = word.chars().last();
+        start = end;
+    }
+    lexer.lex_text(text_start..input.len())?;
+    return Ok(lexer.tokens);
+}
+
+fn is_identifier_char(c: char) -> bool {
+    return c.is_alphabetic() || c.is_ascii_digit() || c == '_';
+}
+
+/// Converts the tokens of the lexer into [`Symbol`]s.
+struct Lexer<'a> {
+    input: &'a str,
+    tokens: Vec<(Symbol, Range<usize>)>,
+}
+
+impl Lexer<'_> {
+    /// Splits the part `span` of the input into its tokens.
+    fn lex_text(&mut self, span: Range<usize>) -> Result<(), ParseError> {
+        let offset = span.start;
+        let mut tokens = dyn_quantity_lexer::lex(&self.input[span])
+            .into_iter()
+            .peekable();
+        while let Some((token, span)) = tokens.next() {
+            let span = span.start + offset..span.end + offset;
+            let token = match token {
+                Ok(token) => token,
+                Err(err) => return Err(self.invalid(span, err)),
+            };
+
+            // The offset of units such as "°C" is not defined if they are
+            // prefixed or raised to a power
+            if let Some((definition, exponents)) = unit_definition(&token)
+                && definition.offset(exponents).is_none()
+            {
+                return Err(error(self.input, span, ParseErrorReason::UnexpectedToken));
+            }
+
+            let caret = self.input[span.clone()].find('^');
+            let symbol = match token {
+                Token::Add => Symbol::Add,
+                Token::Sub => Symbol::Sub,
+                Token::Mul => Symbol::Mul,
+                Token::Div => Symbol::Div,
+                Token::Pow => Symbol::Pow,
+                Token::LeftBracket => Symbol::Open,
+                Token::RightBracket(_) => Symbol::Close,
+                token => match base_factor(&token) {
+                    Some(base) if caret.is_some() => Symbol::Constant(base),
+                    _ => {
+                        let mut quantity = None;
+                        apply_token(&mut quantity, token);
+                        Symbol::Constant(quantity.expect("factor tokens create a quantity"))
+                    }
+                },
+            };
+            let Some(caret) = caret.filter(|_| symbol != Symbol::Pow) else {
+                self.tokens.push((symbol, span));
+                continue;
+            };
+            let caret = span.start + caret;
+
+            // Tokens which contain an exponent (e.g. "m^2" or ")^-1") are
+            // split into their base, the operator and the exponent. If the
+            // decimal places of the exponent are lexed as a separate number
+            // (e.g. "m^0.5" is lexed as "m^0" and ".5"), they are added to it.
+            let mut end = span.end;
+            if let Some((Ok(Token::Real(_)), decimals)) = tokens.peek()
+                && decimals.start + offset == end
+                && self.input[end..].starts_with('.')
+            {
+                end = decimals.end + offset;
+                tokens.next();
+            }
+            let exponent_span = caret + 1..end;
+            let Ok(exponent) = self.input[exponent_span.clone()].parse::<f64>() else {
+                return Err(error(
+                    self.input,
+                    exponent_span,
+                    ParseErrorReason::InvalidExponent,
+                ));
+            };
+            self.tokens.push((symbol, span.start..caret));
+            self.tokens.push((Symbol::Pow, caret..caret + 1));
+            self.tokens.push((
+                Symbol::Constant(DynQuantity::new(
+                    Complex::new(exponent, 0.0),
+                    Unit::default(),
+                )),
+                exponent_span,
+            ));
+        }
+        return Ok(());
+    }
+
+    /**
+    Error for the invalid token at `span`. If it is part of a word (e.g. the
+    `x` in `kWx`), the entire word is returned as an unknown identifier.
+    Malformed numbers (e.g. `1__0`) are returned as invalid numbers.
+     */
+    fn invalid(&self, span: Range<usize>, err: LexingError) -> ParseError {
+        if err != LexingError::CouldNotParse
+            || !self.input[span.clone()].starts_with(is_identifier_char)
+        {
+            return error(self.input, span, err.into());
+        }
+        let start = self.input[..span.start]
+            .rfind(|c: char| !is_identifier_char(c))
+            .map_or(0, |index| {
+                index + self.input[index..].chars().next().map_or(1, char::len_utf8)
+            });
+        let end = self.input[span.start..]
+            .find(|c: char| !is_identifier_char(c))
+            .map_or(self.input.len(), |length| span.start + length);
+        return error(self.input, start..end, ParseErrorReason::UnknownIdentifier);
+    }
+}
+
+/// Recursive descent parser which builds the syntax tree of an equation.
+struct Parser<'a> {
+    input: &'a str,
+    names: &'a [&'a str],
+    tokens: Vec<(Symbol, Range<usize>)>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, names: &'a [&'a str]) -> Result<Self, ParseError> {
+        let tokens = lex(input, names)?;
+        if tokens.is_empty() {
+            return Err(error(input, 0..input.len(), ParseErrorReason::InputIsEmpty));
+        }
+        return Ok(Parser {
+            input,
+            names,
+            tokens,
+            position: 0,
+        });
+    }
+
+    fn peek(&self) -> Option<Symbol> {
+        return self.tokens.get(self.position).map(|(symbol, _)| *symbol);
+    }
+
+    fn next_token(&mut self) -> Option<(Symbol, Range<usize>)> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        return token;
+    }
+
+    fn error(&self, span: Range<usize>, reason: ParseErrorReason) -> ParseError {
+        return error(self.input, span, reason);
+    }
+
+    /// Error for an unexpected token at the current position.
+    fn unexpected(&self) -> ParseError {
+        match self.tokens.get(self.position) {
+            Some((Symbol::Close, span)) => {
+                return self.error(span.clone(), ParseErrorReason::UnbalancedBrackets);
+            }
+            Some((_, span)) => return self.error(span.clone(), ParseErrorReason::UnexpectedToken),
+            None => {
+                // The input ended while an operand was expected
+                let span = self.tokens.last().expect("input is not empty").1.clone();
+                return self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
+            }
+        }
+    }
+
+    fn parse_equation(mut self) -> Result<(Expr, Expr), ParseError> {
+        let lhs = self.parse_sum()?;
+        match self.peek() {
+            Some(Symbol::Equals) => self.position += 1,
+            Some(_) => return Err(self.unexpected()),
+            None => {
+                return Err(self.error(0..self.input.len(), ParseErrorReason::NotAnEquation));
+            }
+        }
+        let rhs = self.parse_sum()?;
+        match self.next_token() {
+            None => return Ok((lhs, rhs)),
+            Some((Symbol::Equals, span)) => {
+                return Err(self.error(span, ParseErrorReason::NotAnEquation));
+            }
+            Some(_) => {
+                self.position -= 1;
+                return Err(self.unexpected());
+            }
+        }
+    }
+
+    fn parse_expression(mut self) -> Result<Expr, ParseError> {
+        let expr = self.parse_sum()?;
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+        return Ok(expr);
+    }
+
+    fn parse_sum(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_product()?;
+        while let Some(symbol @ (Symbol::Add | Symbol::Sub)) = self.peek() {
+            self.position += 1;
+            let rhs = self.parse_product()?;
+            let span = lhs.span.start..rhs.span.end;
+            let kind = if symbol == Symbol::Add {
+                ExprKind::Add(Box::new(lhs), Box::new(rhs))
+            } else {
+                ExprKind::Sub(Box::new(lhs), Box::new(rhs))
+            };
+            lhs = Expr { kind, span };
+        }
+        return Ok(lhs);
+    }
+
+    fn parse_product(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            let is_div = match self.peek() {
+                Some(Symbol::Mul) => {
+                    self.position += 1;
+                    false
+                }
+                Some(Symbol::Div) => {
+                    self.position += 1;
+                    true
+                }
+                // Implicit multiplication, e.g. "m a" or "2 (a + b)"
+                Some(Symbol::Constant(_) | Symbol::Identifier | Symbol::Open) => false,
+                _ => return Ok(lhs),
+            };
+            let rhs = self.parse_unary()?;
+            let span = lhs.span.start..rhs.span.end;
+            let kind = if is_div {
+                ExprKind::Div(Box::new(lhs), Box::new(rhs))
+            } else {
+                ExprKind::Mul(Box::new(lhs), Box::new(rhs))
+            };
+            lhs = Expr { kind, span };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Some(Symbol::Add) => {
+                self.position += 1;
+                return self.parse_unary();
+            }
+            Some(Symbol::Sub) => {
+                let start = self.next_token().expect("token has been peeked").1.start;
+                let operand = self.parse_unary()?;
+                let span = start..operand.span.end;
+                return Ok(Expr {
+                    kind: ExprKind::Neg(Box::new(operand)),
+                    span,
+                });
+            }
+            _ => return self.parse_power(),
+        }
+    }
+
+    fn parse_power(&mut self) -> Result<Expr, ParseError> {
+        let base = self.parse_primary()?;
+        if self.peek() != Some(Symbol::Pow) {
+            return Ok(base);
+        }
+        self.position += 1;
+
+        // Parsing the exponent as unary expression makes "^" right-associative
+        let exponent = self.parse_unary()?;
+        let span = base.span.start..exponent.span.end;
+        return Ok(Expr {
+            kind: ExprKind::Pow(Box::new(base), Box::new(exponent)),
+            span,
+        });
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let Some((symbol, span)) = self.next_token() else {
+            self.position -= 1;
+            return Err(self.unexpected());
+        };
+        match symbol {
+            Symbol::Constant(quantity) => {
+                return Ok(Expr {
+                    kind: ExprKind::Constant(quantity),
+                    span,
+                });
+            }
+            Symbol::Identifier => return self.parse_identifier(span),
+            Symbol::Open => {
+                let (mut expr, end) = self.parse_brackets(span.clone())?;
+                expr.span = span.start..end;
+                return Ok(expr);
+            }
+            Symbol::Close => return Err(self.error(span, ParseErrorReason::UnbalancedBrackets)),
+            _ => {
+                self.position -= 1;
+                return Err(self.unexpected());
+            }
+        }
+    }
+
+    /**
+    Parses the content of a bracket whose opening bracket at `open` has
+    already been consumed. Returns the content and the end of the closing
+    bracket.
+     */
+    fn parse_brackets(&mut self, open: Range<usize>) -> Result<(Expr, usize), ParseError> {
+        let expr = self.parse_sum()?;
+        match self.next_token() {
+            Some((Symbol::Close, close)) => return Ok((expr, close.end)),
+            Some(_) => {
+                self.position -= 1;
+                return Err(self.unexpected());
+            }
+            None => return Err(self.error(open, ParseErrorReason::UnbalancedBrackets)),
+        }
+    }
+
+    fn parse_identifier(&mut self, span: Range<usize>) -> Result<Expr, ParseError> {
+        let name = &self.input[span.clone()];
+        if let Some(index) = self.names.iter().position(|variable| *variable == name) {
+            return Ok(Expr {
+                kind: ExprKind::Variable(index),
+                span,
+            });
+        }
+        let function = FUNCTIONS.iter().find(|(function, _)| *function == name);
+        if let (Some((_, function)), Some(Symbol::Open)) = (function, self.peek()) {
+            let open = self.next_token().expect("token has been peeked").1;
+            let (argument, end) = self.parse_brackets(open)?;
+            let span = span.start..end;
+            return Ok(Expr {
+                kind: ExprKind::Function(*function, Box::new(argument)),
+                span,
+            });
+        }
+        return Err(self.error(span, ParseErrorReason::UnknownIdentifier));
+    }
+}
+
+/// Unit of an expression and its value, if it is a constant.
+struct Value {
+    unit: Unit,
+    constant: Option<f64>,
+}
+
+/// Determines the units of the expressions of an equation.
+struct Checker<'a> {
+    input: &'a str,
+    units: &'a [Unit],
+}
+
+impl Checker<'_> {
+    fn error(&self, span: Range<usize>, reason: ParseErrorReason) -> ParseError {
+        return error(self.input, span, reason);
+    }
+
+    fn check(&self, expr: &Expr) -> Result<Value, ParseError> {
+        match &expr.kind {
+            ExprKind::Constant(quantity) => {
+                let constant = (quantity.value.im == 0.0).then_some(quantity.value.re);
+                return Ok(Value {
+                    unit: quantity.unit,
+                    constant,
+                });
+            }
+            ExprKind::Variable(index) => {
+                return Ok(Value {
+                    unit: self.units[*index],
+                    constant: None,
+                });
+            }
+            ExprKind::Neg(operand) => {
+                let operand = self.check(operand)?;
+                return Ok(Value {
+                    unit: operand.unit,
+                    constant: operand.constant.map(|value| -value),
+                });
+            }
+            ExprKind::Add(lhs, rhs) | ExprKind::Sub(lhs, rhs) => {
+                let is_add = matches!(expr.kind, ExprKind::Add(..));
+                let lhs = self.check(lhs)?;
+                let rhs_value = self.check(rhs)?;
+                if lhs.unit != rhs_value.unit {
+                    return Err(self.error(
+                        rhs.span.clone(),
+                        UnitsNotEqual(lhs.unit, rhs_value.unit).into(),
+                    ));
+                }
+                let constant = lhs.constant.zip(rhs_value.constant).map(|(a, b)| {
+                    if is_add {
+                        return a + b;
+                    }
+                    return a - b;
+                });
+                return Ok(Value {
+                    unit: lhs.unit,
+                    constant,
+                });
+            }
+            ExprKind::Mul(lhs, rhs) => {
+                let lhs = self.check(lhs)?;
+                let rhs = self.check(rhs)?;
+                return Ok(Value {
+                    unit: lhs.unit * rhs.unit,
+                    constant: lhs.constant.zip(rhs.constant).map(|(a, b)| a * b),
+                });
+            }
+            ExprKind::Div(lhs, rhs) => {
+                let lhs = self.check(lhs)?;
+                let rhs = self.check(rhs)?;
+                return Ok(Value {
+                    unit: lhs.unit / rhs.unit,
+                    constant: lhs.constant.zip(rhs.constant).map(|(a, b)| a / b),
+                });
+            }
+            ExprKind::Pow(base, exponent) => {
+                let base_value = self.check(base)?;
+                let exponent_value = self.check(exponent)?;
+                if !exponent_value.unit.is_dimensionless() {
+                    return Err(self.error(
+                        exponent.span.clone(),
+                        UnitsNotEqual(Unit::default(), exponent_value.unit).into(),
+                    ));
+                }
+                let constant = base_value
+                    .constant
+                    .zip(exponent_value.constant)
+                    .map(|(a, b)| a.powf(b));
+                if base_value.unit.is_dimensionless() {
+                    return Ok(Value {
+                        unit: Unit::default(),
+                        constant,
+                    });
+                }
+
+                // A unit can only be raised to a constant rational power
+                let Some(exponent_ratio) = exponent_value
+                    .constant
+                    .and_then(Ratio::<i32>::approximate_float)
+                else {
+                    return Err(
+                        self.error(exponent.span.clone(), ParseErrorReason::InvalidExponent)
+                    );
+                };
+                let Some(unit) = base_value.unit.checked_powi(*exponent_ratio.numer()) else {
+                    return Err(self.error(expr.span.clone(), ParseErrorReason::InvalidExponent));
+                };
+                let unit = unit.try_nthroot(*exponent_ratio.denom()).map_err(|err| {
+                    self.error(expr.span.clone(), ParseErrorReason::RootError(err))
+                })?;
+                return Ok(Value { unit, constant });
+            }
+            ExprKind::Function(function, argument) => {
+                let argument_value = self.check(argument)?;
+                match function {
+                    Function::Dimensionless(function) => {
+                        if !argument_value.unit.is_dimensionless() {
+                            return Err(self.error(
+                                argument.span.clone(),
+                                UnitsNotEqual(Unit::default(), argument_value.unit).into(),
+                            ));
+                        }
+                        return Ok(Value {
+                            unit: Unit::default(),
+                            constant: argument_value.constant.map(function),
+                        });
+                    }
+                    Function::Root(n) => {
+                        let unit = argument_value.unit.try_nthroot(*n).map_err(|err| {
+                            self.error(argument.span.clone(), ParseErrorReason::RootError(err))
+                        })?;
+                        return Ok(Value {
+                            unit,
+                            constant: argument_value
+                                .constant
+                                .map(|value| value.powf(1.0 / *n as f64)),
+                        });
+                    }
+                    Function::Abs => {
+                        return Ok(Value {
+                            unit: argument_value.unit,
+                            constant: argument_value.constant.map(f64::abs),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+Exponents of the unit of an expression which contains the unknown variable
+`x` of [`solve_unit`]: `base + coefficient * x`.
+ */
+#[derive(Debug, Clone, PartialEq)]
+struct Exponents {
+    base: [Ratio<i64>; 7],
+    coefficient: Ratio<i64>,
+    /// Value of the expression, if it is a constant.
+    constant: Option<f64>,
+}
+
+impl Exponents {
+    fn known(unit: Unit, constant: Option<f64>) -> Self {
+        let exponents: [i32; 7] = unit.into();
+        return Exponents {
+            base: exponents.map(|exponent| Ratio::from_integer(exponent.into())),
+            coefficient: Ratio::from_integer(0),
+            constant,
+        };
+    }
+
+    /**
+    Returns `self * factor` (i.e. the exponents of `self^factor`) or `None` if
+    an exponent overflows.
+     */
+    fn scale(&self, factor: Ratio<i64>) -> Option<Self> {
+        let mut base = self.base;
+        for exponent in base.iter_mut() {
+            *exponent = exponent.checked_mul(&factor)?;
+        }
+        return Some(Exponents {
+            base,
+            coefficient: self.coefficient.checked_mul(&factor)?,
+            constant: None,
+        });
+    }
+
+    /**
+    Returns `self + other` (i.e. the exponents of `self * other`) or `None` if
+    an exponent overflows.
+     */
+    fn add(&self, other: &Exponents) -> Option<Self> {
+        let mut base = self.base;
+        for (exponent, other) in base.iter_mut().zip(other.base) {
+            *exponent = exponent.checked_add(&other)?;
+        }
+        return Some(Exponents {
+            base,
+            coefficient: self.coefficient.checked_add(&other.coefficient)?,
+            constant: None,
+        });
+    }
+
+    /**
+    Converts the exponents into an [`Unit`]. Returns a [`RootError`] if one
+    of the exponents is fractional and [`ParseErrorReason::InvalidExponent`]
+    if one of the exponents does not fit into an [`i32`].
+     */
+    fn into_unit(self) -> Result<Unit, ParseErrorReason> {
+        let denominator = self
+            .base
+            .iter()
+            .fold(1, |multiple: i64, exponent| multiple.lcm(exponent.denom()));
+        let mut numerators = [0; 7];
+        for (numerator, exponent) in numerators.iter_mut().zip(self.base) {
+            *numerator = exponent
+                .checked_mul(&Ratio::from_integer(denominator))
+                .and_then(|exponent| i32::try_from(exponent.to_integer()).ok())
+                .ok_or(ParseErrorReason::InvalidExponent)?;
+        }
+        if denominator != 1 {
+            return Err(ParseErrorReason::RootError(RootError {
+                n: i32::try_from(denominator).map_err(|_| ParseErrorReason::InvalidExponent)?,
+                unit: numerators.into(),
+            }));
+        }
+        return Ok(numerators.into());
+    }
+}
+
+/**
+Determines the unit of the unknown variable in [`solve_unit`]. The unknown
+variable has the index zero.
+ */
+struct Solver {
+    /// Exponents of the unknown and the span of the expression they stem from.
+    solution: Option<(Exponents, Range<usize>)>,
+    /// Span of the first expression whose exponents overflow.
+    overflow: Option<Range<usize>>,
+}
+
+impl Solver {
+    /**
+    Requires the exponents `a` and `b` (stemming from the expression at
+    `span`) to be equal. If no solution has been found yet and this equation
+    contains the unknown, it is solved for the unknown.
+     */
+    fn constrain(&mut self, a: &Exponents, b: &Exponents, span: Range<usize>) {
+        if self.solution.is_some() {
+            return;
+        }
+        let Some(coefficient) = a.coefficient.checked_sub(&b.coefficient) else {
+            self.overflow.get_or_insert(span);
+            return;
+        };
+        if coefficient == Ratio::from_integer(0) {
+            return;
+        }
+        let mut base = [Ratio::from_integer(0); 7];
+        for (index, exponent) in base.iter_mut().enumerate() {
+            let Some(solution) = b.base[index]
+                .checked_sub(&a.base[index])
+                .and_then(|difference| difference.checked_div(&coefficient))
+            else {
+                self.overflow.get_or_insert(span);
+                return;
+            };
+            *exponent = solution;
+        }
+        let solution = Exponents {
+            base,
+            coefficient: Ratio::from_integer(0),
+            constant: None,
+        };
+        self.solution = Some((solution, span));
+    }
+
+    /**
+    Returns `exponents` or, if they overflowed, dimensionless exponents and
+    stores `span` as the location of the overflow.
+     */
+    fn checked(&mut self, exponents: Option<Exponents>, span: &Range<usize>) -> Exponents {
+        return exponents.unwrap_or_else(|| {
+            self.overflow.get_or_insert(span.clone());
+            return Exponents::known(Unit::default(), None);
+        });
+    }
+
+    fn exponents(&mut self, expr: &Expr, units: &[Unit]) -> Exponents {
+        let dimensionless = Exponents::known(Unit::default(), None);
+        match &expr.kind {
+            ExprKind::Constant(quantity) => {
+                let constant = (quantity.value.im == 0.0).then_some(quantity.value.re);
+                return Exponents::known(quantity.unit, constant);
+            }
+            ExprKind::Variable(0) => {
+                return Exponents {
+                    coefficient: Ratio::from_integer(1),
+                    ..dimensionless
+                };
+            }
+            ExprKind::Variable(index) => return Exponents::known(units[*index], None),
+            ExprKind::Neg(operand) => {
+                let operand = self.exponents(operand, units);
+                return Exponents {
+                    constant: operand.constant.map(|value| -value),
+                    ..operand
+                };
+            }
+            ExprKind::Add(lhs, rhs) | ExprKind::Sub(lhs, rhs) => {
+                let is_add = matches!(expr.kind, ExprKind::Add(..));
+                let lhs = self.exponents(lhs, units);
+                let rhs = self.exponents(rhs, units);
+                self.constrain(&lhs, &rhs, expr.span.clone());
+                let constant = lhs.constant.zip(rhs.constant).map(|(a, b)| {
+                    if is_add {
+                        return a + b;
+                    }
+                    return a - b;
+                });
+                return Exponents { constant, ..lhs };
+            }
+            ExprKind::Mul(lhs, rhs) => {
+                let lhs = self.exponents(lhs, units);
+                let rhs = self.exponents(rhs, units);
+                let exponents = self.checked(lhs.add(&rhs), &expr.span);
+                return Exponents {
+                    constant: lhs.constant.zip(rhs.constant).map(|(a, b)| a * b),
+                    ..exponents
+                };
+            }
+            ExprKind::Div(lhs, rhs) => {
+                let lhs = self.exponents(lhs, units);
+                let rhs = self.exponents(rhs, units);
+                let exponents = rhs
+                    .scale(Ratio::from_integer(-1))
+                    .and_then(|rhs| lhs.add(&rhs));
+                let exponents = self.checked(exponents, &expr.span);
+                return Exponents {
+                    constant: lhs.constant.zip(rhs.constant).map(|(a, b)| a / b),
+                    ..exponents
+                };
+            }
+            ExprKind::Pow(base, exponent) => {
+                let base = self.exponents(base, units);
+                let exponent_exponents = self.exponents(exponent, units);
+                self.constrain(&exponent_exponents, &dimensionless, exponent.span.clone());
+                let constant = base
+                    .constant
+                    .zip(exponent_exponents.constant)
+                    .map(|(a, b)| a.powf(b));
+                let ratio = exponent_exponents
+                    .constant
+                    .and_then(Ratio::<i32>::approximate_float);
+                match ratio {
+                    Some(ratio) => {
+                        let ratio = Ratio::new((*ratio.numer()).into(), (*ratio.denom()).into());
+                        let exponents = self.checked(base.scale(ratio), &expr.span);
+                        return Exponents {
+                            constant,
+                            ..exponents
+                        };
+                    }
+                    None => {
+                        // A variable exponent requires a dimensionless base
+                        self.constrain(&base, &dimensionless, expr.span.clone());
+                        return Exponents {
+                            constant,
+                            ..dimensionless
+                        };
+                    }
+                }
+            }
+            ExprKind::Function(function, argument) => {
+                let argument_exponents = self.exponents(argument, units);
+                match function {
+                    Function::Dimensionless(function) => {
+                        self.constrain(&argument_exponents, &dimensionless, argument.span.clone());
+                        return Exponents {
+                            constant: argument_exponents.constant.map(function),
+                            ..dimensionless
+                        };
+                    }
+                    Function::Root(n) => {
+                        let exponents = argument_exponents.scale(Ratio::new(1, (*n).into()));
+                        let exponents = self.checked(exponents, &expr.span);
+                        return Exponents {
+                            constant: argument_exponents
+                                .constant
+                                .map(|value| value.powf(1.0 / *n as f64)),
+                            ..exponents
+                        };
+                    }
+                    Function::Abs => {
+                        return Exponents {
+                            constant: argument_exponents.constant.map(f64::abs),
+                            ..argument_exponents
+                        };
+                    }
+                }
+            }
+        }
+    }
+}
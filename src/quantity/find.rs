@@ -0,0 +1,234 @@
+/*!
+This module provides the [`find_quantities`] function, which extracts all
+quantities embedded in arbitrary text such as requirement documents or test
+reports. Like the [`FromStr`] implementation of [`DynQuantity`], it is only
+available if the `from_str` feature is enabled.
+
+A quantity in a text consists of a number followed by a unit expression, e.g.
+`5 kW`, `-20 °C`, `9.81 m/s^2`, `3000 rpm` or `5 %`. Numbers without a unit
+(e.g. years or counts) are skipped, as well as all words which are not
+quantities. Each recognized quantity is parsed using the
+[`FromStr`] implementation of [`DynQuantity`].
+
+# Examples
+
+```
+use dyn_quantity::{find_quantities, DynQuantity, PredefUnit, Unit};
+
+let text = "The motor delivers 5 kW at 3000 rpm and 400 V (see page 12).";
+let quantities: Vec<(DynQuantity<f64>, _)> = find_quantities(text);
+assert_eq!(quantities.len(), 3);
+
+let (quantity, span) = &quantities[0];
+assert_eq!(&text[span.clone()], "5 kW");
+assert_eq!(quantity.value, 5000.0);
+assert_eq!(quantity.unit, Unit::from(PredefUnit::Power));
+
+let (quantity, span) = &quantities[1];
+assert_eq!(&text[span.clone()], "3000 rpm");
+assert_eq!(quantity.value, 50.0);
+
+let (quantity, span) = &quantities[2];
+assert_eq!(&text[span.clone()], "400 V");
+assert_eq!(quantity.unit, Unit::from(PredefUnit::ElectricVoltage));
+```
+
+# Recognized syntax
+
+- The number must start at a word boundary and may be preceded by a sign. It
+  is detected by the same lexer as used by [`FromStr`], hence all number
+  literals supported there can be used (e.g. `1.5e-3`, `1E3`, `1_000`, `0x1F`
+  or `2.5*10^3`).
+- The unit expression directly follows the number, optionally separated by
+  spaces. It consists of one or more units (e.g. `kW`, `m^2`, `°C`, `%`), which
+  are combined by `*` or `/` (e.g. `N*m`, `m / s^2`). Implicit multiplication of
+  units (e.g. `V A` or `VA`) is not recognized, since it cannot be distinguished
+  from the surrounding prose.
+- To avoid false positives, the words `am`, `as`, `at`, `in` and `pm` are not
+  interpreted as units (attometer, attosecond, attotonne, inch and
+  picometer), e.g. in "5 V in series".
+*/
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use dyn_quantity_lexer::Token;
+
+use super::tokenize::{Operator, TokenKind, tokenize};
+use super::{DynQuantity, F64RealOrComplex};
+
+/// Common words which the lexer would interpret as units.
+const STOP_WORDS: [&str; 5] = ["am", "as", "at", "in", "pm"];
+
+/**
+Returns all quantities found in `text` together with their byte spans. See the
+[module-level documentation](crate::quantity::find) for the recognized syntax
+and an example.
+ */
+pub fn find_quantities<V: F64RealOrComplex>(text: &str) -> Vec<(DynQuantity<V>, Range<usize>)> {
+    let mut quantities = Vec::new();
+    let mut position = 0;
+    while position < text.len() {
+        let Some(number) = find_number(text, position) else {
+            break;
+        };
+
+        // Collect the ends of all unit words following the number. Each end is
+        // a possible end of the quantity; the longest parsable one is used.
+        let mut ends = Vec::new();
+        let mut cursor = skip_spaces(text, number.end);
+        if let Some(end) = unit_word_end(text, cursor) {
+            ends.push(end);
+            cursor = end;
+            loop {
+                let operator = skip_spaces(text, cursor);
+                if !text[operator..].starts_with(['*', '/']) {
+                    break;
+                }
+                match unit_word_end(text, skip_spaces(text, operator + 1)) {
+                    Some(end) => {
+                        ends.push(end);
+                        cursor = end;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        let found = ends.iter().rev().find_map(|end| {
+            let span = number.start..*end;
+            return DynQuantity::<V>::from_str(&text[span.clone()])
+                .ok()
+                .map(|quantity| (quantity, span));
+        });
+        match found {
+            Some((quantity, span)) => {
+                position = span.end;
+                quantities.push((quantity, span));
+            }
+            None => position = number.end,
+        }
+    }
+    return quantities;
+}
+
+/**
+Returns the span of the first number in `text` which starts at or after
+`position` and at a word boundary, including a leading sign.
+ */
+fn find_number(text: &str, position: usize) -> Option<Range<usize>> {
+    let mut previous: Option<char> = text[..position].chars().next_back();
+    for (offset, c) in text[position..].char_indices() {
+        let start = position + offset;
+        let rest = &text[start..];
+        let starts_number =
+            c.is_ascii_digit() || (c == '.' && rest[1..].starts_with(|c: char| c.is_ascii_digit()));
+        if !starts_number {
+            previous = Some(c);
+            continue;
+        }
+
+        let start = match previous {
+            None => start,
+            Some(c) if is_boundary(c) => start,
+            // Include the sign if it is located at a word boundary
+            Some('-' | '+') => {
+                if text[..start - 1]
+                    .chars()
+                    .next_back()
+                    .is_none_or(is_boundary)
+                {
+                    start - 1
+                } else {
+                    start
+                }
+            }
+            _ => {
+                previous = Some(c);
+                continue;
+            }
+        };
+        let len = number_len(rest);
+        if len == 0 {
+            previous = Some(c);
+            continue;
+        }
+        let end = text.len() - rest.len() + len;
+
+        // Numbers with thousands separators or version numbers (e.g. "1,000"
+        // or "1.2.3") are skipped entirely
+        let mut after = text[end..].chars();
+        if matches!(after.next(), Some('.' | ','))
+            && after.next().is_some_and(|c| c.is_ascii_digit())
+        {
+            previous = Some('.');
+            continue;
+        }
+        return Some(start..end);
+    }
+    return None;
+}
+
+/// Characters after which a number may start.
+fn is_boundary(c: char) -> bool {
+    return c.is_whitespace() || "([{<=~:;\"'".contains(c);
+}
+
+/**
+Returns the length of the number at the start of `s` in bytes, including a
+following power of ten (e.g. `*10^3`). Returns zero if `s` does not start with
+a number. The number is detected by the lexer, hence all number literals
+supported by the [`FromStr`] implementation of [`DynQuantity`] (e.g. `1E3`,
+`1_000` or `0x1F`) are recognized.
+ */
+fn number_len(s: &str) -> usize {
+    let mut tokens = dyn_quantity_lexer::lex(s).into_iter();
+    let mut end = match tokens.next() {
+        Some((Ok(Token::Real(_) | Token::Imag(_)), span)) if span.start == 0 => span.end,
+        _ => return 0,
+    };
+    for (token, span) in tokens {
+        if !matches!(token, Ok(Token::PowerOfTen(_))) {
+            break;
+        }
+        end = span.end;
+    }
+    return end;
+}
+
+/// Skips spaces and tabs (but not line breaks) starting from `position`.
+fn skip_spaces(text: &str, position: usize) -> usize {
+    return text.len() - text[position..].trim_start_matches([' ', '\t']).len();
+}
+
+/**
+Returns the end of the unit word starting at `position`, if the word consists
+of a single unit (or `%`).
+ */
+fn unit_word_end(text: &str, position: usize) -> Option<usize> {
+    let rest = &text[position..];
+    let len = rest
+        .find(|c: char| c.is_whitespace() || "*/,;:!?()[]{}\"'".contains(c) || c == '.')
+        .unwrap_or(rest.len());
+    let word = &rest[..len];
+    if word.is_empty() || STOP_WORDS.contains(&word) {
+        return None;
+    }
+    // Units within a word are not multiplied implicitly, since this leads to
+    // false positives such as "Watts" (W * at * ts)
+    let mut tokens = tokenize(word);
+    let is_unit = matches!(
+        (tokens.next(), tokens.next()),
+        (
+            Some((
+                TokenKind::Unit(_) | TokenKind::Operator(Operator::Percent),
+                _
+            )),
+            None
+        )
+    );
+    if !is_unit {
+        return None;
+    }
+    return Some(position + len);
+}
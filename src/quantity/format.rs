@@ -0,0 +1,1088 @@
+/*!
+This module contains the [`QuantityFormatter`], which offers more control over
+the string representation of a [`DynQuantity`] than its
+[`Display`](std::fmt::Display) implementation: the number of decimal places or
+significant digits, scientific or engineering notation, the separator between
+value and unit, fraction-style units such as `m/s^2`, named derived units such
+as `N` or `V`, automatic SI prefixes such as `mA` and the order in which the
+base units are written. Besides plain text, the output can be written as
+Unicode with superscript exponents, as HTML or as LaTeX using the macros of the
+siunitx package, see [`Markup`]. A series of quantities can be scaled to a
+common unit via [`QuantityFormatter::scale_series`].
+
+The [`Display`](std::fmt::Display) implementation of [`DynQuantity`] uses the
+default [`QuantityFormatter`] and honours the standard formatting flags: the
+precision (e.g. `{:.3}`), the sign flag (`{:+}`) as well as width, fill and
+alignment (e.g. `{:>12}` or `{:*<12}`). Without an alignment, the output is
+aligned to the right like a number. Scientific notation is available via the
+[`LowerExp`](std::fmt::LowerExp) and [`UpperExp`](std::fmt::UpperExp)
+implementations (`{:e}` and `{:E}`).
+
+# Examples
+
+```
+use std::str::FromStr;
+use dyn_quantity::{DynQuantity, NamedUnit, QuantityFormatter};
+
+let quantity = DynQuantity::<f64>::from_str("9.81 m/s^2").unwrap();
+assert_eq!(quantity.to_string(), "9.81 s^-2 m");
+assert_eq!(format!("{quantity:.1}"), "9.8 s^-2 m");
+assert_eq!(format!("{quantity:.2e}"), "9.81e0 s^-2 m");
+assert_eq!(format!("{quantity:>14}"), "   9.81 s^-2 m");
+
+let formatter = QuantityFormatter::new().fraction(true).significant_digits(2);
+assert_eq!(formatter.format(&quantity).to_string(), "9.8 m/s^2");
+
+let force = DynQuantity::<f64>::from_str("9.81 kg m s^-2").unwrap();
+let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+assert_eq!(formatter.format(&force).to_string(), "9.81 N");
+```
+*/
+
+use std::fmt::{Alignment, Formatter, Write};
+use std::ops::{Div, Mul};
+
+use num::Complex;
+
+use super::{DynQuantity, F64RealOrComplex};
+use crate::error::ConversionError;
+use crate::unit::{BaseUnit, NamedUnit, Unit};
+
+/**
+Notation of the numerical value of a quantity, see
+[`QuantityFormatter::notation`].
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Positional notation, e.g. `1234.5` or `0.000012`.
+    #[default]
+    Decimal,
+    /// Scientific notation with a single digit in front of the decimal point,
+    /// e.g. `1.2345e3` or `1.2e-5`.
+    Scientific,
+    /// Scientific notation where the exponent is a multiple of three, e.g.
+    /// `1.2345e3` or `12e-6`.
+    Engineering,
+}
+
+/**
+Markup of the string representation of a quantity or unit, see
+[`QuantityFormatter::markup`]. The examples show the acceleration
+`9.81 m/s^2` and the value `1.5e3` in scientific notation.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Markup {
+    /// Plain ASCII text (except for `µ` and `Ω`), e.g. `9.81 m s^-2` and
+    /// `1.5e3`. This is the markup of the [`Display`](std::fmt::Display)
+    /// implementation of [`DynQuantity`].
+    #[default]
+    Plain,
+    /// Unicode text with superscript exponents and `·` between the factors,
+    /// e.g. `9.81 m·s⁻²` and `1.5×10³`.
+    Unicode,
+    /// HTML with `<sup>` exponents, e.g. `9.81 m&middot;s<sup>-2</sup>` and
+    /// `1.5&times;10<sup>3</sup>`.
+    Html,
+    /// LaTeX macros of the siunitx package (version 3), e.g.
+    /// `\qty{9.81}{\metre\per\second\squared}`. Dimensionless quantities
+    /// are written as `\num{1.5e3}` and units as `\unit{\metre\per\second}`.
+    Latex,
+}
+
+/**
+A builder which defines how a [`DynQuantity`] is converted into a string. The
+quantity is formatted via the [`Display`](std::fmt::Display) implementation of
+the [`FormattedQuantity`] returned by [`QuantityFormatter::format`].
+
+The default formatter produces the same output as the
+[`Display`](std::fmt::Display) implementation of [`DynQuantity`]: the value in
+decimal notation, followed by a space and the base units with nonzero exponents
+in the order s, m, kg, A, K, mol, cd (e.g. `9.81 s^-2 m`). Complex values are
+written in brackets, e.g. `(1+2i) A`.
+
+# Examples
+
+```
+use std::str::FromStr;
+use dyn_quantity::{BaseUnit, DynQuantity, Notation, QuantityFormatter};
+
+let quantity = DynQuantity::<f64>::from_str("12345.678 kg m / s^2").unwrap();
+
+let formatter = QuantityFormatter::new().precision(1);
+assert_eq!(formatter.format(&quantity).to_string(), "12345.7 s^-2 m kg");
+
+let formatter = QuantityFormatter::new()
+    .notation(Notation::Engineering)
+    .significant_digits(3)
+    .separator("")
+    .unit_order(&[BaseUnit::Kilogram, BaseUnit::Meter]);
+assert_eq!(formatter.format(&quantity).to_string(), "12.3e3kg m s^-2");
+
+let formatter = QuantityFormatter::new().fraction(true);
+assert_eq!(formatter.format(&quantity).to_string(), "12345.678 m kg/s^2");
+```
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantityFormatter {
+    precision: Option<usize>,
+    significant_digits: Option<usize>,
+    notation: Notation,
+    uppercase_exponent: bool,
+    separator: String,
+    fraction: bool,
+    named_units: Vec<NamedUnit>,
+    si_prefix: bool,
+    markup: Markup,
+    unit_order: [BaseUnit; 7],
+}
+
+impl Default for QuantityFormatter {
+    fn default() -> Self {
+        return QuantityFormatter {
+            precision: None,
+            significant_digits: None,
+            notation: Notation::Decimal,
+            uppercase_exponent: false,
+            separator: " ".to_owned(),
+            fraction: false,
+            named_units: Vec::new(),
+            si_prefix: false,
+            markup: Markup::Plain,
+            unit_order: BaseUnit::ALL,
+        };
+    }
+}
+
+impl QuantityFormatter {
+    /// Returns the default formatter, see the docstring of [`QuantityFormatter`].
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /**
+    Sets the number of decimal places of the value (of the mantissa in case of
+    scientific or engineering notation). This overrides
+    [`QuantityFormatter::significant_digits`]. The precision of the format
+    string (e.g. `{:.3}`) takes priority over this setting.
+     */
+    pub fn precision(mut self, decimal_places: usize) -> Self {
+        self.precision = Some(decimal_places);
+        self.significant_digits = None;
+        return self;
+    }
+
+    /**
+    Sets the number of significant digits of the value, e.g. `1230` or `0.0123`
+    for three significant digits. Trailing zeros are kept. This overrides
+    [`QuantityFormatter::precision`].
+     */
+    pub fn significant_digits(mut self, digits: usize) -> Self {
+        self.significant_digits = Some(digits.max(1));
+        self.precision = None;
+        return self;
+    }
+
+    /// Sets the notation of the value, see [`Notation`].
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        return self;
+    }
+
+    /**
+    If set to `true`, the exponent of the scientific or engineering notation is
+    written with an uppercase `E` (e.g. `1.5E3`). Defaults to `false`.
+     */
+    pub fn uppercase_exponent(mut self, uppercase: bool) -> Self {
+        self.uppercase_exponent = uppercase;
+        return self;
+    }
+
+    /**
+    Sets the separator between value and unit, e.g. `""` for `5m` or a
+    non-breaking space. Defaults to a space. The separator is omitted for
+    dimensionless quantities.
+     */
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        return self;
+    }
+
+    /**
+    If set to `true`, base units with negative exponents are written as a
+    denominator, e.g. `m/s^2` instead of `s^-2 m` or `kg m^2/(s^3 A)` for
+    multiple units in the denominator. Defaults to `false`.
+     */
+    pub fn fraction(mut self, fraction: bool) -> Self {
+        self.fraction = fraction;
+        return self;
+    }
+
+    /**
+    Sets the named derived units (e.g. `N` or `V`) which may be used to write
+    the unit, see [`Unit::decompose`] for how the simplest representation is
+    chosen. The order of `named_units` states the preference in case of ties,
+    and leaving out a named unit prevents its use (e.g. to get `N m` instead of
+    `J`). Named units are written in front of the remaining base units.
+    Defaults to an empty list, i.e. only base units are written.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{DynQuantity, NamedUnit, QuantityFormatter};
+
+    let quantity = DynQuantity::<f64>::from_str("2 V/m").unwrap();
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    assert_eq!(formatter.format(&quantity).to_string(), "2 V m^-1");
+    let formatter = formatter.fraction(true);
+    assert_eq!(formatter.format(&quantity).to_string(), "2 V/m");
+
+    let torque = DynQuantity::<f64>::from_str("3 N m").unwrap();
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    assert_eq!(formatter.format(&torque).to_string(), "3 J");
+    let formatter = QuantityFormatter::new().named_units(&[NamedUnit::Newton]);
+    assert_eq!(formatter.format(&torque).to_string(), "3 N m");
+    ```
+     */
+    pub fn named_units(mut self, named_units: &[NamedUnit]) -> Self {
+        self.named_units = named_units.to_vec();
+        return self;
+    }
+
+    /**
+    If set to `true`, the value is scaled with the SI prefix (from `q` to `Q`,
+    multiples of three only) which puts the larger part of the value into the
+    range [1, 1000), e.g. `1.2 mA` instead of `0.0012 A`. The prefix is applied
+    to the first factor of the unit with a positive exponent, which may also be
+    a named unit (`4.7 nF`). For a factor with exponent n, the range is
+    [1, 1000^n) instead, e.g. `12 mm^2` for `1.2e-5 m^2`, but `120000 mm^2`
+    for `0.12 m^2`. The prefix of the kilogram is replaced, e.g. `1.2 g`
+    instead of `0.0012 kg`. For complex values, the larger of the real and the
+    imaginary part determines the prefix. Dimensionless quantities, units
+    without a factor with positive exponent, zero and values which are not
+    finite are written without prefix. Defaults to `false`.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{DynQuantity, NamedUnit, QuantityFormatter};
+
+    let formatter = QuantityFormatter::new().si_prefix(true);
+    let current = DynQuantity::<f64>::from_str("0.0012 A").unwrap();
+    assert_eq!(formatter.format(&current).to_string(), "1.2 mA");
+
+    let formatter = formatter.named_units(&NamedUnit::ALL);
+    let capacitance = DynQuantity::<f64>::from_str("4.7e-9 F").unwrap();
+    assert_eq!(formatter.format(&capacitance).to_string(), "4.7 nF");
+    ```
+     */
+    pub fn si_prefix(mut self, si_prefix: bool) -> Self {
+        self.si_prefix = si_prefix;
+        return self;
+    }
+
+    /**
+    Sets the markup of the output, see [`Markup`]. The markup affects the
+    exponents of the unit and of the value in scientific or engineering
+    notation. In case of [`Markup::Latex`], the separator is ignored and
+    units with negative exponents are always written with `\per`, so that
+    siunitx decides how they are typeset (e.g. via its `per-mode` option). The
+    [`QuantityFormatter::fraction`] setting only moves these units behind
+    the others. Defaults to [`Markup::Plain`].
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{BaseUnit, DynQuantity, Markup, NamedUnit, QuantityFormatter};
+
+    let quantity = DynQuantity::<f64>::from_str("9.81 m/s^2").unwrap();
+    let formatter = QuantityFormatter::new().unit_order(&[BaseUnit::Meter]);
+
+    let unicode = formatter.clone().markup(Markup::Unicode);
+    assert_eq!(unicode.format(&quantity).to_string(), "9.81 m·s⁻²");
+
+    let html = formatter.clone().markup(Markup::Html);
+    assert_eq!(html.format(&quantity).to_string(), "9.81 m&middot;s<sup>-2</sup>");
+
+    let latex = formatter.markup(Markup::Latex);
+    assert_eq!(
+        latex.format(&quantity).to_string(),
+        r"\qty{9.81}{\metre\per\second\squared}"
+    );
+
+    // Units can be formatted on their own
+    let latex = latex.si_prefix(true).named_units(&NamedUnit::ALL);
+    let current = DynQuantity::<f64>::from_str("0.0012 A").unwrap();
+    assert_eq!(latex.format(&current).to_string(), r"\qty{1.2}{\milli\ampere}");
+    assert_eq!(latex.format_unit(&quantity.unit), r"\unit{\metre\per\second\squared}");
+    ```
+     */
+    pub fn markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        return self;
+    }
+
+    /**
+    Sets the order in which the base units are written. Base units which are
+    not contained in `order` are written afterwards in the default order
+    (s, m, kg, A, K, mol, cd).
+     */
+    pub fn unit_order(mut self, order: &[BaseUnit]) -> Self {
+        let mut unit_order = Vec::with_capacity(BaseUnit::ALL.len());
+        for base_unit in order.iter().chain(BaseUnit::ALL.iter()) {
+            if !unit_order.contains(base_unit) {
+                unit_order.push(*base_unit);
+            }
+        }
+        self.unit_order = unit_order.try_into().expect("contains all base units");
+        return self;
+    }
+
+    /**
+    Returns a [`FormattedQuantity`] which formats `quantity` according to the
+    settings of `self` via its [`Display`](std::fmt::Display) implementation.
+     */
+    pub fn format<'a, V: F64RealOrComplex>(
+        &'a self,
+        quantity: &'a DynQuantity<V>,
+    ) -> FormattedQuantity<'a, V> {
+        return FormattedQuantity {
+            formatter: self,
+            quantity,
+        };
+    }
+
+    /**
+    Formats a real value according to the notation, precision and significant
+    digits of `self`. Values which are not finite are formatted using the
+    [`Display`](std::fmt::Display) implementation of [`f64`].
+     */
+    pub(crate) fn format_value(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let exponent_char = if self.uppercase_exponent { 'E' } else { 'e' };
+        match self.notation {
+            Notation::Decimal => match (self.precision, self.significant_digits) {
+                (Some(precision), _) => return format!("{value:.precision$}"),
+                (None, Some(digits)) => {
+                    let (mantissa, exponent) = round_digits(value, Some(digits));
+                    let decimal_places = (digits as i32 - 1 - exponent).max(0) as usize;
+                    let sign = if value.is_sign_negative() { "-" } else { "" };
+                    return format!("{sign}{}", positional(&mantissa, exponent, decimal_places));
+                }
+                (None, None) => return value.to_string(),
+            },
+            Notation::Scientific => {
+                let string = match (self.precision, self.significant_digits) {
+                    (Some(precision), _) => format!("{value:.precision$e}"),
+                    (None, Some(digits)) => format!("{value:.*e}", digits - 1),
+                    (None, None) => format!("{value:e}"),
+                };
+                return string.replace('e', &exponent_char.to_string());
+            }
+            Notation::Engineering => {
+                let (mantissa, exponent) = match self.precision {
+                    Some(precision) => {
+                        // Number of significant digits needed for the given
+                        // number of decimal places of the mantissa
+                        let (_, exponent) = round_digits(value, None);
+                        let integer_digits = exponent - exponent.div_euclid(3) * 3 + 1;
+                        round_digits(value, Some(integer_digits as usize + precision))
+                    }
+                    None => round_digits(value, self.significant_digits),
+                };
+                let engineering_exponent = exponent.div_euclid(3) * 3;
+                let integer_digits = exponent - engineering_exponent + 1;
+                let decimal_places = match self.precision {
+                    Some(precision) => precision,
+                    None => (mantissa.len() as i32 - integer_digits).max(0) as usize,
+                };
+                let sign = if value.is_sign_negative() { "-" } else { "" };
+                return format!(
+                    "{sign}{}{exponent_char}{engineering_exponent}",
+                    positional(&mantissa, exponent - engineering_exponent, decimal_places)
+                );
+            }
+        }
+    }
+
+    /**
+    Formats the unit according to the settings of `self`. Returns an empty
+    string for dimensionless units. In case of [`Markup::Latex`], the unit is
+    wrapped into the siunitx macro `\unit`, e.g. `\unit{\metre\per\second}`.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{DynQuantity, Markup, NamedUnit, QuantityFormatter};
+
+    let unit = DynQuantity::<f64>::from_str("1 kg m^2/s^3/A").unwrap().unit;
+    let formatter = QuantityFormatter::new().fraction(true);
+    assert_eq!(formatter.format_unit(&unit), "m^2 kg/(s^3 A)");
+    let formatter = formatter.markup(Markup::Unicode);
+    assert_eq!(formatter.format_unit(&unit), "m²·kg/(s³·A)");
+    let formatter = formatter.named_units(&NamedUnit::ALL).markup(Markup::Html);
+    assert_eq!(formatter.format_unit(&unit), "V");
+    ```
+     */
+    pub fn format_unit(&self, unit: &Unit) -> String {
+        return self.unit_string(&self.factors(unit));
+    }
+
+    /**
+    Returns the factors the unit is written with, i.e. the named units (if any)
+    followed by the base units in [`QuantityFormatter::unit_order`]. Factors
+    with an exponent of zero are omitted.
+     */
+    fn factors(&self, unit: &Unit) -> Vec<Factor> {
+        let (named, residual) = if self.named_units.is_empty() {
+            (Vec::new(), *unit)
+        } else {
+            unit.decompose(&self.named_units)
+        };
+        return named
+            .into_iter()
+            .map(|(named_unit, exponent)| (named_unit.symbol(), exponent))
+            .chain(
+                self.unit_order
+                    .iter()
+                    .map(|base_unit| (base_unit.symbol(), residual.exponent(*base_unit))),
+            )
+            .filter(|(_, exponent)| *exponent != 0)
+            .map(|(symbol, exponent)| Factor {
+                prefix: 0,
+                symbol,
+                exponent,
+            })
+            .collect();
+    }
+
+    /**
+    Returns the unit written with the given factors, see
+    [`QuantityFormatter::format_unit`].
+     */
+    fn unit_string(&self, factors: &[Factor]) -> String {
+        let unit = self.join_factors(factors);
+        if self.markup == Markup::Latex && !unit.is_empty() {
+            return format!("\\unit{{{unit}}}");
+        }
+        return unit;
+    }
+
+    /**
+    Joins the factors of a unit, taking [`QuantityFormatter::fraction`] and
+    [`QuantityFormatter::markup`] into account. In case of [`Markup::Latex`],
+    the siunitx macros are returned without the surrounding `\unit`.
+     */
+    fn join_factors(&self, factors: &[Factor]) -> String {
+        if self.markup == Markup::Latex {
+            // siunitx expects the units with negative exponent behind `\per`.
+            let (numerator, denominator): (Vec<&Factor>, Vec<&Factor>) = if self.fraction {
+                factors.iter().partition(|factor| factor.exponent > 0)
+            } else {
+                (factors.iter().collect(), Vec::new())
+            };
+            return numerator
+                .into_iter()
+                .chain(denominator)
+                .map(|factor| factor.latex())
+                .collect();
+        }
+
+        let mut numerator = Vec::new();
+        let mut denominator = Vec::new();
+        for factor in factors.iter() {
+            if self.fraction && factor.exponent < 0 {
+                denominator.push(factor.to_string(self.markup, -factor.exponent));
+            } else {
+                numerator.push(factor.to_string(self.markup, factor.exponent));
+            }
+        }
+        let separator = match self.markup {
+            Markup::Plain => " ",
+            Markup::Unicode => "·",
+            Markup::Html => "&middot;",
+            Markup::Latex => unreachable!("handled above"),
+        };
+        let numerator = numerator.join(separator);
+        match denominator.len() {
+            0 => return numerator,
+            length => {
+                let numerator = if numerator.is_empty() {
+                    "1".to_owned()
+                } else {
+                    numerator
+                };
+                let denominator = denominator.join(separator);
+                if length == 1 {
+                    return format!("{numerator}/{denominator}");
+                } else {
+                    return format!("{numerator}/({denominator})");
+                }
+            }
+        }
+    }
+
+    /**
+    Applies [`QuantityFormatter::markup`] to a value formatted by
+    [`QuantityFormatter::format_value`], e.g. `1.5×10³` instead of `1.5e3`.
+     */
+    fn markup_value(&self, value: String) -> String {
+        let Some((mantissa, exponent)) = value.split_once(['e', 'E']) else {
+            return value;
+        };
+        match self.markup {
+            Markup::Plain | Markup::Latex => return value,
+            Markup::Unicode => return format!("{mantissa}×10{}", superscript(exponent)),
+            Markup::Html => return format!("{mantissa}&times;10<sup>{exponent}</sup>"),
+        }
+    }
+
+    /**
+    Prefixes the first factor with a positive exponent with the SI prefix
+    which brings `largest` into [1, 1000) (or [1, 1000^n) for a factor with
+    exponent n). Returns the power of ten values need to be scaled with, see
+    [`scale`]. Since the kilogram already carries a prefix, the prefix replaces
+    its `k`. If `largest` is zero or not finite, no prefix is applied.
+     */
+    fn apply_prefix(&self, largest: f64, factors: &mut [Factor]) -> i32 {
+        if largest == 0.0 || !largest.is_finite() {
+            return 0;
+        }
+        let Some(factor) = factors.iter_mut().find(|factor| factor.exponent > 0) else {
+            return 0;
+        };
+        let exponent = factor.exponent;
+        let shift = if factor.symbol == "kg" {
+            factor.symbol = "g";
+            3
+        } else {
+            0
+        };
+
+        let power = |prefix: i32| (shift - prefix) * exponent;
+        let upper = 10f64.powi(3 * exponent);
+        let mut prefix =
+            ((largest.log10() / exponent as f64 + shift as f64) / 3.0).floor() as i32 * 3;
+        prefix = prefix.clamp(-30, 30);
+
+        // Correct inaccuracies of the logarithm and values which are rounded
+        // up to the upper limit by the formatting (e.g. 999.96 to "1000.0").
+        if scale(largest, power(prefix)) < 1.0 && prefix > -30 {
+            prefix -= 3;
+        } else if scale(largest, power(prefix)) >= upper && prefix < 30 {
+            prefix += 3;
+        }
+        let rounded = self
+            .format_value(scale(largest, power(prefix)))
+            .parse::<f64>()
+            .unwrap_or(0.0);
+        if rounded >= upper && prefix < 30 {
+            prefix += 3;
+        }
+
+        factor.prefix = prefix;
+        return power(prefix);
+    }
+
+    /**
+    Scales a series of quantities to a common unit, see [`ScaledSeries`]. The
+    unit is written according to the settings of `self`. If
+    [`QuantityFormatter::si_prefix`] is enabled, the prefix is chosen so that
+    the largest absolute value of the series (the larger of real and imaginary
+    part for complex values) lies in [1, 1000).
+
+    Returns an error if the units of the quantities are not identical, like
+    [`to_vec_checked`](crate::quantity::to_vec_checked) does. An empty slice
+    results in an empty series without unit.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{DynQuantity, QuantityFormatter};
+
+    let currents: Vec<DynQuantity<f64>> = ["1.5 mA", "0.02 A", "250 µA"]
+        .into_iter()
+        .map(|s| DynQuantity::from_str(s).unwrap())
+        .collect();
+    let formatter = QuantityFormatter::new().si_prefix(true);
+    let series = formatter.scale_series(&currents).expect("identical units");
+    assert_eq!(series.unit(), "mA");
+    assert_eq!(series.label("Current"), "Current / mA");
+    assert!((series.values()[1] - 20.0).abs() < 1e-12);
+
+    // Mixed dimensions
+    let mixed = [currents[0], DynQuantity::from_str("1 V").unwrap()];
+    assert!(formatter.scale_series(&mixed).is_err());
+    ```
+     */
+    pub fn scale_series<V: F64RealOrComplex>(
+        &self,
+        quantities: &[DynQuantity<V>],
+    ) -> Result<ScaledSeries<V>, ConversionError> {
+        let Some(first) = quantities.first() else {
+            return Ok(ScaledSeries {
+                values: Vec::new(),
+                unit: String::new(),
+            });
+        };
+        let mut largest: f64 = 0.0;
+        for quantity in quantities.iter() {
+            if quantity.unit != first.unit {
+                return Err(ConversionError::UnitMismatch {
+                    expected: first.unit,
+                    found: quantity.unit,
+                });
+            }
+            let value = quantity.value.to_complexf64();
+            for part in [value.re.abs(), value.im.abs()] {
+                if part.is_finite() {
+                    largest = largest.max(part);
+                }
+            }
+        }
+
+        let mut factors = self.factors(&first.unit);
+        let power = if self.si_prefix {
+            self.apply_prefix(largest, &mut factors)
+        } else {
+            0
+        };
+        return Ok(ScaledSeries {
+            values: quantities
+                .iter()
+                .map(|quantity| scale(quantity.value, power))
+                .collect(),
+            unit: self.unit_string(&factors),
+        });
+    }
+
+    /**
+    Writes `value` and `unit` into `f`, taking the precision, sign, width, fill
+    and alignment flags of `f` into account.
+     */
+    pub(crate) fn write(
+        &self,
+        f: &mut Formatter<'_>,
+        mut value: Complex<f64>,
+        unit: &Unit,
+    ) -> std::fmt::Result {
+        // The precision of the format string takes priority
+        let with_precision;
+        let formatter = match f.precision() {
+            Some(precision) => {
+                with_precision = self.clone().precision(precision);
+                &with_precision
+            }
+            None => self,
+        };
+
+        let mut factors = formatter.factors(unit);
+        if formatter.si_prefix {
+            let largest = value.re.abs().max(value.im.abs());
+            let power = formatter.apply_prefix(largest, &mut factors);
+            value = scale(value, power);
+        }
+
+        let mut string = String::new();
+        if value.im == 0.0 {
+            string.push_str(&formatter.markup_value(formatter.format_value(value.re)));
+            if f.sign_plus() && !string.starts_with('-') {
+                string.insert(0, '+');
+            }
+        } else {
+            let sign = if value.im < 0.0 { '-' } else { '+' };
+            let re = formatter.markup_value(formatter.format_value(value.re));
+            let im = formatter.markup_value(formatter.format_value(value.im.abs()));
+            if formatter.markup == Markup::Latex {
+                write!(string, "{re}{sign}{im}i")?;
+            } else {
+                write!(string, "({re}{sign}{im}i)")?;
+            }
+        }
+        let unit = formatter.join_factors(&factors);
+        if formatter.markup == Markup::Latex {
+            if unit.is_empty() {
+                string = format!("\\num{{{string}}}");
+            } else {
+                string = format!("\\qty{{{string}}}{{{unit}}}");
+            }
+        } else if !unit.is_empty() {
+            string.push_str(&formatter.separator);
+            string.push_str(&unit);
+        }
+        return pad(f, &string);
+    }
+}
+
+/**
+A [`DynQuantity`] together with a [`QuantityFormatter`]. This struct is created
+by [`QuantityFormatter::format`] and formats the quantity via its
+[`Display`](std::fmt::Display) implementation. Like the
+[`Display`](std::fmt::Display) implementation of [`DynQuantity`], it honours
+the standard formatting flags.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedQuantity<'a, V: F64RealOrComplex> {
+    formatter: &'a QuantityFormatter,
+    quantity: &'a DynQuantity<V>,
+}
+
+impl<V: F64RealOrComplex> std::fmt::Display for FormattedQuantity<'_, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return self
+            .formatter
+            .write(f, self.quantity.value.to_complexf64(), &self.quantity.unit);
+    }
+}
+
+impl<V: F64RealOrComplex> std::fmt::Display for DynQuantity<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return QuantityFormatter::default().write(f, self.value.to_complexf64(), &self.unit);
+    }
+}
+
+impl<V: F64RealOrComplex> std::fmt::LowerExp for DynQuantity<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return QuantityFormatter::default()
+            .notation(Notation::Scientific)
+            .write(f, self.value.to_complexf64(), &self.unit);
+    }
+}
+
+impl<V: F64RealOrComplex> std::fmt::UpperExp for DynQuantity<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return QuantityFormatter::default()
+            .notation(Notation::Scientific)
+            .uppercase_exponent(true)
+            .write(f, self.value.to_complexf64(), &self.unit);
+    }
+}
+
+impl<V: F64RealOrComplex> DynQuantity<V> {
+    /**
+    Returns the canonical string representation of `self`, which is parsed back
+    into an equal [`DynQuantity`] by its [`FromStr`](std::str::FromStr)
+    implementation. The canonical format is defined as follows:
+
+    - Numbers are written in scientific notation with the shortest mantissa
+      which round-trips exactly (e.g. `1.5e3`, `-2e-7`, `5e-324`). Infinities
+      are written as `inf` and `-inf`, not-a-number values as `NaN`.
+    - A value with an imaginary part other than zero is written as
+      `(<real> + <imag> i)` or `(<real> - <imag> i)`, e.g. `(1.5e3 - 2e-7 i)`.
+      Otherwise, only the real part is written.
+    - The unit follows the value, separated by a space. It consists of the
+      base units with nonzero exponents in the order s, m, kg, A, K, mol, cd,
+      separated by spaces, with the exponent written as `^<exponent>` if it is
+      not one (e.g. `s^-2 m`). Dimensionless quantities have no unit.
+    - The [`kind`](DynQuantity::kind) is not written, since the
+      [`FromStr`](std::str::FromStr) syntax has no notation for it. A quantity
+      with a kind is therefore parsed back into a quantity without a kind,
+      which can be restored via [`DynQuantity::with_kind`].
+
+    Apart from the kind, the round trip is exact in the sense of the
+    [`PartialEq`] implementation, hence the sign of a zero is not preserved. Values containing a
+    not-a-number part are parsed to not-a-number values (for complex values,
+    both parts may become not-a-number).
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use num::Complex;
+    use dyn_quantity::{DynQuantity, PredefUnit};
+
+    let quantity = DynQuantity::<f64>::from_str("9.81 m/s^2").unwrap();
+    assert_eq!(quantity.to_canonical_string(), "9.81e0 s^-2 m");
+
+    let quantity = DynQuantity::<f64>::from_str("0.1 mA").unwrap();
+    let string = quantity.to_canonical_string();
+    assert_eq!(string, "1e-4 A");
+    assert_eq!(DynQuantity::<f64>::from_str(&string).unwrap(), quantity);
+
+    let quantity = DynQuantity::<Complex<f64>>::from_str("(1 - 2j) V").unwrap();
+    let string = quantity.to_canonical_string();
+    assert_eq!(string, "(1e0 - 2e0 i) s^-3 m^2 kg A^-1");
+    assert_eq!(DynQuantity::<Complex<f64>>::from_str(&string).unwrap(), quantity);
+
+    // The kind is dropped
+    let torque = DynQuantity::new(2.0, PredefUnit::Torque)
+        .with_kind(PredefUnit::Torque)
+        .unwrap();
+    let parsed = DynQuantity::<f64>::from_str(&torque.to_canonical_string()).unwrap();
+    assert_eq!(parsed.kind, None);
+    assert_eq!(parsed.with_kind(PredefUnit::Torque).unwrap(), torque);
+    ```
+     */
+    pub fn to_canonical_string(&self) -> String {
+        let value = self.value.to_complexf64();
+        let mut string = if value.im == 0.0 {
+            format!("{:e}", value.re)
+        } else {
+            let sign = if value.im.is_sign_negative() {
+                '-'
+            } else {
+                '+'
+            };
+            format!("({:e} {sign} {:e} i)", value.re, value.im.abs())
+        };
+        let unit = QuantityFormatter::new().format_unit(&self.unit);
+        if !unit.is_empty() {
+            string.push(' ');
+            string.push_str(&unit);
+        }
+        return string;
+    }
+}
+
+/**
+A series of quantities scaled to a common unit, e.g. for a table column or a
+plot axis. This struct is created by [`QuantityFormatter::scale_series`].
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledSeries<V: F64RealOrComplex> {
+    values: Vec<V>,
+    unit: String,
+}
+
+impl<V: F64RealOrComplex> ScaledSeries<V> {
+    /// Returns the values of the quantities in [`ScaledSeries::unit`].
+    pub fn values(&self) -> &[V] {
+        return &self.values;
+    }
+
+    /// Returns the values of the quantities in [`ScaledSeries::unit`].
+    pub fn into_values(self) -> Vec<V> {
+        return self.values;
+    }
+
+    /**
+    Returns the common unit of the series, e.g. `mA`. Returns an empty string
+    for dimensionless quantities.
+     */
+    pub fn unit(&self) -> &str {
+        return &self.unit;
+    }
+
+    /**
+    Returns a label for e.g. a table header or a plot axis consisting of `name`
+    and the unit, separated by a slash (e.g. `Current / mA`). For dimensionless
+    quantities, only `name` is returned.
+     */
+    pub fn label(&self, name: &str) -> String {
+        if self.unit.is_empty() {
+            return name.to_owned();
+        }
+        return format!("{name} / {}", self.unit);
+    }
+}
+
+/**
+SI prefixes which are multiples of three together with their power of ten and
+their siunitx macro.
+ */
+const SI_PREFIXES: [(&str, i32, &str); 21] = [
+    ("Q", 30, r"\quetta"),
+    ("R", 27, r"\ronna"),
+    ("Y", 24, r"\yotta"),
+    ("Z", 21, r"\zetta"),
+    ("E", 18, r"\exa"),
+    ("P", 15, r"\peta"),
+    ("T", 12, r"\tera"),
+    ("G", 9, r"\giga"),
+    ("M", 6, r"\mega"),
+    ("k", 3, r"\kilo"),
+    ("", 0, ""),
+    ("m", -3, r"\milli"),
+    ("µ", -6, r"\micro"),
+    ("n", -9, r"\nano"),
+    ("p", -12, r"\pico"),
+    ("f", -15, r"\femto"),
+    ("a", -18, r"\atto"),
+    ("z", -21, r"\zepto"),
+    ("y", -24, r"\yocto"),
+    ("r", -27, r"\ronto"),
+    ("q", -30, r"\quecto"),
+];
+
+/// Returns the siunitx macro of a base or named unit symbol.
+fn latex_symbol(symbol: &str) -> &'static str {
+    match symbol {
+        "s" => return r"\second",
+        "m" => return r"\metre",
+        "g" => return r"\gram",
+        "kg" => return r"\kilogram",
+        "A" => return r"\ampere",
+        "K" => return r"\kelvin",
+        "mol" => return r"\mole",
+        "cd" => return r"\candela",
+        "N" => return r"\newton",
+        "Pa" => return r"\pascal",
+        "J" => return r"\joule",
+        "W" => return r"\watt",
+        "C" => return r"\coulomb",
+        "V" => return r"\volt",
+        "F" => return r"\farad",
+        "Ω" => return r"\ohm",
+        "S" => return r"\siemens",
+        "Wb" => return r"\weber",
+        "T" => return r"\tesla",
+        "H" => return r"\henry",
+        _ => unreachable!("all base and named unit symbols are covered"),
+    }
+}
+
+/// A factor of a unit, e.g. `mm^2`.
+struct Factor {
+    /// Power of ten of the SI prefix, a multiple of three between -30 and 30.
+    prefix: i32,
+    symbol: &'static str,
+    exponent: i32,
+}
+
+impl Factor {
+    fn prefix(&self) -> (&'static str, &'static str) {
+        let (symbol, _, latex) = SI_PREFIXES
+            .iter()
+            .find(|(_, power, _)| *power == self.prefix)
+            .expect("prefix is a multiple of three between -30 and 30");
+        return (symbol, latex);
+    }
+
+    /**
+    Returns e.g. `mm^2` for the factor `mm` and the given exponent (which may
+    differ from the exponent of the factor in case of a fraction).
+     */
+    fn to_string(&self, markup: Markup, exponent: i32) -> String {
+        let (prefix, _) = self.prefix();
+        let symbol = self.symbol;
+        if exponent == 1 {
+            return format!("{prefix}{symbol}");
+        }
+        match markup {
+            Markup::Plain | Markup::Latex => return format!("{prefix}{symbol}^{exponent}"),
+            Markup::Unicode => {
+                return format!("{prefix}{symbol}{}", superscript(&exponent.to_string()));
+            }
+            Markup::Html => return format!("{prefix}{symbol}<sup>{exponent}</sup>"),
+        }
+    }
+
+    /// Returns the factor as siunitx macros, e.g. `\per\milli\metre\squared`.
+    fn latex(&self) -> String {
+        let (prefix, symbol) = if self.symbol == "g" && self.prefix == 3 {
+            ("", latex_symbol("kg"))
+        } else {
+            (self.prefix().1, latex_symbol(self.symbol))
+        };
+        let per = if self.exponent < 0 { r"\per" } else { "" };
+        return format!("{per}{prefix}{symbol}{}", latex_power(self.exponent));
+    }
+}
+
+/// Returns the siunitx power macro for the absolute value of `exponent`.
+fn latex_power(exponent: i32) -> String {
+    match exponent.abs() {
+        1 => return String::new(),
+        2 => return r"\squared".to_owned(),
+        3 => return r"\cubed".to_owned(),
+        exponent => return format!(r"\tothe{{{exponent}}}"),
+    }
+}
+
+/// Replaces the digits and the minus sign of `string` by Unicode superscripts.
+fn superscript(string: &str) -> String {
+    return string
+        .chars()
+        .map(|c| match c {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '-' => '⁻',
+            '+' => '⁺',
+            c => c,
+        })
+        .collect();
+}
+
+/// Multiplies `value` with ten to the power of `power`.
+fn scale<T: Mul<f64, Output = T> + Div<f64, Output = T>>(value: T, power: i32) -> T {
+    // Dividing by a power of ten is more accurate than multiplying with its
+    // inverse, e.g. 1200 / 1000 results in exactly 1.2.
+    if power >= 0 {
+        return value * 10f64.powi(power);
+    } else {
+        return value / 10f64.powi(-power);
+    }
+}
+
+/**
+Rounds the absolute value of `value` to the given number of significant digits
+(or to the shortest representation if `None`). Returns the digits and the
+decimal exponent of the first digit, e.g. `("1235", 2)` for 123.45 and four
+significant digits.
+ */
+fn round_digits(value: f64, significant_digits: Option<usize>) -> (String, i32) {
+    let scientific = match significant_digits {
+        Some(digits) => format!("{:.*e}", digits.max(1) - 1, value.abs()),
+        None => format!("{:e}", value.abs()),
+    };
+    let (mantissa, exponent) = scientific.split_once('e').expect("scientific notation");
+    let exponent = exponent.parse().expect("valid exponent");
+    return (mantissa.replace('.', ""), exponent);
+}
+
+/**
+Writes the `digits` in positional notation, where `exponent` is the decimal
+exponent of the first digit. The fraction is cut or padded with zeros to the
+given number of decimal places.
+ */
+fn positional(digits: &str, exponent: i32, decimal_places: usize) -> String {
+    let integer_digits = exponent + 1;
+    let (integer, mut fraction) = if integer_digits <= 0 {
+        let zeros = "0".repeat(integer_digits.unsigned_abs() as usize);
+        ("0".to_owned(), format!("{zeros}{digits}"))
+    } else if integer_digits as usize >= digits.len() {
+        let zeros = "0".repeat(integer_digits as usize - digits.len());
+        (format!("{digits}{zeros}"), String::new())
+    } else {
+        let (integer, fraction) = digits.split_at(integer_digits as usize);
+        (integer.to_owned(), fraction.to_owned())
+    };
+    fraction.truncate(decimal_places);
+    while fraction.len() < decimal_places {
+        fraction.push('0');
+    }
+    if fraction.is_empty() {
+        return integer;
+    }
+    return format!("{integer}.{fraction}");
+}
+
+/// Writes `string` into `f`, taking width, fill and alignment into account.
+fn pad(f: &mut Formatter<'_>, string: &str) -> std::fmt::Result {
+    let padding = f
+        .width()
+        .unwrap_or(0)
+        .saturating_sub(string.chars().count());
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(string)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    return Ok(());
+}
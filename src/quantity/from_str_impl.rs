@@ -17,6 +17,16 @@ either an `i` or `j` behind the numerical value (with or without a space).
 For example, the following strings are all parsed to the same [`DynQuantity`]:
 `2 i`, `2j`, `2.0 j`, `2.0i`.
 
+Real numbers can be written in the following forms, each of which is parsed
+with full [`f64`] precision:
+* Decimal numbers with an optional fraction, e.g. `42`, `1.5`, `.5` or `5.`.
+* Scientific notation with `e` or `E`, e.g. `1.5e-3`, `1.5E3` or `2e+3`.
+* Digits separated by underscores, e.g. `1_000_000` or `0.000_1`. Each
+underscore must be placed between two digits.
+* Hexadecimal or binary integers, e.g. `0x1F`, `0XFF_FF` or `0b1010`.
+
+Malformed numbers such as `1__000`, `1_` or `0b102` result in an error.
+
 The following special numbers are recognized:
 * `inf`, `Inf`, `INF`, `infinity`, `Infinity`, `INFINITY`, `.inf`, `.Inf`,
 `.INF` are all parsed to [`std::f64::INFINITY`].
@@ -24,6 +34,8 @@ The following special numbers are recognized:
 `-.Inf`, `-.INF` are all parsed to [`std::f64::NEG_INFINITY`].
 * `10^x` or `ex` where `x` is a positive or negative integer are parsed to `ex`
 (10 to the power of `x`).
+* `NaN`, `nan`, `NAN`, `.nan`, `.NaN`, `.NAN` are all parsed to
+[`f64::NAN`].
 * `pi`, `π`, `PI`, `Pi` are all parsed to [`std::f64::consts::PI`].
 
 ## Units of measurement
@@ -42,17 +54,41 @@ The following units of measurement are recognized:
 * `Nm`: Newton meter
 * `W`: Watt
 * `J`: Joule
+* `Pa`: Pascal
 * `Hz`: Hertz
 * `rpm`: Rotations per minute
 * `Wb`: Weber
 * `T`: Tesla
 * `H`: Henry
 * `S`: Siemens
+* `C`: Coulomb
+* `F`: Farad
 * `t`: Ton - could also be represented by `Mg` (mega-gram)
 * `Ohm`, `ohm`: Ohm
 * `Ω`: Omega
-
-Units can be prefixed by metric prefixes (see <https://en.wikipedia.org/wiki/Metric_prefix>).
+* `min`: Minute
+* `h`: Hour
+* `d`: Day
+* `ft`: Foot
+* `yd`: Yard
+* `in`: Inch
+
+The units minute, hour, day, foot, yard and inch can not be combined with metric
+prefixes. Since the offset of `°C` is only defined for the unit itself, `°C`
+can neither be prefixed nor raised to a power (e.g. `k°C` or `°C^2` are
+invalid).
+
+If the imaginary unit after a number starts a longer token such as `in` or
+`inf`, the number is real, hence `3 in` is three inch and `2 inf` is infinity
+(see [`dyn_quantity_lexer::lex`]). Other imaginary numbers are not affected,
+e.g. `3 iV` still equals `3 i V`.
+
+**Breaking change:** Since the symbols `min`, `h`, `d`, `ft`, `yd` and `in` are
+recognized as units, `ft` means foot (`5 ft` equals 1.524 m). Previously, it
+was interpreted as femto-tonne (`5 ft` equaled 5e-12 kg). The symbols `min`,
+`h`, `d`, `yd` and `in` were rejected before.
+
+All other units can be prefixed by metric prefixes (see <https://en.wikipedia.org/wiki/Metric_prefix>).
 This multiplies their associated numerical values with `ex`, where `x` is defined by
 the following table:
 * `Q`: quetta, `x` = 30
@@ -98,10 +134,21 @@ The following operators are available:
 * `-`: Subtraction (fails if units of involved quantities are not identical)
 * `*`: Multiplication
 * `/`: Division
-* `^`: Exponentiation (after an exponentiation, only a positive or negative
-integer may follow)
+* `^`: Exponentiation of numbers, constants, units and brackets (e.g. `2^10`,
+`pi^2`, `m^2` or `(2 m)^3`). The exponent is a positive or negative number
+(e.g. `-2` or `0.5`) or a dimensionless real expression in brackets (e.g.
+`(1/3)` or `(1 + 1)`). Exponentiation is right-associative, i.e. `2^3^2`
+equals `2^(3^2)`. Non-integer exponents are only allowed if the resulting
+unit exponents are integers (see
+[`Unit::try_nthroot`](crate::unit::Unit::try_nthroot)), hence `(4 m^2)^0.5`
+is valid, but `m^0.5` is not.
 * `%`: Percentage, this is equivalent to `*1e2`
 
+Since units are multiplied implicitly, a string such as `1 h 30 min` is
+interpreted as `1 h * 30 min`. Quantities written as a sum of several parts
+without `+` signs can be parsed via
+[`DynQuantity::from_str_compound`](crate::quantity::compound) instead.
+
 ## Angles
 
 Angles have two dimensionless units: degree or radians, which can be converted
@@ -110,8 +157,27 @@ parsing an angle, the resulting numerical value is always in radians, hence
 values with the unit `degree` are converted via the aforementioned relationship.
 The following strings can be used to define the angular units:
 * Degree: `degree`, `Degree``, `°`, `deg`, `Deg`
+* Arcminute (1/60 degree): `'`, `′`
+* Arcsecond (1/3600 degree): `"`, `″`
 * Radians: `rad`, `Rad`, `radians`, `Radians`
 
+Angles can also be given in degree-minute-second notation (e.g. `12°30'15"`)
+or in degree-decimal-minute notation (e.g. `45° 30.5'`). A number directly
+following a degree or arcminute unit is added to the angle instead of being
+multiplied with it, if it is followed by a smaller angular unit. A leading sign
+applies to the entire angle, i.e. `-12°30'` equals `-12.5°`.
+
+```
+use std::str::FromStr;
+use dyn_quantity::DynQuantity;
+
+let angle = DynQuantity::<f64>::from_str("12°30'15\"").expect("valid string");
+assert!((angle.value.to_degrees() - 12.504166666666666).abs() < 1e-12);
+
+let angle = DynQuantity::<f64>::from_str("-45° 30.5'").expect("valid string");
+assert!((angle.value.to_degrees() + 45.50833333333333).abs() < 1e-12);
+```
+
 ## Brackets
 
 The resolution order of mathematical operations can be modified via round
@@ -121,9 +187,27 @@ a bracket, the multiplication operator `*` is inserted implicitly.
 
 For example, the following strings parse to the same [`DynQuantity`]:
 `3 * (1A + 4A)`, `3 * ((1A + 4A))`, `3(1A + 4A)`
-all result in a value of `15` with the unit `A`. Brackets are not allowed
-directly after an exponentiation symbol `^`. However, exponentiation of a
-bracket is allowed
+all result in a value of `15` with the unit `A`. After an exponentiation
+symbol `^`, brackets must contain a dimensionless real expression, e.g.
+`(9 m^2)^(1/2)` or `2^(1 + 1)`. An exponent applies only to the number, constant, unit or
+bracket directly in front of it, hence `2 m^2` equals `2 * (m^2)` and
+`m^-1/2` equals `(m^-1) / 2`.
+
+```
+use std::str::FromStr;
+use dyn_quantity::DynQuantity;
+
+let quantity = DynQuantity::<f64>::from_str("2^10 m").expect("valid string");
+assert_eq!(quantity.value, 1024.0);
+
+let quantity = DynQuantity::<f64>::from_str("(9 m^2)^(1/2)").expect("valid string");
+assert_eq!(quantity.value, 3.0);
+assert_eq!(quantity.unit, DynQuantity::<f64>::from_str("m").unwrap().unit);
+
+// Odd roots of negative numbers are real
+let quantity = DynQuantity::<f64>::from_str("(-8)^(1/3)").expect("valid string");
+assert!((quantity.value + 2.0).abs() < 1e-12);
+```
 
 # Examples
 
@@ -214,628 +298,951 @@ assert!(DynQuantity::<f64>::from_str("5i").is_err());
 // Unbalanced brackets
 assert!(DynQuantity::<f64>::from_str("((1 + 3)").is_err());
 
-// Exponentiation is only allowed for integers w/o brackets
+// Exponents must be dimensionless real numbers
 assert!(DynQuantity::<f64>::from_str("(2 km)^V").is_err());
-assert!(DynQuantity::<f64>::from_str("(2 km)^(3)").is_err());
+assert!(DynQuantity::<f64>::from_str("2^(1 m)").is_err());
+
+// The root of a unit must have integer exponents
+assert!(DynQuantity::<f64>::from_str("m^(1/2)").is_err());
 
 // Unknown unit
 assert!(DynQuantity::<f64>::from_str("1 metre").is_err());
 ```
+
+# Error handling
+
+The [`FromStr`] implementation returns the first error it finds. When validating
+larger inputs (e.g. hand-written configuration files), it is often more
+convenient to get all errors at once. This is what
+[`DynQuantity::from_str_collect_errors`] is for: Instead of aborting, the parser
+skips the offending token (or, in case of an addition of quantities with
+different units, the offending term) and continues. All errors are returned
+together, each one with a span pointing to the corresponding part of the input:
+
+```
+use dyn_quantity::{DynQuantity, ParseErrorReason};
+
+let errors = DynQuantity::<f64>::from_str_collect_errors("1 m + 2 s $ + (3 A").unwrap_err();
+assert_eq!(errors.len(), 4);
+assert_eq!(errors[0].substring, "$");
+assert_eq!(errors[1].substring, "+ 2 s");
+assert_eq!(errors[2].substring, "(");
+assert_eq!(errors[3].substring, "+ (3 A");
+```
 */
 
 use std::{
     f64::{INFINITY, NEG_INFINITY, consts::PI},
+    iter::Peekable,
     str::FromStr,
 };
 
-use ::num::{Complex, Zero};
+use ::num::{Complex, One, Zero, rational::Ratio};
 
-#[cfg(not(doc))]
-use dyn_quantity_lexer::{Logos, Token};
+use dyn_quantity_lexer::{Exponents, LexingError, Span, Token};
 
+use super::scaled_unit::ScaledUnit;
+use super::tokenize::unit_definition;
 use super::{DynQuantity, F64RealOrComplex};
 use crate::{
-    Unit,
-    error::{ParseError, ParseErrorReason},
+    PredefUnit, Unit,
+    error::{ConversionError, ParseError, ParseErrorReason, UnitsNotEqual},
 };
 
 impl<V: F64RealOrComplex> FromStr for DynQuantity<V> {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let dyn_quantity = from_str_complexf64(s)?;
-        match V::try_from_complexf64(dyn_quantity.value) {
-            Ok(value) => {
-                return Ok(DynQuantity::new(value, dyn_quantity.unit));
-            }
-            Err(conversion_error) => {
-                return Err(ParseError {
-                    substring: "".into(),
-                    span: 0..0,
-                    reason: ParseErrorReason::NotConvertibleFromComplexF64(conversion_error),
-                });
-            }
-        }
+        return Self::from_str_collect_errors(s).map_err(|mut errors| errors.remove(0));
     }
 }
 
-fn from_str_complexf64(s: &str) -> Result<DynQuantity<Complex<f64>>, ParseError> {
-    #[derive(Debug, Copy, Clone, PartialEq)]
-    enum PreviousToken {
-        Add,
-        Sub,
-        Mul,
-        Div,
-        Other,
+impl<V: F64RealOrComplex> DynQuantity<V> {
+    /**
+    Returns the value of `self` expressed in the unit `target`, which is parsed
+    into a [`ScaledUnit`] (see the [module docs](crate::quantity::from_str_impl) for the
+    syntax). For example, the target `mm/min` returns the number of
+    millimeters per minute. The target may contain a numerical factor, e.g.
+    `100 km` or `1/h`. The offset of units such as `°C` is taken into account
+    (see [`ScaledUnit::value_of`]).
+
+    Returns [`ConversionError::UnitMismatch`] if the units of `self` and
+    `target` are not identical and [`ConversionError::Custom`] if `target`
+    cannot be parsed into a [`ScaledUnit`]. See
+    [`DynQuantity::value_in_quantity`] for a variant which takes an already
+    parsed target.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{ConversionError, DynQuantity};
+
+    let velocity = DynQuantity::<f64>::from_str("0.5 m/s").unwrap();
+    let value = velocity.value_in("mm/min").expect("same unit");
+    assert!((value - 30000.0).abs() < 1e-9);
+
+    let torque = DynQuantity::<f64>::from_str("2500 N m").unwrap();
+    assert_eq!(torque.value_in("kN m").unwrap(), 2.5);
+
+    assert!(matches!(
+        torque.value_in("kN"),
+        Err(ConversionError::UnitMismatch { .. })
+    ));
+    assert!(matches!(torque.value_in("kN (m"), Err(ConversionError::Custom(_))));
+
+    let temperature = DynQuantity::<f64>::from_str("300 K").unwrap();
+    assert!((temperature.value_in("°C").unwrap() - 26.85).abs() < 1e-9);
+    ```
+     */
+    pub fn value_in(&self, target: &str) -> Result<V, ConversionError> {
+        let target = ScaledUnit::from_str(target).map_err(ConversionError::custom)?;
+        return target.value_of(self);
+    }
+
+    /**
+    Parses a string like the [`FromStr`] implementation and tags the resulting
+    quantity with the given `kind` (see [`DynQuantity::with_kind`]). If the
+    unit of the parsed quantity does not match the unit of `kind`, a
+    [`ParseErrorReason::UnitsNotEqual`] error spanning the entire string is
+    returned.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, ParseErrorReason, PredefUnit};
+
+    let torque = DynQuantity::<f64>::from_str_with_kind("2 kN m", PredefUnit::Torque).unwrap();
+    assert_eq!(torque.value, 2000.0);
+    assert_eq!(torque.kind, Some(PredefUnit::Torque));
+
+    let error = DynQuantity::<f64>::from_str_with_kind("2 kN", PredefUnit::Torque).unwrap_err();
+    assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    ```
+     */
+    pub fn from_str_with_kind(s: &str, kind: PredefUnit) -> Result<Self, ParseError> {
+        let quantity = Self::from_str(s)?;
+        return quantity.with_kind(kind).map_err(|_| ParseError {
+            substring: s.to_owned(),
+            span: 0..s.len(),
+            reason: ParseErrorReason::UnitsNotEqual(UnitsNotEqual(kind.into(), quantity.unit)),
+        });
     }
 
     /**
-    This enum encapsulates a quantity and the mathematical operation which follows it.
-    For example:
-    Add(x) means "x +", Mul(x) means "x *", Div(x) means "x /".
+    Parses a string into a [`DynQuantity`] like the [`FromStr`] implementation,
+    but does not stop at the first error. Instead, the parser skips the
+    offending part of the string and continues, so that all problems of the
+    input (unknown tokens, unbalanced brackets, additions of quantities with
+    different units, ...) are reported at once. The errors are returned in the
+    order in which they were encountered, each one with its own span. The
+    returned vector is never empty.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, ParseErrorReason};
+
+    let errors = DynQuantity::<f64>::from_str_collect_errors("(2 l + 3 V) * 4)").unwrap_err();
+    assert_eq!(errors.len(), 3);
+
+    // Unknown token "l"
+    assert_eq!(errors[0].span, 3..4);
+    assert_eq!(errors[0].reason, ParseErrorReason::UnexpectedToken);
+
+    // "2 + 3 V": Units do not match
+    assert_eq!(errors[1].substring, "+ 3 V");
+    assert!(matches!(errors[1].reason, ParseErrorReason::UnitsNotEqual(_)));
+
+    // The last closing bracket has no opening counterpart
+    assert_eq!(errors[2].span, 15..16);
+    assert_eq!(errors[2].reason, ParseErrorReason::UnbalancedBrackets);
+
+    // A valid string is parsed just like with `from_str`
+    let quantity = DynQuantity::<f64>::from_str_collect_errors("(2 A + 3 A) / 5").unwrap();
+    assert_eq!(quantity.value, 1.0);
+    ```
      */
-    #[derive(Debug)]
-    enum Operation {
-        Add(DynQuantity<Complex<f64>>),
-        Mul(DynQuantity<Complex<f64>>),
-        Div(DynQuantity<Complex<f64>>),
-    }
-
-    impl From<Operation> for DynQuantity<Complex<f64>> {
-        fn from(value: Operation) -> Self {
-            match value {
-                Operation::Add(item) => item,
-                Operation::Mul(item) => item,
-                Operation::Div(item) => item,
+    pub fn from_str_collect_errors(s: &str) -> Result<Self, Vec<ParseError>> {
+        let mut parser = Parser::new(s);
+        let quantity = parser.parse();
+        let mut errors = parser.errors;
+        if let Some(quantity) = quantity {
+            match V::try_from_complexf64(quantity.value) {
+                Ok(value) => {
+                    if errors.is_empty() {
+                        return Ok(DynQuantity::new(value, quantity.unit));
+                    }
+                }
+                Err(conversion_error) => {
+                    errors.push(ParseError {
+                        substring: s.to_owned(),
+                        span: 0..s.len(),
+                        reason: ParseErrorReason::NotConvertibleFromComplexF64(conversion_error),
+                    });
+                }
             }
         }
+        return Err(errors);
     }
+}
 
-    fn adjust<F: FnMut(&mut DynQuantity<Complex<f64>>)>(
-        active_quantity: &mut Option<DynQuantity<Complex<f64>>>,
-        mut fun: F,
-    ) {
-        let mut quantity = active_quantity
-            .take()
-            .unwrap_or(DynQuantity::new(Complex::new(1.0, 0.0), Unit::default()));
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum PreviousToken {
+    Add,
+    Sub,
+    Mul,
+    Other,
+}
+
+/**
+A recursive descent parser which evaluates the token stream created by the
+lexer on the fly. The grammar is (in order of precedence):
+
+```text
+sum      := term (("+" | "-") term)*
+term     := ("+" | "-")? factor (("*")? ("+" | "-")? factor | "/" divisor)*
+divisor  := ("+" | "-")? factor
+factor   := (number | unit | constant | "(" sum ")") ("^" exponent)*
+exponent := ("+" | "-")? (number ("^" exponent)* | "(" sum ")")
+```
 
-        fun(&mut quantity);
+The sum in brackets of an exponent needs to be dimensionless and real (e.g.
+`(1/3)` or `(1 + 1)`). Exponents are converted into fractions of two integers.
+
+Units, constants and closing brackets can contain an integer exponent in
+their token (e.g. `m^2` or `)^2`). If such a token is directly followed by
+the decimal places of a number (e.g. `m^0.5`, which is lexed as `m^0` and
+`.5`), both are combined into a decimal exponent.
+
+Consecutive factors without an operator inbetween are multiplied. A division
+only applies to the factor directly following the `/` operator, further
+factors are multiplied with the result of the division (e.g. `1 / 2 m` is
+equal to `0.5 m`).
+
+As an exception, a number which directly follows a degree or arcminute unit
+and is followed by a smaller angular unit starts a new term, so that angles in
+degree-minute-second notation (e.g. `12°30'15"`) are added up.
+
+Errors do not abort the parsing. Instead, they are stored in `errors` and
+the offending token is skipped, so that all errors of the input can be
+collected in a single run.
+ */
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Peekable<std::vec::IntoIter<(Result<Token, LexingError>, Span)>>,
+    /// End of the span of the last consumed token.
+    consumed: usize,
+    errors: Vec<ParseError>,
+}
 
-        *active_quantity = Some(quantity);
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        let tokens = dyn_quantity_lexer::lex(input);
+        return Parser {
+            input,
+            tokens: tokens.into_iter().peekable(),
+            consumed: 0,
+            errors: Vec::new(),
+        };
     }
 
-    fn include_infinity(active_quantity: &mut Option<DynQuantity<Complex<f64>>>, infinity: f64) {
-        if let Some(quantity) = active_quantity.as_mut() {
-            let re = if quantity.value.re == 0.0 {
-                0.0
-            } else {
-                quantity.value.re.signum() * infinity
-            };
-            let im = if quantity.value.im == 0.0 {
-                0.0
-            } else {
-                quantity.value.re.signum() * infinity
-            };
-            quantity.value = Complex::new(re, im);
-        } else {
-            *active_quantity = Some(DynQuantity::new(
-                Complex::new(infinity, 0.0),
-                Unit::default(),
-            ));
+    fn next_token(&mut self) -> Option<(Result<Token, LexingError>, Span)> {
+        let next = self.tokens.next();
+        if let Some((_, span)) = next.as_ref() {
+            self.consumed = span.end;
         }
+        return next;
+    }
+
+    fn error(&mut self, span: Span, reason: ParseErrorReason) {
+        self.errors.push(ParseError {
+            substring: self.input[span.clone()].to_owned(),
+            span,
+            reason,
+        });
     }
 
     /**
-    When multiplying an infinite value with zero, the IEEE result is NaN.
-    However, in our case the result should be 0
+    Parses the entire input. Returns `None` if no quantity could be
+    constructed from the input. In this case, at least one error has been
+    stored.
      */
-    fn multiply_no_nan(arg1: Complex<f64>, arg2: Complex<f64>) -> Complex<f64> {
-        // Multiply "by hand" in order to treat 0 * Inf accordingly
-        let mut re = 0.0;
-        let mut im = 0.0;
-
-        if !(arg1.re.is_infinite() && arg2.re.is_zero())
-            && !(arg1.re.is_zero() && arg2.re.is_infinite())
-        {
-            re += arg1.re * arg2.re;
-        }
-
-        if !(arg1.im.is_infinite() && arg2.re.is_zero())
-            && !(arg1.im.is_zero() && arg2.re.is_infinite())
-        {
-            im += arg1.im * arg2.re;
+    fn parse(&mut self) -> Option<DynQuantity<Complex<f64>>> {
+        let quantity = self.parse_sum(false);
+        if quantity.is_none() && self.errors.is_empty() {
+            self.error(0..self.input.len(), ParseErrorReason::InputIsEmpty);
         }
-
-        if !(arg1.re.is_infinite() && arg2.im.is_zero())
-            && !(arg1.re.is_zero() && arg2.im.is_infinite())
-        {
-            im += arg1.re * arg2.im;
-        }
-
-        if !(arg1.im.is_infinite() && arg2.im.is_zero())
-            && !(arg1.im.is_zero() && arg2.im.is_infinite())
-        {
-            re -= arg1.im * arg2.im;
-        }
-
-        return Complex::new(re, im);
+        return quantity;
     }
 
-    // ===============================================================================
-
-    let mut lexer = Token::lexer(s);
-
-    let mut active_quantity: Option<DynQuantity<Complex<f64>>> = None;
+    /**
+    Parses a sum of terms. If `nested` is true, the sum is enclosed in brackets
+    and ends before the corresponding closing bracket. Otherwise, it ends at
+    the end of the input and closing brackets are reported as unbalanced.
+     */
+    fn parse_sum(&mut self, nested: bool) -> Option<DynQuantity<Complex<f64>>> {
+        let mut sum: Option<DynQuantity<Complex<f64>>> = None;
 
-    // This is a stack of quantities. Two quantities are separated by a mathematical
-    // operator which defines how the quantities are combined. THe last operator
-    // is combined with "active_quantity". For example, stack = [Add(x), Mul(y)]
-    // and active_quantity = Some(z), where x, y and z are quantities would
-    // be combined as follows:
-    // x + y * z
-    let mut stack: Vec<Operation> = Vec::new();
+        // The term which is currently parsed. Its span starts at the sign of
+        // the term (if there is one) and is used for error reporting if the
+        // term cannot be added to the sum.
+        let mut term: Option<DynQuantity<Complex<f64>>> = None;
+        let mut term_span: Option<Span> = None;
 
-    // The bracket level increases by one for each open bracket "(" and decreases by
-    // one for each closing bracket ")". At the end of the parsing, the bracket
-    // level must be zero. Additionally, it may never become negative, therefore
-    // we always use checked_sub. If it would become negative or is not zero at the
-    // end, an error is returned.
-    let mut bracket_level: usize = 0;
+        let mut previous_token = PreviousToken::Other;
 
-    let mut previous_token = PreviousToken::Other;
-    let mut division_pending = false;
+        // Rank of the angular unit of the previous token (see `angle_rank`)
+        let mut previous_angle: Option<u8> = None;
 
-    while let Some(token) = lexer.next() {
-        let token: Token = token.map_err(|_| {
-            let reason = ParseErrorReason::UnexpectedToken;
-            return ParseError {
-                substring: s[lexer.span()].to_owned(),
-                span: lexer.span(),
-                reason,
+        while let Some((token, _)) = self.tokens.peek() {
+            if nested && matches!(token, Ok(Token::RightBracket(_))) {
+                break;
+            }
+            let (token, span) = self.next_token().expect("token has been peeked");
+            let previous_rank = previous_angle.take();
+            let token = match token {
+                Ok(token) => token,
+                Err(err) => {
+                    self.error(span, err.into());
+                    continue;
+                }
             };
-        })?;
-
-        match token {
-            Token::Real(val) => {
-                if let Some(quantity) = active_quantity.as_mut() {
-                    quantity.value = multiply_no_nan(quantity.value, Complex::new(val, 0.0));
-                } else {
-                    active_quantity =
-                        Some(DynQuantity::new(Complex::new(val, 0.0), Unit::default()));
+            previous_angle = angle_rank(&token);
+
+            // Degree-minute-second notation: In "12°30'", the number 30 starts
+            // a new term which is added to the angle. The sign of the angle
+            // applies to all of its parts.
+            let starts_angle_part = match (&token, previous_rank) {
+                (Token::Real(_), Some(rank)) => {
+                    self.next_angle_rank().is_some_and(|next| next > rank)
                 }
+                _ => false,
+            };
+            if starts_angle_part {
+                let sign = match term.as_ref() {
+                    Some(quantity) if quantity.value.re < 0.0 => -1.0,
+                    _ => 1.0,
+                };
+                self.add_term(&mut sum, term.take(), term_span.take());
+                term = Some(DynQuantity::new(Complex::new(sign, 0.0), Unit::default()));
             }
-            Token::Imag(val) => {
-                if let Some(quantity) = active_quantity.as_mut() {
-                    quantity.value = multiply_no_nan(quantity.value, Complex::new(0.0, val));
-                } else {
-                    active_quantity =
-                        Some(DynQuantity::new(Complex::new(0.0, val), Unit::default()));
+            let term_start = term_span.as_ref().map_or(span.start, |span| span.start);
+
+            match token {
+                Token::Add | Token::Sub => {
+                    let (sign, token) = if token == Token::Add {
+                        (1.0, PreviousToken::Add)
+                    } else {
+                        (-1.0, PreviousToken::Sub)
+                    };
+                    match previous_token {
+                        // Constructs such as "-+" or "++" are not allowed ...
+                        PreviousToken::Add | PreviousToken::Sub => {
+                            self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
+                            continue;
+                        }
+                        // ... but "*-1" is: Like after a number, the sign
+                        // starts a new summand, i.e. "2 * -3" equals "2 - 3".
+                        PreviousToken::Mul | PreviousToken::Other => {
+                            self.add_term(&mut sum, term.take(), term_span.take());
+                            term = Some(DynQuantity::new(Complex::new(sign, 0.0), Unit::default()));
+                            term_span = Some(span);
+                        }
+                    }
+                    previous_token = token;
+                    continue;
                 }
-            }
-            Token::Infinity => {
-                include_infinity(&mut active_quantity, INFINITY);
-            }
-            Token::NegInfinity => {
-                include_infinity(&mut active_quantity, NEG_INFINITY);
-            }
-            Token::Mul => {
-                // This is essentially a no-op - we therefore do just some error checking
-                if previous_token != PreviousToken::Other {
-                    let reason = ParseErrorReason::TwoOperatorsWithoutNumber;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Token::Mul => {
+                    // This is essentially a no-op - we therefore do just some error checking
+                    if previous_token != PreviousToken::Other {
+                        self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
+                        continue;
+                    }
+                    if term.is_none() {
+                        self.error(span, ParseErrorReason::MustNotStartWith);
+                        continue;
+                    }
+                    previous_token = PreviousToken::Mul;
+                    continue;
                 }
-                if active_quantity.is_none() {
-                    let reason = ParseErrorReason::MustNotStartWith;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Token::Div => {
+                    if previous_token != PreviousToken::Other {
+                        self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
+                        continue;
+                    }
+                    match term.as_mut() {
+                        Some(quantity) => {
+                            if let Some(divisor) = self.parse_divisor() {
+                                *quantity /= divisor;
+                            }
+                        }
+                        None => {
+                            self.error(span, ParseErrorReason::MustNotStartWith);
+                            continue;
+                        }
+                    }
                 }
-                previous_token = PreviousToken::Mul;
-                continue;
-            }
-            Token::Div => {
-                if previous_token != PreviousToken::Other {
-                    let reason = ParseErrorReason::TwoOperatorsWithoutNumber;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Token::LeftBracket => {
+                    if let Some(bracket) = self.parse_bracket(span.clone()) {
+                        multiply(&mut term, bracket);
+                    }
                 }
-                if let Some(quantity) = active_quantity.take() {
-                    stack.push(Operation::Div(quantity));
-                } else {
-                    let reason = ParseErrorReason::MustNotStartWith;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Token::RightBracket(_) => {
+                    // Closing brackets of a nested sum are handled by
+                    // parse_bracket, hence this one has no opening counterpart.
+                    self.error(span, ParseErrorReason::UnbalancedBrackets);
+                    continue;
                 }
-                previous_token = PreviousToken::Div;
-                division_pending = true;
-
-                // Short-circuit to retain previous_token and division_pending into the next
-                // division
-                continue;
-            }
-            Token::Percent => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.value *= 1e-2;
-                });
-            }
-            Token::Pi(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.value *= PI.powi(exponents.unit) * 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::LeftBracket => {
-                if let Some(quantity) = active_quantity.take() {
-                    stack.push(Operation::Mul(quantity));
-                } else {
-                    /*
-                    If a bracket starts and the previous token was not an operator, add
-                    a buffer operation (multiplication with one) to the stack so the stack popping
-                    stops at the opening bracket when the closing (right) bracket comes.
-                     */
-                    if previous_token == PreviousToken::Other {
-                        stack.push(Operation::Mul(DynQuantity::new(
-                            Complex::new(1.0, 0.0),
-                            Unit::default(),
-                        )));
+                Token::Pow => {
+                    // Exponents are handled together with their base, hence
+                    // this operator has no base.
+                    if previous_token != PreviousToken::Other {
+                        self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
+                    } else if term.is_none() {
+                        self.error(span, ParseErrorReason::MustNotStartWith);
+                    } else {
+                        self.error(span, ParseErrorReason::UnexpectedToken);
                     }
+                    continue;
                 }
-                bracket_level += 1;
+                token => self.apply_factor(&mut term, token, span),
             }
-            Token::RightBracket(exponent) => match bracket_level.checked_sub(1) {
-                // Merge all stack elements with the current bracket up to and including the first
-                // "multiply".
-                Some(val) => {
-                    if let Some(mut quantity) = active_quantity.take() {
-                        while let Some(stack_item) = stack.pop() {
-                            match stack_item {
-                                Operation::Add(elem) => {
-                                    quantity.try_add_assign(&elem).map_err(|add| {
-                                        return ParseError {
-                                            substring: s[lexer.span()].to_owned(),
-                                            span: lexer.span(),
-                                            reason: ParseErrorReason::UnitsNotEqual(add),
-                                        };
-                                    })?
-                                }
-                                Operation::Mul(elem) => {
-                                    quantity = elem * quantity.powi(exponent);
-                                    break;
-                                }
-                                Operation::Div(elem) => {
-                                    quantity = elem / quantity.powi(exponent);
-                                    break;
-                                }
-                            }
-                        }
+            term_span = Some(term_start..self.consumed);
+            previous_token = PreviousToken::Other;
+        }
 
-                        // The resolved bracket becomes the new active quantity
-                        active_quantity = Some(quantity);
+        self.add_term(&mut sum, term, term_span);
+        return sum;
+    }
+
+    /// Returns the rank of the angular unit of the next token, if any.
+    fn next_angle_rank(&mut self) -> Option<u8> {
+        match self.tokens.peek() {
+            Some((Ok(token), _)) => angle_rank(token),
+            _ => None,
+        }
+    }
 
-                        // Adjust the bracket level
-                        bracket_level = val;
+    /**
+    Parses the divisor following a division operator: An optional sign and
+    exactly one factor. Returns `None` if no factor follows the operator.
+     */
+    fn parse_divisor(&mut self) -> Option<DynQuantity<Complex<f64>>> {
+        let mut divisor: Option<DynQuantity<Complex<f64>>> = None;
+        while let Some((token, _)) = self.tokens.peek() {
+            if matches!(token, Ok(Token::RightBracket(_))) {
+                break;
+            }
+            let (token, span) = self.next_token().expect("token has been peeked");
+            match token {
+                Ok(Token::Add) | Ok(Token::Sub) => {
+                    if divisor.is_some() {
+                        self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
                     } else {
-                        let reason = ParseErrorReason::UnbalancedBrackets;
-                        return Err(ParseError {
-                            substring: s[lexer.span()].to_owned(),
-                            span: lexer.span(),
-                            reason,
-                        });
+                        let sign = if token == Ok(Token::Add) { 1.0 } else { -1.0 };
+                        divisor = Some(DynQuantity::new(Complex::new(sign, 0.0), Unit::default()));
                     }
                 }
-                None => {
-                    let reason = ParseErrorReason::UnbalancedBrackets;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Ok(Token::Mul) | Ok(Token::Div) | Ok(Token::Pow) => {
+                    self.error(span, ParseErrorReason::TwoOperatorsWithoutNumber);
                 }
-            },
-            Token::Add => {
-                // Constructs such as "-+" or "++" are not allowed, but *+1 is
-                if previous_token == PreviousToken::Add || previous_token == PreviousToken::Sub {
-                    let reason = ParseErrorReason::TwoOperatorsWithoutNumber;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Ok(Token::LeftBracket) => {
+                    if let Some(bracket) = self.parse_bracket(span.clone()) {
+                        multiply(&mut divisor, bracket);
+                    }
+                    return divisor;
                 }
-
-                let new_quantity = DynQuantity::new(Complex::new(1.0, 0.0), Unit::default());
-                if let Some(quantity) = active_quantity.replace(new_quantity) {
-                    stack.push(Operation::Add(quantity));
+                Ok(token) => {
+                    self.apply_factor(&mut divisor, token, span);
+                    return divisor;
                 }
-                previous_token = PreviousToken::Add;
-                continue;
-            }
-            Token::Sub => {
-                // Constructs such as "+-" or "--" are not allowed, but /-1 or /+1 is
-                if previous_token == PreviousToken::Add || previous_token == PreviousToken::Sub {
-                    let reason = ParseErrorReason::TwoOperatorsWithoutNumber;
-                    return Err(ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason,
-                    });
+                Err(err) => {
+                    self.error(span, err.into());
                 }
+            }
+        }
+        return divisor;
+    }
 
-                let new_quantity = DynQuantity::new(Complex::new(-1.0, 0.0), Unit::default());
-                if let Some(quantity) = active_quantity.replace(new_quantity) {
-                    stack.push(Operation::Add(quantity));
+    /**
+    Parses the content of a bracket (the opening bracket has already been
+    consumed) including the closing bracket and all exponentiations following
+    it.
+     */
+    fn parse_bracket(&mut self, opening: Span) -> Option<DynQuantity<Complex<f64>>> {
+        let num_errors = self.errors.len();
+        let quantity = self.parse_sum(true);
+        match self.next_token() {
+            Some((Ok(Token::RightBracket(exponent)), span)) => match quantity {
+                Some(quantity) => {
+                    let exponent = self
+                        .decimal_exponent(&span)
+                        .unwrap_or(Ratio::from_integer(exponent));
+                    let exponent = self.parse_powers(exponent)?;
+                    return self.power(quantity, exponent, opening.start..self.consumed);
                 }
-                previous_token = PreviousToken::Sub;
-                continue;
-            }
-            Token::PowerOfTen(exponent) => {
-                if let Some(quantity) = active_quantity.as_mut() {
-                    quantity.value *= 10f64.powi(exponent);
-                } else {
-                    active_quantity = Some(DynQuantity::new(
-                        Complex::new(10f64.powi(exponent), 0.0),
-                        Unit::default(),
-                    ));
+                None => {
+                    // Empty brackets "()"
+                    if self.errors.len() == num_errors {
+                        self.error(span, ParseErrorReason::UnbalancedBrackets);
+                    }
+                    return None;
                 }
+            },
+            // parse_sum only stops at a closing bracket or at the end of the input
+            _ => {
+                self.error(opening, ParseErrorReason::UnbalancedBrackets);
+                return quantity;
             }
-            Token::Second(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.second += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Meter(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.meter += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Gram(mut exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    // Special treatment of gram: The prefix needs to be reduced by 3, since the SI
-                    // system works in kilogram
-                    exponents.prefix -= 3;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Ampere(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.ampere += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Kelvin(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kelvin += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Mol(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.mol += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Candela(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.candela += exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Celsius(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kelvin += exponents.unit;
-                    // Special treatment of celsius: The value needs to be corrected by an offset of
-                    // -273.15 to the power of the unit exponent
-                    quantity.value += (273.15f64).powi(exponents.unit);
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Newton(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Watt(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.second -= 3 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Joule(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Volt(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.ampere -= exponents.unit;
-                    quantity.unit.second -= 3 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Weber(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.ampere -= exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Tesla(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.ampere -= exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Henry(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.ampere -= 2 * exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
-            }
-            Token::Hertz(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.second -= exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+        }
+    }
+
+    /**
+    Applies the number, constant or unit `token` located at `span` to
+    `quantity`, see [`apply_token`]. If the token is followed by an
+    exponentiation (e.g. `2^10`) or a decimal exponent (e.g. `m^0.5`), the
+    exponent only applies to this token and not to the entire `quantity`.
+     */
+    fn apply_factor(
+        &mut self,
+        quantity: &mut Option<DynQuantity<Complex<f64>>>,
+        token: Token,
+        span: Span,
+    ) {
+        // The offset of units such as "°C" is not defined if they are
+        // prefixed or raised to a power
+        if let Some((definition, exponents)) = unit_definition(&token)
+            && definition.offset(exponents).is_none()
+        {
+            self.error(span, ParseErrorReason::UnexpectedToken);
+            return;
+        }
+        let decimal_exponent = self.decimal_exponent(&span);
+        if decimal_exponent.is_none() && !matches!(self.tokens.peek(), Some((Ok(Token::Pow), _))) {
+            apply_token(quantity, token);
+            return;
+        }
+
+        // If the token already contains an exponent (e.g. "m^2"), following
+        // exponentiations apply to this exponent (e.g. "m^2^3" equals "m^8")
+        let contained_exponent = decimal_exponent.or_else(|| {
+            if !self.input[span.clone()].contains('^') {
+                return None;
             }
-            Token::Siemens(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram -= exponents.unit;
-                    quantity.unit.meter -= 2 * exponents.unit;
-                    quantity.unit.second += 3 * exponents.unit;
-                    quantity.unit.ampere += 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+            return contained_exponent(&token).map(Ratio::from_integer);
+        });
+        let (base, exponent) = match contained_exponent {
+            Some(exponent) => {
+                let base = base_factor(&token).expect("only these tokens contain an exponent");
+                (base, exponent)
             }
-            Token::RotationsPerMinute(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.second -= exponents.unit;
-                    quantity.value *=
-                        (1.0f64 / 60.0f64).powi(exponents.unit) * 10f64.powi(exponents.exponent());
-                });
+            None => {
+                let mut base = None;
+                apply_token(&mut base, token);
+                let Some(base) = base else {
+                    return;
+                };
+                (base, Ratio::one())
             }
-            Token::Degree(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.value *=
-                        (PI / 180.0).powi(exponents.unit) * 10f64.powi(exponents.exponent());
-                });
+        };
+        let Some(exponent) = self.parse_powers(exponent) else {
+            return;
+        };
+        if let Some(factor) = self.power(base, exponent, span.start..self.consumed) {
+            multiply(quantity, factor);
+        }
+    }
+
+    /**
+    If the token at `span` contains an exponent (e.g. `m^0` or `)^-1`) and the
+    next token are the decimal places of a number directly following it (e.g.
+    `.5`), the next token is consumed and the combined exponent is returned.
+     */
+    fn decimal_exponent(&mut self, span: &Span) -> Option<Ratio<i32>> {
+        let text = &self.input[span.clone()];
+        let exponent_start = text.find('^')? + 1;
+        let decimals = match self.tokens.peek() {
+            Some((Ok(Token::Real(_)), next)) if next.start == span.end => next.clone(),
+            _ => return None,
+        };
+        if !self.input[decimals.clone()].starts_with('.') {
+            return None;
+        }
+        self.next_token();
+        let exponent = format!("{}{}", &text[exponent_start..], &self.input[decimals]);
+        return exponent
+            .parse::<f64>()
+            .ok()
+            .and_then(Ratio::approximate_float);
+    }
+
+    /**
+    Parses the exponentiation following a factor which is raised to the power
+    of `exponent` (e.g. one for `2` or two for `m^2`) and returns the combined
+    exponent. Exponentiation is right-associative, hence `2^3^2` equals `2^9`
+    and `m^2^3` equals `m^8`. If no exponentiation follows, `exponent` is
+    returned unchanged.
+     */
+    fn parse_powers(&mut self, exponent: Ratio<i32>) -> Option<Ratio<i32>> {
+        let Some((Ok(Token::Pow), _)) = self.tokens.peek() else {
+            return Some(exponent);
+        };
+        let (_, operator) = self.next_token().expect("token has been peeked");
+        let start = self
+            .tokens
+            .peek()
+            .map_or(operator.end, |(_, span)| span.start);
+        let power = self.parse_exponent(operator)?;
+        if exponent.is_one() {
+            return Some(power);
+        }
+        let value = (*exponent.numer() as f64 / *exponent.denom() as f64)
+            .powf(*power.numer() as f64 / *power.denom() as f64);
+        return self.rational(value, start..self.consumed);
+    }
+
+    /**
+    Parses the exponent following the exponentiation operator located at
+    `operator`: An optional sign followed by a number or an expression in
+    brackets, e.g. `-2`, `0.5`, `(-1/3)` or `(1 + 1)`. Further
+    exponentiations are applied to the exponent (e.g. `3^2` in `2^3^2`), the
+    sign is applied afterwards.
+     */
+    fn parse_exponent(&mut self, operator: Span) -> Option<Ratio<i32>> {
+        let mut sign = 1;
+        if let Some((Ok(token @ (Token::Add | Token::Sub)), _)) = self.tokens.peek() {
+            if *token == Token::Sub {
+                sign = -1;
             }
-            Token::Radians(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+            self.next_token();
+        }
+        let exponent = match self.next_token() {
+            Some((Ok(Token::Real(value)), span)) => {
+                let exponent = self.rational(value, span);
+                // Exponentiations of the exponent are parsed even if it is
+                // invalid, so that they are not mistaken for further operators
+                let power = self.parse_powers(exponent.unwrap_or(Ratio::one()));
+                exponent.and(power)
             }
-            Token::Ohm(exponents) | Token::Omega(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.second -= 3 * exponents.unit;
-                    quantity.unit.ampere -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+            Some((Ok(Token::LeftBracket), opening)) => {
+                let quantity = self.parse_bracket(opening.clone())?;
+                let span = opening.start..self.consumed;
+                if quantity.unit.is_dimensionless() && quantity.value.im == 0.0 {
+                    self.rational(quantity.value.re, span)
+                } else {
+                    self.error(span, ParseErrorReason::InvalidExponent);
+                    None
+                }
             }
-            Token::NewtonMeter(exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    quantity.unit.meter += 2 * exponents.unit;
-                    quantity.unit.second -= 2 * exponents.unit;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+            Some((_, span)) => {
+                self.error(span, ParseErrorReason::InvalidExponent);
+                None
             }
-            Token::Ton(mut exponents) => {
-                adjust(&mut active_quantity, |quantity| {
-                    quantity.unit.kilogram += exponents.unit;
-                    // Special treatment of gram: The prefix needs to be increased by 3
-                    exponents.prefix += 3;
-                    quantity.value *= 10f64.powi(exponents.exponent());
-                });
+            None => {
+                self.error(operator, ParseErrorReason::InvalidExponent);
+                None
             }
+        };
+        return exponent.map(|exponent| exponent * sign);
+    }
+
+    /**
+    Converts the exponent `value` located at `span` into a fraction. Stores an
+    error and returns `None` if this is not possible (e.g. for infinite values
+    caused by a division by zero).
+     */
+    fn rational(&mut self, value: f64, span: Span) -> Option<Ratio<i32>> {
+        let rational = Ratio::approximate_float(value);
+        if rational.is_none() {
+            self.error(span, ParseErrorReason::InvalidExponent);
         }
+        return rational;
+    }
 
-        // If the last element of the stack is a division and the next token was no open
-        // bracket, perform the division immediately
-        if division_pending {
-            if let Some(last_stack_item) = stack.last() {
-                if let Operation::Div(_) = last_stack_item {
-                    if let Some(quantity) = active_quantity.take() {
-                        // Remove the last element of the stack, so it can be used in the division
-                        let popped_quantity: DynQuantity<Complex<f64>> =
-                            stack.pop().expect("stack has at least one element").into();
-
-                        // Perform the division
-                        active_quantity = Some(popped_quantity / quantity);
+    /**
+    Raises `quantity` to the power of `exponent`. If the unit of `quantity`
+    cannot be raised to this power (e.g. `m^(1/2)`) or its exponents overflow
+    (e.g. `(m^50000)^50000`), an error is stored for `span` and `None` is
+    returned.
+     */
+    fn power(
+        &mut self,
+        quantity: DynQuantity<Complex<f64>>,
+        exponent: Ratio<i32>,
+        span: Span,
+    ) -> Option<DynQuantity<Complex<f64>>> {
+        if exponent.is_one() {
+            return Some(quantity);
+        }
+        let (numer, denom) = (*exponent.numer(), *exponent.denom());
+        let Some(unit) = quantity.unit.checked_powi(numer) else {
+            self.error(span, ParseErrorReason::InvalidExponent);
+            return None;
+        };
+        let unit = match unit.try_nthroot(denom) {
+            Ok(unit) => unit,
+            Err(error) => {
+                self.error(span, ParseErrorReason::RootError(error));
+                return None;
+            }
+        };
+        return Some(DynQuantity::new(
+            power_value(quantity.value, numer, denom),
+            unit,
+        ));
+    }
+
+    /**
+    Adds `term` to `sum`. If the units of both are not identical, an error is
+    stored and `sum` is left unchanged.
+     */
+    fn add_term(
+        &mut self,
+        sum: &mut Option<DynQuantity<Complex<f64>>>,
+        term: Option<DynQuantity<Complex<f64>>>,
+        span: Option<Span>,
+    ) {
+        if let Some(term) = term {
+            match sum.as_mut() {
+                Some(quantity) => {
+                    if let Err(error) = quantity.try_add_assign(&term) {
+                        let span = span.unwrap_or(0..self.input.len());
+                        self.error(span, error.into());
                     }
                 }
-            } else {
-                // Division without stack item would mean that the string looks something like
-                // this: "/3" This results in a parse error
-                let reason = ParseErrorReason::UnbalancedBrackets;
-                return Err(ParseError {
-                    substring: s[lexer.span()].to_owned(),
-                    span: lexer.span(),
-                    reason,
-                });
+                None => *sum = Some(term),
             }
         }
-        division_pending = false;
-        previous_token = PreviousToken::Other;
     }
-    // End of the loop
+}
 
-    // Check the bracket level
-    if bracket_level != 0 {
-        let reason = ParseErrorReason::UnbalancedBrackets;
-        return Err(ParseError {
-            substring: s[lexer.span()].to_owned(),
-            span: lexer.span(),
-            reason,
-        });
+/**
+Returns the rank of an angular unit in the degree-minute-second notation
+(0 for degree, 1 for arcminute and 2 for arcsecond). Only units without prefix
+and exponent are part of this notation, for all other tokens `None` is
+returned.
+ */
+fn angle_rank(token: &Token) -> Option<u8> {
+    let (rank, exponents) = match token {
+        Token::Degree(exponents) => (0, exponents),
+        Token::ArcMinute(exponents) => (1, exponents),
+        Token::ArcSecond(exponents) => (2, exponents),
+        _ => return None,
+    };
+    if exponents.unit == 1 && exponents.prefix == 0 {
+        return Some(rank);
     }
+    return None;
+}
+
+/**
+Returns the factor represented by a unit, constant or power of ten token
+without its exponent, e.g. `1e-3 m` for the token `mm^2` or `10` for the token
+`*10^3`. For all other tokens, `None` is returned.
+ */
+pub(super) fn base_factor(token: &Token) -> Option<DynQuantity<Complex<f64>>> {
+    if let Some((definition, exponents)) = unit_definition(token) {
+        let exponents = Exponents {
+            unit: 1,
+            prefix: exponents.prefix,
+        };
+        return Some(DynQuantity::new(
+            Complex::new(definition.scale(&exponents), 0.0),
+            definition.unit,
+        ));
+    }
+    let value = match token {
+        Token::Pi(exponents) => PI * 10f64.powi(exponents.prefix),
+        Token::PowerOfTen(_) => 10.0,
+        _ => return None,
+    };
+    return Some(DynQuantity::new(Complex::new(value, 0.0), Unit::default()));
+}
+
+/**
+Returns the exponent of a number, constant or unit token which contains an
+exponent, e.g. `2` for `m^2` or `3` for `*10^3`.
+ */
+fn contained_exponent(token: &Token) -> Option<i32> {
+    if let Some((_, exponents)) = unit_definition(token) {
+        return Some(exponents.unit);
+    }
+    match token {
+        Token::Pi(exponents) => return Some(exponents.unit),
+        Token::PowerOfTen(exponent) => return Some(*exponent),
+        _ => return None,
+    }
+}
 
-    if let Some(initial) = stack.pop() {
-        let initial = if let Some(quantity) = active_quantity.take() {
-            stack.push(initial);
-            quantity
+/**
+Raises `value` to the power of `numer / denom`. Real values are raised using
+real arithmetic, so that the result is as accurate as possible (e.g. `10^-3`
+equals `1e-3`) and overflows to infinity. Complex arithmetic is only used for
+complex values and for even roots of negative numbers.
+ */
+fn power_value(value: Complex<f64>, numer: i32, denom: i32) -> Complex<f64> {
+    let exponent = numer as f64 / denom as f64;
+    if value.im != 0.0 {
+        if denom == 1 {
+            return value.powi(numer);
+        }
+        return value.powc(Complex::new(exponent, 0.0));
+    }
+    let base = value.re;
+    if denom == 1 || base >= 0.0 || base.is_nan() {
+        return Complex::new(base.powf(exponent), 0.0);
+    }
+    // Odd roots of negative real numbers are real (e.g. "(-8)^(1/3)")
+    if denom % 2 == 1 {
+        let sign = if numer % 2 == 0 { 1.0 } else { -1.0 };
+        return Complex::new(sign * (-base).powf(exponent), 0.0);
+    }
+    return value.powc(Complex::new(exponent, 0.0));
+}
+
+/**
+Multiplies `quantity` with `factor`. If `quantity` is `None`, it is replaced by
+`factor`.
+ */
+fn multiply(quantity: &mut Option<DynQuantity<Complex<f64>>>, factor: DynQuantity<Complex<f64>>) {
+    *quantity = Some(match quantity.take() {
+        Some(quantity) => quantity * factor,
+        None => factor,
+    });
+}
+
+fn adjust<F: FnMut(&mut DynQuantity<Complex<f64>>)>(
+    active_quantity: &mut Option<DynQuantity<Complex<f64>>>,
+    mut fun: F,
+) {
+    let mut quantity = active_quantity
+        .take()
+        .unwrap_or(DynQuantity::new(Complex::new(1.0, 0.0), Unit::default()));
+
+    fun(&mut quantity);
+
+    *active_quantity = Some(quantity);
+}
+
+fn include_infinity(active_quantity: &mut Option<DynQuantity<Complex<f64>>>, infinity: f64) {
+    if let Some(quantity) = active_quantity.as_mut() {
+        let re = if quantity.value.re == 0.0 {
+            0.0
         } else {
-            initial.into()
+            quantity.value.re.signum() * infinity
         };
+        let im = if quantity.value.im == 0.0 {
+            0.0
+        } else {
+            quantity.value.re.signum() * infinity
+        };
+        quantity.value = Complex::new(re, im);
+    } else {
+        *active_quantity = Some(DynQuantity::new(
+            Complex::new(infinity, 0.0),
+            Unit::default(),
+        ));
+    }
+}
 
-        stack.into_iter().try_fold(initial, |mut acc, item| {
-            match item {
-                Operation::Add(item) => acc.try_add_assign(&item).map_err(|add| {
-                    return ParseError {
-                        substring: s[lexer.span()].to_owned(),
-                        span: lexer.span(),
-                        reason: ParseErrorReason::UnitsNotEqual(add),
-                    };
-                })?,
-                Operation::Mul(item) => {
-                    acc = item * acc;
-                }
-                Operation::Div(item) => {
-                    acc = item / acc;
-                }
+/**
+When multiplying an infinite or NaN value with zero, the IEEE result is NaN.
+However, in our case the result should be 0. Otherwise, e.g. the imaginary
+part of `2 * NaN` would be NaN (`0 * 2 + 0 * NaN`).
+ */
+fn multiply_no_nan(arg1: Complex<f64>, arg2: Complex<f64>) -> Complex<f64> {
+    // Multiply "by hand" in order to treat 0 * Inf and 0 * NaN accordingly
+    let is_zero_product =
+        |a: f64, b: f64| (!a.is_finite() && b.is_zero()) || (a.is_zero() && !b.is_finite());
+    let mut re = 0.0;
+    let mut im = 0.0;
+
+    if !is_zero_product(arg1.re, arg2.re) {
+        re += arg1.re * arg2.re;
+    }
+
+    if !is_zero_product(arg1.im, arg2.re) {
+        im += arg1.im * arg2.re;
+    }
+
+    if !is_zero_product(arg1.re, arg2.im) {
+        im += arg1.re * arg2.im;
+    }
+
+    if !is_zero_product(arg1.im, arg2.im) {
+        re -= arg1.im * arg2.im;
+    }
+
+    return Complex::new(re, im);
+}
+
+/**
+Applies a number, constant or unit token to the quantity. Numbers and
+constants multiply the quantity with their value, units additionally adjust its
+exponents. If `active_quantity` is `None`, the token is applied to a
+dimensionless quantity with the value 1.
+ */
+pub(super) fn apply_token(active_quantity: &mut Option<DynQuantity<Complex<f64>>>, token: Token) {
+    match token {
+        Token::Real(val) => {
+            if let Some(quantity) = active_quantity.as_mut() {
+                quantity.value = multiply_no_nan(quantity.value, Complex::new(val, 0.0));
+            } else {
+                *active_quantity = Some(DynQuantity::new(Complex::new(val, 0.0), Unit::default()));
             }
-            Ok(acc)
-        })
-    } else {
-        if let Some(quantity) = active_quantity.take() {
-            return Ok(quantity);
-        } else {
-            let reason = ParseErrorReason::InputIsEmpty;
-            return Err(ParseError {
-                substring: s[lexer.span()].to_owned(),
-                span: lexer.span(),
-                reason,
+        }
+        Token::Imag(val) => {
+            if let Some(quantity) = active_quantity.as_mut() {
+                quantity.value = multiply_no_nan(quantity.value, Complex::new(0.0, val));
+            } else {
+                *active_quantity = Some(DynQuantity::new(Complex::new(0.0, val), Unit::default()));
+            }
+        }
+        Token::Infinity => {
+            include_infinity(active_quantity, INFINITY);
+        }
+        Token::NegInfinity => {
+            include_infinity(active_quantity, NEG_INFINITY);
+        }
+        Token::Percent => {
+            adjust(active_quantity, |quantity| {
+                quantity.value *= 1e-2;
+            });
+        }
+        Token::Pi(exponents) => {
+            adjust(active_quantity, |quantity| {
+                quantity.value *= PI.powi(exponents.unit) * 10f64.powi(exponents.exponent());
+            });
+        }
+        Token::PowerOfTen(exponent) => {
+            if let Some(quantity) = active_quantity.as_mut() {
+                quantity.value *= 10f64.powi(exponent);
+            } else {
+                *active_quantity = Some(DynQuantity::new(
+                    Complex::new(10f64.powi(exponent), 0.0),
+                    Unit::default(),
+                ));
+            }
+        }
+        Token::Add
+        | Token::Sub
+        | Token::Mul
+        | Token::Div
+        | Token::Pow
+        | Token::LeftBracket
+        | Token::RightBracket(_) => {
+            unreachable!("operators and brackets are handled by the parser")
+        }
+        token => {
+            let (definition, exponents) =
+                unit_definition(&token).expect("all remaining tokens are units");
+            adjust(active_quantity, |quantity| {
+                quantity.unit *= definition.unit.powi(exponents.unit);
+                // Special treatment of celsius: The value needs to be corrected by an offset of
+                // -273.15
+                if definition.offset != 0.0 {
+                    quantity.value += definition
+                        .offset(exponents)
+                        .expect("undefined offsets are rejected by the parser");
+                }
+                quantity.value *= definition.scale(exponents);
             });
         }
     }
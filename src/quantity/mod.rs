@@ -2,6 +2,7 @@
 This module contains the [`DynQuantity`] struct and code for:
 - Interaction with a [`DynQuantity`]: Basic arithmetic operation, conversion
 from and into other types, formatting, etc.
+- Customizable string representations of [`DynQuantity`]s: See [`format`].
 - Parsing of strings into [`DynQuantity`]s: See [`from_str_impl`] only available
 if the `from_str` feature is enabled).
 - Serialization and deserialization: See [`serde_impl`] (only available if the
@@ -17,15 +18,41 @@ use num::complex::ComplexFloat;
 
 use std::ops::{Div, DivAssign, Mul, MulAssign};
 
-use crate::error::{ConversionError, NotConvertibleFromComplexF64, RootError, UnitsNotEqual};
-use crate::unit::Unit;
+use crate::error::{
+    AdditionError, ConversionError, KindsNotEqual, NotConvertibleFromComplexF64, RootError,
+    UnitsNotEqual,
+};
+use crate::unit::{PredefUnit, Unit};
+
+#[cfg(feature = "from_str")]
+pub mod complete;
+
+#[cfg(feature = "from_str")]
+pub mod compound;
+
+pub mod dms;
+
+#[cfg(feature = "from_str")]
+pub mod equation;
+
+#[cfg(feature = "from_str")]
+pub mod find;
+
+pub mod format;
 
 #[cfg(feature = "from_str")]
 pub mod from_str_impl;
 
+pub mod rkm;
+
+pub mod scaled_unit;
+
 #[cfg(feature = "serde")]
 pub mod serde_impl;
 
+#[cfg(feature = "from_str")]
+pub mod tokenize;
+
 #[cfg(feature = "uom")]
 pub mod uom_impl;
 
@@ -119,7 +146,7 @@ impl F64RealOrComplex for f64 {
     }
 
     fn set_im_f64(&mut self, _: f64) -> () {
-        ();
+        // A real number has no imaginary part
     }
 
     fn nth_root(self, n: i32) -> Self {
@@ -129,7 +156,7 @@ impl F64RealOrComplex for f64 {
 
 impl F64RealOrComplex for Complex<f64> {
     fn try_from_complexf64(number: Complex<f64>) -> Result<Self, NotConvertibleFromComplexF64> {
-        return Ok(Complex::<f64>::from(number));
+        return Ok(number);
     }
 
     fn to_complexf64(self) -> Complex<f64> {
@@ -183,6 +210,11 @@ let quantity = DynQuantity::<f64>::from_str("9.81 m/s^2").expect("parseable");
 assert_eq!(quantity.to_string(), "9.81 s^-2 m".to_string());
 ```
 
+The standard formatting flags such as `{:.3}`, `{:e}` or `{:>10}` are
+honoured. Further options (significant digits, engineering notation,
+fraction-style units etc.) are provided by the
+[`QuantityFormatter`](crate::quantity::format::QuantityFormatter).
+
 # Conversion into uom `Quantity`
 
 If the `uom` feature is enabled, a [`DynQuantity`] can be (fallible)
@@ -199,8 +231,8 @@ deserialized. Serialization creates the "standard"
 `Serialize` macro.
 
 When deserializing however, multiple options are available:
-1) Using the "standard" serialized representation of a struct. For example, the
-yaml representation of a [`DynQuantity<f64>`] looks like this:
+1) Using the "standard" serialized representation of a struct. For example,
+   the yaml representation of a [`DynQuantity<f64>`] looks like this:
 ```text
 ---
 value: 2.0
@@ -215,14 +247,14 @@ exponents:
 ```
 
 2) Deserializing directly from a string. This uses the [`std::str::FromStr`]
-implementation under the hood, see the
-[`from_str`](crate::quantity::from_str_impl) module documentation. Only
-available if the `from_str` feature is enabled.
-3) Deserialize directly from a real or complex value. This option is mainly here
-to allow deserializing a serialized [uom](https://crates.io/crates/uom) quantity
-(whose serialized representation is simply its numerical value without any
-units). For example, deserializing `5.0` into [`DynQuantity<f64>`] produces the
-same result as deserializing:
+   implementation under the hood, see the
+   [`from_str`](crate::quantity::from_str_impl) module documentation. Only
+   available if the `from_str` feature is enabled.
+3) Deserialize directly from a real or complex value. This option is mainly
+   here to allow deserializing a serialized [uom](https://crates.io/crates/uom)
+   quantity (whose serialized representation is simply its numerical value
+   without any units). For example, deserializing `5.0` into
+   [`DynQuantity<f64>`] produces the same result as deserializing:
 ```text
 ---
 value: 5.0
@@ -237,6 +269,54 @@ exponents:
 ```
 
 The three different possibilities are realized via a crate-internal untagged enum.
+
+# Kind of quantity
+
+Some physical quantities share the same [`Unit`] even though they are
+different kinds of quantities, e.g. torque and energy (both `N m` = `J`) or
+frequency and angular velocity (both `1/s`). To distinguish them, a
+[`DynQuantity`] can optionally be tagged with a [`PredefUnit`] as its
+[`kind`](DynQuantity::kind) (see [`DynQuantity::with_kind`]). The kind is
+handled as follows:
+- [`DynQuantity::try_add`] and [`DynQuantity::try_sub`] (and their assigning
+  variants) fail with [`KindsNotEqual`] if both quantities are tagged with
+  different kinds. If only one of them is tagged, the result inherits its kind.
+- Multiplication and division with a [`f64`] or an untagged dimensionless
+  [`DynQuantity`] keeps the kind. All other multiplications, divisions, powers
+  and roots return an untagged quantity, since the kind of the result is not
+  known in general.
+- If the `uom` feature is enabled, the conversion from and into a uom
+  [`Quantity`](https://docs.rs/uom/latest/uom/si/struct.Quantity.html) takes its
+  `Kind` type parameter into account, see [`uom_impl`].
+- If the `from_str` feature is enabled,
+  [`DynQuantity::from_str_with_kind`] parses a string and tags the result.
+- If the `serde` feature is enabled, the native representation has an optional
+  `kind` field, e.g. `kind: Torque`.
+- Equality (`==`) compares the kind in addition to value and unit, hence a
+  tagged quantity is not equal to an untagged one. Compare `value` and `unit`
+  directly if the kind should be ignored.
+
+```
+use dyn_quantity::{DynQuantity, PredefUnit};
+
+let torque = DynQuantity::new(2.0, PredefUnit::Torque).with_kind(PredefUnit::Torque).unwrap();
+let energy = DynQuantity::new(3.0, PredefUnit::Energy).with_kind(PredefUnit::Energy).unwrap();
+assert!(torque.try_add(&energy).is_err());
+
+// Scaling keeps the kind ...
+assert_eq!((torque * 2.0).kind, Some(PredefUnit::Torque));
+let factor = DynQuantity::new(0.5, PredefUnit::None);
+assert_eq!((torque * factor).kind, Some(PredefUnit::Torque));
+
+// ... while the product with another quantity does not.
+let time = DynQuantity::new(0.5, PredefUnit::Time);
+assert_eq!((torque * time).kind, None);
+
+// The kind is part of the equality
+let untagged = DynQuantity::new(2.0, PredefUnit::Torque);
+assert_ne!(torque, untagged);
+assert_eq!((torque.value, torque.unit), (untagged.value, untagged.unit));
+```
 */
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[repr(C)]
@@ -249,19 +329,76 @@ pub struct DynQuantity<V: F64RealOrComplex> {
     The (SI) base units of the physical quantity, represented by their exponents.
      */
     pub unit: Unit,
+    /**
+    The optional kind of the physical quantity, e.g. [`PredefUnit::Torque`]
+    for a quantity measured in `N m`. See the section "Kind of quantity" of
+    the [`DynQuantity`] docs.
+     */
+    pub kind: Option<PredefUnit>,
 }
 
 impl<V: F64RealOrComplex> DynQuantity<V> {
     /**
-    Returns a new instance of `Self`.
+    Returns a new instance of `Self` without a kind.
      */
     pub fn new<U: Into<Unit>>(value: V, unit: U) -> Self {
         return Self {
             value,
             unit: unit.into(),
+            kind: None,
         };
     }
 
+    /**
+    Tags `self` with the given `kind`. Returns
+    [`ConversionError::UnitMismatch`] if the unit of `self` does not match the
+    unit of `kind`.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit};
+
+    let torque = DynQuantity::new(2.0, PredefUnit::Torque);
+    assert_eq!(torque.kind, None);
+
+    let torque = torque.with_kind(PredefUnit::Torque).expect("units match");
+    assert_eq!(torque.kind, Some(PredefUnit::Torque));
+
+    // An energy is measured in the same unit as a torque ...
+    assert!(torque.with_kind(PredefUnit::Energy).is_ok());
+
+    // ... but not in the unit of a force.
+    assert!(torque.with_kind(PredefUnit::Force).is_err());
+    ```
+     */
+    pub fn with_kind(mut self, kind: PredefUnit) -> Result<Self, ConversionError> {
+        let expected = Unit::from(kind);
+        if expected != self.unit {
+            return Err(ConversionError::UnitMismatch {
+                expected,
+                found: self.unit,
+            });
+        }
+        self.kind = Some(kind);
+        return Ok(self);
+    }
+
+    /**
+    Checks whether `self` and `other` can be added or subtracted and returns
+    the kind of the result.
+     */
+    fn additive_kind(&self, other: &Self) -> Result<Option<PredefUnit>, AdditionError> {
+        if self.unit != other.unit {
+            return Err(UnitsNotEqual(self.unit.clone(), other.unit.clone()).into());
+        }
+        if let (Some(first), Some(second)) = (self.kind, other.kind)
+            && first != second
+        {
+            return Err(KindsNotEqual(first, second).into());
+        }
+        return Ok(self.kind.or(other.kind));
+    }
+
     /**
     Fallible addition of `self` and `other`.
 
@@ -269,7 +406,8 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     Hence, this function first compares the `.unit` fields of `self` and
     `other`. If they are identical, the `value` fields are added up and the
     resulting quantity is returned. Otherwise, a [`UnitsNotEqual`]
-    error is returned.
+    error is returned. If both quantities are tagged with different kinds, a
+    [`KindsNotEqual`] error is returned.
 
     # Examples
     ```
@@ -289,7 +427,7 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     assert!(volt1.try_add(&curr1).is_err());
     ```
      */
-    pub fn try_add(&self, other: &Self) -> Result<Self, UnitsNotEqual> {
+    pub fn try_add(&self, other: &Self) -> Result<Self, AdditionError> {
         let mut output = self.clone();
         output.try_add_assign(other)?;
         return Ok(output);
@@ -319,13 +457,10 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     assert_eq!(volt1, volt_cpy);
     ```
      */
-    pub fn try_add_assign(&mut self, other: &Self) -> Result<(), UnitsNotEqual> {
-        if self.unit == other.unit {
-            self.value += other.value;
-            return Ok(());
-        } else {
-            return Err(UnitsNotEqual(self.unit.clone(), other.unit.clone()));
-        }
+    pub fn try_add_assign(&mut self, other: &Self) -> Result<(), AdditionError> {
+        self.kind = self.additive_kind(other)?;
+        self.value += other.value;
+        return Ok(());
     }
 
     /**
@@ -335,7 +470,8 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     Hence, this function first compares the `.unit` fields of `self` and
     `other`. If they are identical, the `value` fields are subtracted up and the
     resulting quantity is returned. Otherwise, a [`UnitsNotEqual`]
-    error is returned.
+    error is returned. If both quantities are tagged with different kinds, a
+    [`KindsNotEqual`] error is returned.
 
     # Examples
     ```
@@ -355,7 +491,7 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     assert!(volt1.try_sub(&curr1).is_err());
     ```
      */
-    pub fn try_sub(&self, other: &Self) -> Result<Self, UnitsNotEqual> {
+    pub fn try_sub(&self, other: &Self) -> Result<Self, AdditionError> {
         let mut output = self.clone();
         output.try_sub_assign(other)?;
         return Ok(output);
@@ -385,17 +521,15 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     assert_eq!(volt1, volt_cpy);
     ```
      */
-    pub fn try_sub_assign(&mut self, other: &Self) -> Result<(), UnitsNotEqual> {
-        if self.unit == other.unit {
-            self.value -= other.value;
-            return Ok(());
-        } else {
-            return Err(UnitsNotEqual(self.unit.clone(), other.unit.clone()));
-        }
+    pub fn try_sub_assign(&mut self, other: &Self) -> Result<(), AdditionError> {
+        self.kind = self.additive_kind(other)?;
+        self.value -= other.value;
+        return Ok(());
     }
 
     /**
-    Raises `self` to an integer power.
+    Raises `self` to an integer power. Unless `n` is 1, the kind of the result
+    is `None`.
 
     # Examples
     ```
@@ -411,79 +545,63 @@ impl<V: F64RealOrComplex> DynQuantity<V> {
     pub fn powi(mut self, n: i32) -> Self {
         self.value = self.value.powi(n);
         self.unit = self.unit.powi(n);
+        if n != 1 {
+            self.kind = None;
+        }
         return self;
     }
 
     /**
-    Tries to calculate the `n`th root of self.
+    Returns the value of `self` expressed as a multiple of `target`, e.g. the
+    number of millimeters per minute if `target` is `1 mm/min`. Returns
+    [`ConversionError::UnitMismatch`] if the units of `self` and `target` are
+    not identical. Since `target` is a quantity, it has no offset. Use
+    [`ScaledUnit::value_of`](crate::ScaledUnit::value_of) for units with an
+    offset such as `°C`, or [`DynQuantity::value_in`], which parses `target`
+    from a string and takes its offset into account.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit};
+
+    let velocity = DynQuantity::new(0.5, PredefUnit::Velocity);
+    let mm_per_min = DynQuantity::new(1e-3 / 60.0, PredefUnit::Velocity);
+    let value = velocity.value_in_quantity(&mm_per_min).expect("same unit");
+    assert!((value - 30000.0).abs() < 1e-9);
+
+    let current = DynQuantity::new(0.5, PredefUnit::ElectricCurrent);
+    assert!(current.value_in_quantity(&mm_per_min).is_err());
+    ```
+     */
+    pub fn value_in_quantity(&self, target: &DynQuantity<f64>) -> Result<V, ConversionError> {
+        if self.unit != target.unit {
+            return Err(ConversionError::UnitMismatch {
+                expected: target.unit,
+                found: self.unit,
+            });
+        }
+        return Ok(self.value / target.value);
+    }
+
+    /**
+    Tries to calculate the `n`th root of self. Unless `n` is 1, the kind of
+    the result is `None`.
      */
     pub fn try_nthroot(mut self, n: i32) -> Result<Self, RootError> {
         self.unit = self.unit.try_nthroot(n)?;
         self.value = self.value.nth_root(n);
+        if n != 1 {
+            self.kind = None;
+        }
         return Ok(self);
     }
-}
-
-impl<V: F64RealOrComplex> std::fmt::Display for DynQuantity<V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.value.im() == V::zero().im() {
-            write!(f, "{}", self.value.re())?;
-        } else {
-            write!(f, "({})", self.value)?;
-        }
-
-        // Go through all units and add them, if their exponents aren't zero
-        if self.unit.second != 0 {
-            if self.unit.second == 1 {
-                write!(f, " s")?;
-            } else {
-                write!(f, " s^{}", self.unit.second)?;
-            }
-        }
-        if self.unit.meter != 0 {
-            if self.unit.meter == 1 {
-                write!(f, " m")?;
-            } else {
-                write!(f, " m^{}", self.unit.meter)?;
-            }
-        }
-        if self.unit.kilogram != 0 {
-            if self.unit.kilogram == 1 {
-                write!(f, " kg")?;
-            } else {
-                write!(f, " kg^{}", self.unit.kilogram)?;
-            }
-        }
-        if self.unit.ampere != 0 {
-            if self.unit.ampere == 1 {
-                write!(f, " A")?;
-            } else {
-                write!(f, " A^{}", self.unit.ampere)?;
-            }
-        }
-        if self.unit.kelvin != 0 {
-            if self.unit.kelvin == 1 {
-                write!(f, " K")?;
-            } else {
-                write!(f, " K^{}", self.unit.kelvin)?;
-            }
-        }
-        if self.unit.mol != 0 {
-            if self.unit.mol == 1 {
-                write!(f, " mol")?;
-            } else {
-                write!(f, " mol^{}", self.unit.mol)?;
-            }
-        }
-        if self.unit.candela != 0 {
-            if self.unit.candela == 1 {
-                write!(f, " cd")?;
-            } else {
-                write!(f, " cd^{}", self.unit.candela)?;
-            }
-        }
 
-        return Ok(());
+    /**
+    Returns `true` if `self` is dimensionless and not tagged with a kind, i.e.
+    if multiplying with `self` does not change the kind of another quantity.
+     */
+    fn is_plain_number(&self) -> bool {
+        return self.kind.is_none() && self.unit.is_dimensionless();
     }
 }
 
@@ -507,6 +625,11 @@ impl<V: F64RealOrComplex> Mul<f64> for DynQuantity<V> {
 
 impl<V: F64RealOrComplex> MulAssign for DynQuantity<V> {
     fn mul_assign(&mut self, rhs: Self) {
+        if self.is_plain_number() {
+            self.kind = rhs.kind;
+        } else if !rhs.is_plain_number() {
+            self.kind = None;
+        }
         self.value *= rhs.value;
         self.unit *= rhs.unit;
     }
@@ -538,6 +661,9 @@ impl<V: F64RealOrComplex> Div<f64> for DynQuantity<V> {
 
 impl<V: F64RealOrComplex> DivAssign for DynQuantity<V> {
     fn div_assign(&mut self, rhs: Self) {
+        if !rhs.is_plain_number() {
+            self.kind = None;
+        }
         if self.value.is_infinite() {
             let mut value = self.value / rhs.value;
             if value.re().is_nan() {
@@ -571,12 +697,28 @@ impl<V: F64RealOrComplex> DivAssign<f64> for DynQuantity<V> {
     }
 }
 
+impl<V: F64RealOrComplex> From<DynQuantity<V>> for Unit {
+    fn from(quantity: DynQuantity<V>) -> Self {
+        return quantity.unit;
+    }
+}
+
+impl<V: F64RealOrComplex> From<&DynQuantity<V>> for Unit {
+    fn from(quantity: &DynQuantity<V>) -> Self {
+        return quantity.unit;
+    }
+}
+
 impl TryFrom<DynQuantity<Complex<f64>>> for DynQuantity<f64> {
     type Error = NotConvertibleFromComplexF64;
 
     fn try_from(quantity: DynQuantity<Complex<f64>>) -> Result<Self, Self::Error> {
         if quantity.value.im() == 0.0 {
-            return Ok(DynQuantity::new(quantity.value.re(), quantity.unit));
+            return Ok(DynQuantity {
+                value: quantity.value.re(),
+                unit: quantity.unit,
+                kind: quantity.kind,
+            });
         } else {
             return Err(NotConvertibleFromComplexF64 {
                 source: quantity.value,
@@ -588,25 +730,29 @@ impl TryFrom<DynQuantity<Complex<f64>>> for DynQuantity<f64> {
 
 impl From<DynQuantity<f64>> for DynQuantity<Complex<f64>> {
     fn from(value: DynQuantity<f64>) -> Self {
-        return DynQuantity::new(Complex::new(value.value, 0.0), value.unit);
+        return DynQuantity {
+            value: Complex::new(value.value, 0.0),
+            unit: value.unit,
+            kind: value.kind,
+        };
     }
 }
 
 impl From<&DynQuantity<f64>> for DynQuantity<f64> {
     fn from(value: &DynQuantity<f64>) -> Self {
-        return DynQuantity::new(value.value, value.unit);
+        return *value;
     }
 }
 
 impl From<&DynQuantity<f64>> for DynQuantity<Complex<f64>> {
     fn from(value: &DynQuantity<f64>) -> Self {
-        return DynQuantity::new(Complex::new(value.value, 0.0), value.unit);
+        return DynQuantity::from(*value);
     }
 }
 
 impl From<&DynQuantity<Complex<f64>>> for DynQuantity<Complex<f64>> {
     fn from(value: &DynQuantity<Complex<f64>>) -> Self {
-        return DynQuantity::new(value.value, value.unit);
+        return *value;
     }
 }
 
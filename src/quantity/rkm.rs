@@ -0,0 +1,247 @@
+/*!
+This module implements the RKM code (IEC 60062) for [`DynQuantity`], which is
+used in schematics and bills of materials to write component values such as
+`4k7` (4.7 k), `R47` (0.47) or `2n2` (2.2 n). In this notation, the SI prefix
+(or `R` for a factor of one) replaces the decimal point.
+
+Parsing is opt-in via [`DynQuantity::from_str_rkm`] (only available if the
+`from_str` feature is enabled), since the notation conflicts with the regular
+syntax: `5m` is 5 milli in RKM code, but 5 meters for the
+[`FromStr`](std::str::FromStr) implementation. The inverse operation is
+provided by [`DynQuantity::to_rkm`].
+
+# Examples
+
+```
+use dyn_quantity::{DynQuantity, PredefUnit};
+
+let resistance = DynQuantity::<f64>::from_str_rkm("4k7 Ω").expect("valid");
+assert_eq!(resistance.value, 4700.0);
+assert_eq!(resistance.unit, PredefUnit::ElectricResistance.into());
+assert_eq!(resistance.to_rkm().to_string(), "4k7");
+```
+*/
+
+#[cfg(feature = "from_str")]
+use std::str::FromStr;
+
+use super::DynQuantity;
+#[cfg(feature = "from_str")]
+use super::F64RealOrComplex;
+#[cfg(feature = "from_str")]
+use crate::error::ParseError;
+#[cfg(feature = "from_str")]
+use crate::unit::Unit;
+
+/**
+Letters of the RKM code together with their power of ten. `u` is an
+alternative spelling of `µ` and is only used for parsing.
+ */
+const RKM_PREFIXES: [(char, i32); 10] = [
+    ('p', -12),
+    ('n', -9),
+    ('µ', -6),
+    ('u', -6),
+    ('m', -3),
+    ('R', 0),
+    ('k', 3),
+    ('M', 6),
+    ('G', 9),
+    ('T', 12),
+];
+
+#[cfg(feature = "from_str")]
+impl<V: F64RealOrComplex> DynQuantity<V> {
+    /**
+    Parses a string starting with a value in RKM code (IEC 60062) into a
+    [`DynQuantity`]. The RKM code consists of an optional sign, digits, one of
+    the letters `p`, `n`, `µ` (or `u`), `m`, `R`, `k`, `M`, `G`, `T` and
+    further digits, e.g. `4k7`, `R47`, `47k` or `2n2`. The letter is used as
+    decimal point and multiplies the value with the corresponding power of ten
+    (`R` stands for a factor of one).
+
+    The RKM code may be followed by a unit expression (e.g. `4k7 Ω` or
+    `4k7Ω`), which is parsed using the [`FromStr`] implementation and
+    multiplied with the value. Without a unit expression, the returned quantity
+    is dimensionless.
+
+    If the string does not start with an RKM code, it is parsed using the
+    [`FromStr`] implementation. This is also the case if the letter is directly
+    followed by another letter (as in `5ms` or `47kΩ`), since it is then the
+    prefix of a unit.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit};
+
+    let quantity = DynQuantity::<f64>::from_str_rkm("R47").expect("valid");
+    assert_eq!(quantity.value, 0.47);
+    assert!(quantity.unit.is_dimensionless());
+
+    let quantity = DynQuantity::<f64>::from_str_rkm("2M2").expect("valid");
+    assert_eq!(quantity.value, 2.2e6);
+
+    let quantity = DynQuantity::<f64>::from_str_rkm("-2n2 A").expect("valid");
+    assert_eq!(quantity.value, -2.2e-9);
+    assert_eq!(quantity.unit, PredefUnit::ElectricCurrent.into());
+
+    // Regular quantity strings are accepted as well
+    let quantity = DynQuantity::<f64>::from_str_rkm("5 ms").expect("valid");
+    assert_eq!(quantity.value, 5e-3);
+    ```
+     */
+    pub fn from_str_rkm(s: &str) -> Result<Self, ParseError> {
+        let Some((value, end)) = parse_rkm_code(s) else {
+            return Self::from_str(s);
+        };
+        let value = V::from_f64(value);
+        if s[end..].trim().is_empty() {
+            return Ok(DynQuantity::new(value, Unit::default()));
+        }
+        let mut quantity = Self::from_str(&s[end..]).map_err(|mut error| {
+            error.span = (error.span.start + end)..(error.span.end + end);
+            error
+        })?;
+        quantity.value *= value;
+        return Ok(quantity);
+    }
+}
+
+/**
+Parses the RKM code at the start of `s` (leading whitespace is ignored).
+Returns the value of the code and the byte position where the code ends, or
+`None` if `s` does not start with a RKM code.
+ */
+#[cfg(feature = "from_str")]
+fn parse_rkm_code(s: &str) -> Option<(f64, usize)> {
+    let start = s.len() - s.trim_start().len();
+    let mut chars = s[start..].char_indices().peekable();
+
+    let mut sign = 1.0;
+    if let Some((_, c @ ('+' | '-'))) = chars.peek().copied() {
+        if c == '-' {
+            sign = -1.0;
+        }
+        chars.next();
+    }
+
+    let mut integer = String::new();
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        integer.push(c);
+    }
+    let (_, letter) = chars.next()?;
+    let (_, exponent) = RKM_PREFIXES.iter().find(|(c, _)| *c == letter)?;
+    let mut fraction = String::new();
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        fraction.push(c);
+    }
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    // A letter directly after the prefix (e.g. "5ms") or a decimal point
+    // (e.g. "4k7.5") means that this is not a RKM code.
+    let end = chars.peek().map_or(s.len(), |(index, _)| start + index);
+    if s[end..].starts_with(|c: char| c == '.' || (fraction.is_empty() && c.is_alphabetic())) {
+        return None;
+    }
+
+    // Parsing the exponent together with the digits avoids rounding errors
+    let value: f64 = format!("{integer}.{fraction}e{exponent}").parse().ok()?;
+    return Some((sign * value, end));
+}
+
+impl DynQuantity<f64> {
+    /**
+    Returns a [`Rkm`] which formats the value of `self` in RKM code (IEC 60062)
+    via its [`Display`](std::fmt::Display) implementation. The unit of `self`
+    is not part of the output.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit};
+
+    let capacitance = DynQuantity::new(2.2e-9, PredefUnit::None);
+    assert_eq!(capacitance.to_rkm().to_string(), "2n2");
+
+    let resistance = DynQuantity::new(0.47, PredefUnit::ElectricResistance);
+    assert_eq!(resistance.to_rkm().to_string(), "R47");
+
+    // The precision specifies the number of significant digits
+    let resistance = DynQuantity::new(1e6, PredefUnit::ElectricResistance);
+    assert_eq!(format!("{:.2}", resistance.to_rkm()), "1M0");
+    ```
+     */
+    pub fn to_rkm(&self) -> Rkm {
+        return Rkm { value: self.value };
+    }
+}
+
+/**
+The value of a [`DynQuantity<f64>`] formatted in RKM code (IEC 60062) via its
+[`Display`](std::fmt::Display) implementation, e.g. `4k7`, `R47` or `2n2`.
+This struct is created by [`DynQuantity::to_rkm`].
+
+The letter is chosen so that the digits in front of it are between 1 and 999.
+Values between 0.1 and 1 are written with a leading `R` (e.g. `R47`), zero is
+written as `0R`. The precision of the formatter (e.g. `{:.3}`) specifies the
+number of significant digits, trailing zeros included. Without a precision,
+the value is rounded to 12 significant digits and trailing zeros are omitted.
+Values which are not finite are written using the [`Display`](std::fmt::Display)
+implementation of [`f64`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rkm {
+    value: f64,
+}
+
+impl std::fmt::Display for Rkm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.value.is_finite() {
+            return write!(f, "{}", self.value);
+        }
+        if self.value == 0.0 {
+            return write!(f, "0R");
+        }
+        let significant_digits = f.precision().unwrap_or(12).max(1);
+
+        // Rounding to the significant digits is done via the scientific
+        // representation, e.g. "4.70e3" for 4700 and three significant digits.
+        let scientific = format!("{:.*e}", significant_digits - 1, self.value.abs());
+        let (mantissa, exponent) = scientific.split_once('e').expect("scientific notation");
+        let exponent: i32 = exponent.parse().expect("valid exponent");
+        let digits = mantissa.replace('.', "");
+
+        // Values between 0.1 and 1 are written as e.g. "R47"
+        let prefix_exponent = if exponent == -1 {
+            0
+        } else {
+            (exponent.div_euclid(3) * 3).clamp(-12, 12)
+        };
+        let letter = RKM_PREFIXES
+            .iter()
+            .find(|(_, e)| *e == prefix_exponent)
+            .map(|(c, _)| *c)
+            .expect("exponent is a multiple of three between -12 and 12");
+
+        // Position of the letter within the digits
+        let position = exponent - prefix_exponent + 1;
+        let (integer, fraction) = if position <= 0 {
+            let zeros = "0".repeat(position.unsigned_abs() as usize);
+            (String::new(), format!("{zeros}{digits}"))
+        } else if position as usize >= digits.len() {
+            let zeros = "0".repeat(position as usize - digits.len());
+            (format!("{digits}{zeros}"), String::new())
+        } else {
+            let (integer, fraction) = digits.split_at(position as usize);
+            (integer.to_owned(), fraction.to_owned())
+        };
+        let fraction = if f.precision().is_some() {
+            &fraction
+        } else {
+            fraction.trim_end_matches('0')
+        };
+        let sign = if self.value < 0.0 { "-" } else { "" };
+        write!(f, "{sign}{integer}{letter}{fraction}")
+    }
+}
@@ -0,0 +1,251 @@
+/*!
+This module contains [`ScaledUnit`], a unit of measurement such as `mm`, `kN/mm^2`
+or `°C` without a value. It consists of an [`Unit`] together with a scale
+factor and an optional offset and can be used to convert values into and out of
+[`DynQuantity`]. If the `from_str` feature is enabled, a [`ScaledUnit`] can be
+parsed from a string using the same syntax as [`DynQuantity`] (see
+[`from_str_impl`](crate::quantity::from_str_impl)).
+
+# Examples
+
+```
+use std::str::FromStr;
+use dyn_quantity::{DynQuantity, PredefUnit, ScaledUnit};
+
+let stress = ScaledUnit::from_str("kN/mm^2").unwrap();
+assert_eq!(stress.factor, 1e9);
+
+// Conversion into a quantity in SI base units ...
+let quantity = stress.quantity(0.2);
+assert_eq!(quantity.value, 2e8);
+assert_eq!(quantity.unit, stress.unit);
+
+// ... and back
+assert_eq!(stress.value_of(&quantity).unwrap(), 0.2);
+
+// Scaled units can be combined
+let area = ScaledUnit::from_str("mm").unwrap().powi(2);
+let force = stress * area;
+assert_eq!(force.unit, PredefUnit::Force.into());
+assert_eq!(force.factor, 1e3);
+```
+*/
+
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+#[cfg(feature = "from_str")]
+use std::str::FromStr;
+
+#[cfg(feature = "from_str")]
+use super::tokenize::{TokenKind, tokenize};
+use super::{DynQuantity, F64RealOrComplex};
+use crate::error::ConversionError;
+#[cfg(feature = "from_str")]
+use crate::error::{ParseError, ParseErrorReason};
+use crate::unit::{PredefUnit, Unit};
+
+/**
+A unit of measurement with a scale factor and an offset, e.g. `km` or `°C`. A
+value `x` given in this unit equals `(x + offset) * factor` in the SI base
+units [`ScaledUnit::unit`]. For example, `km` is represented by a factor of
+1000 and the unit `m`, while `°C` is represented by a factor of 1, an offset of
+273.15 and the unit `K`.
+
+The offset is only meaningful for a single unit such as `°C`. Products,
+quotients and powers of scaled units (via the [`Mul`] and [`Div`]
+implementations and [`ScaledUnit::powi`]) therefore drop the offset, e.g. the
+product of `°C` and `s^-1` is a temperature change rate with the factor 1 and
+no offset.
+
+See the [module docs](crate::quantity::scaled_unit) for examples.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaledUnit {
+    /// SI base units of the scaled unit.
+    pub unit: Unit,
+    /// Factor which converts a value given in this unit into SI base units.
+    pub factor: f64,
+    /// Offset which is added to a value given in this unit before applying `factor`.
+    pub offset: f64,
+}
+
+impl ScaledUnit {
+    /**
+    Returns a [`ScaledUnit`] with the given factor and unit and without offset.
+
+    # Examples
+    ```
+    use dyn_quantity::{PredefUnit, ScaledUnit};
+
+    let kilometer = ScaledUnit::new(1e3, PredefUnit::Length);
+    assert_eq!(kilometer.quantity(2.0).value, 2000.0);
+    ```
+     */
+    pub fn new<U: Into<Unit>>(factor: f64, unit: U) -> Self {
+        return ScaledUnit {
+            unit: unit.into(),
+            factor,
+            offset: 0.0,
+        };
+    }
+
+    /**
+    Sets the offset which is added to a value given in this unit before
+    applying the factor.
+
+    # Examples
+    ```
+    use dyn_quantity::{PredefUnit, ScaledUnit};
+
+    let fahrenheit = ScaledUnit::new(5.0 / 9.0, PredefUnit::Temperature)
+        .with_offset(459.67);
+    assert!((fahrenheit.quantity(32.0).value - 273.15).abs() < 1e-12);
+    ```
+     */
+    pub fn with_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        return self;
+    }
+
+    /**
+    Returns the quantity in SI base units which corresponds to `value` given in
+    this unit.
+     */
+    pub fn quantity<V: F64RealOrComplex>(&self, value: V) -> DynQuantity<V> {
+        let mut value = value;
+        if self.offset != 0.0 {
+            value += V::from_f64(self.offset);
+        }
+        return DynQuantity::new(value * self.factor, self.unit);
+    }
+
+    /**
+    Returns the value of `quantity` given in this unit. Returns
+    [`ConversionError::UnitMismatch`] if the unit of `quantity` is not
+    identical to [`ScaledUnit::unit`].
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{DynQuantity, ScaledUnit};
+
+    let celsius = ScaledUnit::from_str("°C").unwrap();
+    let temperature = DynQuantity::<f64>::from_str("300 K").unwrap();
+    assert!((celsius.value_of(&temperature).unwrap() - 26.85).abs() < 1e-12);
+
+    let length = DynQuantity::<f64>::from_str("1 m").unwrap();
+    assert!(celsius.value_of(&length).is_err());
+    ```
+     */
+    pub fn value_of<V: F64RealOrComplex>(
+        &self,
+        quantity: &DynQuantity<V>,
+    ) -> Result<V, ConversionError> {
+        if quantity.unit != self.unit {
+            return Err(ConversionError::UnitMismatch {
+                expected: self.unit,
+                found: quantity.unit,
+            });
+        }
+        let mut value = quantity.value / self.factor;
+        if self.offset != 0.0 {
+            value -= V::from_f64(self.offset);
+        }
+        return Ok(value);
+    }
+
+    /**
+    Raises the scaled unit to the power of `n`. The offset is dropped.
+
+    # Examples
+    ```
+    use dyn_quantity::{PredefUnit, ScaledUnit};
+
+    let millimeter = ScaledUnit::new(1e-3, PredefUnit::Length);
+    let cubic_millimeter = millimeter.powi(3);
+    assert_eq!(cubic_millimeter.unit, PredefUnit::Volume.into());
+    assert_eq!(cubic_millimeter.factor, 1e-9);
+    ```
+     */
+    pub fn powi(self, n: i32) -> Self {
+        return ScaledUnit::new(self.factor.powi(n), self.unit.powi(n));
+    }
+}
+
+impl Default for ScaledUnit {
+    fn default() -> Self {
+        return ScaledUnit::new(1.0, Unit::default());
+    }
+}
+
+impl From<Unit> for ScaledUnit {
+    fn from(unit: Unit) -> Self {
+        return ScaledUnit::new(1.0, unit);
+    }
+}
+
+impl From<PredefUnit> for ScaledUnit {
+    fn from(unit: PredefUnit) -> Self {
+        return ScaledUnit::new(1.0, unit);
+    }
+}
+
+impl Mul for ScaledUnit {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        return ScaledUnit::new(self.factor * rhs.factor, self.unit * rhs.unit);
+    }
+}
+
+impl MulAssign for ScaledUnit {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for ScaledUnit {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        return ScaledUnit::new(self.factor / rhs.factor, self.unit / rhs.unit);
+    }
+}
+
+impl DivAssign for ScaledUnit {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(feature = "from_str")]
+impl FromStr for ScaledUnit {
+    type Err = ParseError;
+
+    /**
+    Parses a unit expression such as `kN/mm^2`, `1/h` or `100 km` using the
+    same syntax as the [`FromStr`](std::str::FromStr) implementation of
+    [`DynQuantity`]. Numbers within the expression become part of the factor.
+    Units with an offset such as `°C` must stand alone without prefix and
+    exponent, since the offset of e.g. `°C/s`, `k°C` or `°C^2` would be
+    ambiguous.
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<_> = tokenize(s).collect();
+        for (kind, span) in tokens.iter() {
+            if let TokenKind::Unit(token) = kind
+                && token.offset != 0.0
+            {
+                if tokens.len() == 1 {
+                    return Ok(ScaledUnit::new(token.factor, token.unit).with_offset(token.offset));
+                }
+                return Err(ParseError {
+                    substring: s[span.clone()].to_owned(),
+                    span: span.clone(),
+                    reason: ParseErrorReason::UnexpectedToken,
+                });
+            }
+        }
+        let quantity = DynQuantity::<f64>::from_str(s)?;
+        return Ok(ScaledUnit::new(quantity.value, quantity.unit));
+    }
+}
@@ -13,8 +13,8 @@ use std::str::FromStr;
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use super::F64RealOrComplex;
-use crate::error::{ConversionError, ParseError};
-use crate::unit::Unit;
+use crate::error::ConversionError;
+use crate::unit::{PredefUnit, Unit};
 
 impl<V> Serialize for DynQuantity<V>
 where
@@ -24,9 +24,14 @@ where
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("DynQuantity", 2)?;
+        let len = if self.kind.is_some() { 3 } else { 2 };
+        let mut state = serializer.serialize_struct("DynQuantity", len)?;
         state.serialize_field("value", &self.value)?;
         state.serialize_field("unit", &self.unit)?;
+        match &self.kind {
+            Some(kind) => state.serialize_field("kind", kind)?,
+            None => state.skip_field("kind")?,
+        }
         state.end()
     }
 }
@@ -54,7 +59,7 @@ where
 {
     /**
     Native representation of [`DynQuantity`] (via an alias struct in order
-    to avoid infinite recursion)-
+    to avoid infinite recursion). The `kind` field is optional.
      */
     Quantity(QuantityAlias<V>),
     /**
@@ -76,28 +81,28 @@ where
 struct QuantityAlias<V: F64RealOrComplex> {
     value: V,
     unit: Unit,
+    #[serde(default)]
+    kind: Option<PredefUnit>,
 }
 
 impl<V: F64RealOrComplex> TryFrom<QuantityVariants<V>> for DynQuantity<V> {
-    type Error = ParseError;
+    type Error = ConversionError;
 
     fn try_from(variant: QuantityVariants<V>) -> Result<Self, Self::Error> {
         match variant {
             QuantityVariants::Quantity(variant) => {
-                return Ok(Self {
-                    value: variant.value,
-                    unit: variant.unit,
-                });
+                let quantity = Self::new(variant.value, variant.unit);
+                match variant.kind {
+                    Some(kind) => return quantity.with_kind(kind),
+                    None => return Ok(quantity),
+                }
             }
             #[cfg(feature = "from_str")]
             QuantityVariants::String(string) => {
-                return Self::from_str(&string);
+                return Self::from_str(&string).map_err(ConversionError::custom);
             }
             QuantityVariants::Value(value) => {
-                return Ok(Self {
-                    value,
-                    unit: Unit::default(),
-                });
+                return Ok(Self::new(value, Unit::default()));
             }
         }
     }
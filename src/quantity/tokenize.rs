@@ -0,0 +1,412 @@
+/*!
+This module provides a documented view on the tokens of a quantity string via
+the [`tokenize`] function. It is meant for editor tooling such as syntax
+highlighting of input fields or showing the SI representation of a unit when
+hovering over it. Like [`from_str_impl`](crate::quantity::from_str_impl), this
+module is only available if the `from_str` feature is enabled.
+
+The tokenizer uses the same lexer as the [`FromStr`](std::str::FromStr)
+implementation of [`DynQuantity`](crate::DynQuantity), hence the tokens
+returned by [`tokenize`] are exactly the building blocks the parser works with.
+
+# Examples
+
+```
+use dyn_quantity::{tokenize, Bracket, Operator, PredefUnit, TokenKind, Unit};
+
+let tokens: Vec<_> = tokenize("2 (3 km^2 + 1 pi mm^2)").collect();
+assert_eq!(tokens.len(), 9);
+
+assert!(matches!(tokens[0], (TokenKind::Number(_), _)));
+assert_eq!(tokens[1], (TokenKind::Bracket(Bracket::Open), 2..3));
+
+// Units carry their SI representation
+let (kind, span) = &tokens[3];
+assert_eq!(*span, 5..9);
+match kind {
+    TokenKind::Unit(unit) => {
+        assert_eq!(unit.symbol, "m");
+        assert_eq!(unit.prefix, 3);
+        assert_eq!(unit.exponent, 2);
+        assert_eq!(unit.unit, Unit::from(PredefUnit::Area));
+        assert_eq!(unit.factor, 1e6);
+    }
+    _ => unreachable!(),
+}
+
+assert_eq!(tokens[4], (TokenKind::Operator(Operator::Add), 10..11));
+assert!(matches!(tokens[6], (TokenKind::Constant { name: "pi", .. }, _)));
+assert_eq!(tokens[8], (TokenKind::Bracket(Bracket::Close { exponent: 1 }), 21..22));
+
+// Unknown characters are returned as invalid tokens
+let tokens: Vec<_> = tokenize("1 $").collect();
+assert_eq!(tokens[1], (TokenKind::Invalid, 2..3));
+```
+*/
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use dyn_quantity_lexer::{Exponents, LexingError, Token};
+use num::Complex;
+
+use crate::unit::Unit;
+
+/**
+Splits `s` into its tokens and classifies each of them. Each token is returned
+together with its span, which can be used to index into `s`. Whitespace is not
+returned as a token. Characters which cannot be interpreted are returned as
+[`TokenKind::Invalid`].
+
+See the [module-level documentation](crate::quantity::tokenize) for an
+example.
+ */
+pub fn tokenize(s: &str) -> impl Iterator<Item = (TokenKind, Range<usize>)> + '_ {
+    return dyn_quantity_lexer::lex(s)
+        .into_iter()
+        .map(|(token, span)| (TokenKind::from_lexer(token), span));
+}
+
+/**
+The classification of a token returned by [`tokenize`].
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /**
+    A real or imaginary number, including infinity and powers of ten such as
+    `e3` or `*10^3` (which are represented by their value `1000`).
+     */
+    Number(Complex<f64>),
+    /// A unit of measurement, see [`UnitToken`].
+    Unit(UnitToken),
+    /// A mathematical constant such as `pi`.
+    Constant {
+        /// Name of the constant.
+        name: &'static str,
+        /// Value of the constant, including prefix and exponent (e.g. `pi^2`).
+        value: f64,
+    },
+    /// An arithmetic operator, see [`Operator`].
+    Operator(Operator),
+    /// An opening or closing bracket, see [`Bracket`].
+    Bracket(Bracket),
+    /**
+    A sequence of characters which could not be interpreted. This includes
+    units with an offset which are prefixed or raised to a power (e.g. `k°C`
+    or `°C^2`).
+     */
+    Invalid,
+}
+
+/**
+A unit of measurement token such as `km^2`. Besides its textual components
+(symbol, prefix and exponent), it contains the SI representation of the unit.
+For example, the token `km^2` is equivalent to `1e6 m^2`.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitToken {
+    /// Symbol of the unit without prefix and exponent, e.g. `m` for `km^2`.
+    pub symbol: &'static str,
+    /// Power of ten of the SI prefix, e.g. 3 for `km^2`.
+    pub prefix: i32,
+    /// Exponent of the unit, e.g. 2 for `km^2`.
+    pub exponent: i32,
+    /// SI base units of the token, including the exponent.
+    pub unit: Unit,
+    /**
+    Factor which converts a value given in this unit into SI base units,
+    including prefix and exponent (e.g. `1e6` for `km^2`).
+     */
+    pub factor: f64,
+    /**
+    Offset which is added to a value given in this unit before applying
+    `factor`. This is zero for all units except `°C`, where it is `273.15`.
+     */
+    pub offset: f64,
+}
+
+/// Arithmetic operators, see [`TokenKind::Operator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Percent,
+    /// `^`, if not part of a unit, constant or bracket token (e.g. in `2^10`)
+    Pow,
+}
+
+/// Brackets, see [`TokenKind::Bracket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bracket {
+    /// `(`
+    Open,
+    /// `)`, possibly followed by an exponent, e.g. `)^2`
+    Close {
+        /// Exponent of the bracket. Is 1 if no exponent is given.
+        exponent: i32,
+    },
+}
+
+impl TokenKind {
+    fn from_lexer(token: Result<Token, LexingError>) -> Self {
+        let token = match token {
+            Ok(token) => token,
+            Err(_) => return TokenKind::Invalid,
+        };
+        if let Some((definition, exponents)) = unit_definition(&token) {
+            return match definition.token(exponents) {
+                Some(unit) => TokenKind::Unit(unit),
+                None => TokenKind::Invalid,
+            };
+        }
+        match token {
+            Token::Real(value) => return TokenKind::Number(Complex::new(value, 0.0)),
+            Token::Imag(value) => return TokenKind::Number(Complex::new(0.0, value)),
+            Token::Infinity => return TokenKind::Number(Complex::new(f64::INFINITY, 0.0)),
+            Token::NegInfinity => return TokenKind::Number(Complex::new(f64::NEG_INFINITY, 0.0)),
+            Token::PowerOfTen(exponent) => {
+                return TokenKind::Number(Complex::new(10f64.powi(exponent), 0.0));
+            }
+            Token::Pi(exponents) => {
+                return TokenKind::Constant {
+                    name: "pi",
+                    value: PI.powi(exponents.unit) * 10f64.powi(exponents.exponent()),
+                };
+            }
+            Token::Add => return TokenKind::Operator(Operator::Add),
+            Token::Sub => return TokenKind::Operator(Operator::Sub),
+            Token::Mul => return TokenKind::Operator(Operator::Mul),
+            Token::Div => return TokenKind::Operator(Operator::Div),
+            Token::Percent => return TokenKind::Operator(Operator::Percent),
+            Token::Pow => return TokenKind::Operator(Operator::Pow),
+            Token::LeftBracket => return TokenKind::Bracket(Bracket::Open),
+            Token::RightBracket(exponent) => {
+                return TokenKind::Bracket(Bracket::Close { exponent });
+            }
+            _ => unreachable!("units are handled by unit_definition"),
+        }
+    }
+}
+
+/**
+Definition of a unit of measurement which is recognized by the lexer in terms
+of SI base units: A value `x` given in this unit equals
+`(x + offset) * factor * 10^power_of_ten` in SI base units.
+ */
+pub(crate) struct UnitDefinition {
+    /// Canonical symbol of the unit.
+    pub(crate) symbol: &'static str,
+    /// SI base units of the unit.
+    pub(crate) unit: Unit,
+    /**
+    Decimal part of the conversion factor into SI base units. It is stored
+    separately from `factor`, so it can be combined with the SI prefix of the
+    token without loss of precision.
+     */
+    pub(crate) power_of_ten: i32,
+    /// Non-decimal part of the conversion factor into SI base units.
+    pub(crate) factor: f64,
+    /// Offset of the unit (only nonzero for degree celsius).
+    pub(crate) offset: f64,
+}
+
+impl UnitDefinition {
+    const fn new(symbol: &'static str, unit: [i32; 7]) -> Self {
+        return UnitDefinition {
+            symbol,
+            unit: Unit {
+                second: unit[0],
+                meter: unit[1],
+                kilogram: unit[2],
+                ampere: unit[3],
+                kelvin: unit[4],
+                mol: unit[5],
+                candela: unit[6],
+            },
+            power_of_ten: 0,
+            factor: 1.0,
+            offset: 0.0,
+        };
+    }
+
+    const fn with_power_of_ten(mut self, power_of_ten: i32) -> Self {
+        self.power_of_ten = power_of_ten;
+        return self;
+    }
+
+    const fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        return self;
+    }
+
+    const fn with_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        return self;
+    }
+
+    /**
+    Returns the factor which converts a value given in this unit with the
+    given SI prefix and exponent into SI base units.
+     */
+    pub(crate) fn scale(&self, exponents: &Exponents) -> f64 {
+        return self.factor.powi(exponents.unit)
+            * 10f64.powi((exponents.prefix + self.power_of_ten) * exponents.unit);
+    }
+
+    /**
+    Returns the offset which is added to a value given in this unit with the
+    given SI prefix and exponent before scaling it. Returns `None` if the unit
+    has an offset and is prefixed or raised to a power other than one (e.g.
+    `k°C` or `°C^2`), since the offset is not defined in this case.
+     */
+    pub(crate) fn offset(&self, exponents: &Exponents) -> Option<f64> {
+        if self.offset == 0.0 {
+            return Some(0.0);
+        }
+        if exponents.unit != 1 || exponents.prefix != 0 {
+            return None;
+        }
+        return Some(self.offset);
+    }
+
+    fn token(&self, exponents: &Exponents) -> Option<UnitToken> {
+        return Some(UnitToken {
+            symbol: self.symbol,
+            prefix: exponents.prefix,
+            exponent: exponents.unit,
+            unit: self.unit.powi(exponents.unit),
+            factor: self.scale(exponents),
+            offset: self.offset(exponents)?,
+        });
+    }
+}
+
+/**
+All units of measurement recognized by the lexer. Order and symbols correspond
+to the list in the documentation of
+[`from_str_impl`](crate::quantity::from_str_impl).
+ */
+pub(crate) static UNITS: [UnitDefinition; 33] = [
+    UnitDefinition::new("s", [1, 0, 0, 0, 0, 0, 0]),
+    UnitDefinition::new("m", [0, 1, 0, 0, 0, 0, 0]),
+    UnitDefinition::new("g", [0, 0, 1, 0, 0, 0, 0]).with_power_of_ten(-3),
+    UnitDefinition::new("A", [0, 0, 0, 1, 0, 0, 0]),
+    UnitDefinition::new("K", [0, 0, 0, 0, 1, 0, 0]),
+    UnitDefinition::new("mol", [0, 0, 0, 0, 0, 1, 0]),
+    UnitDefinition::new("cd", [0, 0, 0, 0, 0, 0, 1]),
+    UnitDefinition::new("°C", [0, 0, 0, 0, 1, 0, 0]).with_offset(273.15),
+    UnitDefinition::new("V", [-3, 2, 1, -1, 0, 0, 0]),
+    UnitDefinition::new("N", [-2, 1, 1, 0, 0, 0, 0]),
+    UnitDefinition::new("Nm", [-2, 2, 1, 0, 0, 0, 0]),
+    UnitDefinition::new("W", [-3, 2, 1, 0, 0, 0, 0]),
+    UnitDefinition::new("J", [-2, 2, 1, 0, 0, 0, 0]),
+    UnitDefinition::new("Pa", [-2, -1, 1, 0, 0, 0, 0]),
+    UnitDefinition::new("Hz", [-1, 0, 0, 0, 0, 0, 0]),
+    UnitDefinition::new("rpm", [-1, 0, 0, 0, 0, 0, 0]).with_factor(1.0 / 60.0),
+    UnitDefinition::new("Wb", [-2, 2, 1, -1, 0, 0, 0]),
+    UnitDefinition::new("T", [-2, 0, 1, -1, 0, 0, 0]),
+    UnitDefinition::new("H", [-2, 2, 1, -2, 0, 0, 0]),
+    UnitDefinition::new("S", [3, -2, -1, 2, 0, 0, 0]),
+    UnitDefinition::new("C", [1, 0, 0, 1, 0, 0, 0]),
+    UnitDefinition::new("F", [4, -2, -1, 2, 0, 0, 0]),
+    UnitDefinition::new("t", [0, 0, 1, 0, 0, 0, 0]).with_power_of_ten(3),
+    UnitDefinition::new("Ω", [-3, 2, 1, -2, 0, 0, 0]),
+    UnitDefinition::new("°", [0, 0, 0, 0, 0, 0, 0]).with_factor(PI / 180.0),
+    UnitDefinition::new("′", [0, 0, 0, 0, 0, 0, 0]).with_factor(PI / 10800.0),
+    UnitDefinition::new("″", [0, 0, 0, 0, 0, 0, 0]).with_factor(PI / 648000.0),
+    UnitDefinition::new("min", [1, 0, 0, 0, 0, 0, 0]).with_factor(60.0),
+    UnitDefinition::new("h", [1, 0, 0, 0, 0, 0, 0]).with_factor(3600.0),
+    UnitDefinition::new("d", [1, 0, 0, 0, 0, 0, 0]).with_factor(86400.0),
+    UnitDefinition::new("ft", [0, 1, 0, 0, 0, 0, 0]).with_factor(0.3048),
+    UnitDefinition::new("yd", [0, 1, 0, 0, 0, 0, 0]).with_factor(0.9144),
+    UnitDefinition::new("in", [0, 1, 0, 0, 0, 0, 0]).with_factor(0.0254),
+];
+
+/**
+SI prefixes recognized by the lexer together with their power of ten. The
+alternative spelling `u` for micro is omitted.
+ */
+pub(crate) static PREFIXES: [(&str, i32); 22] = [
+    ("Q", 30),
+    ("R", 27),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+    ("r", -27),
+    ("q", -30),
+];
+
+/// Definition of radians, which is not part of [`UNITS`] since it does not
+/// change the value (it is dimensionless and has a factor of one).
+pub(crate) static RADIANS: UnitDefinition = UnitDefinition::new("rad", [0, 0, 0, 0, 0, 0, 0]);
+
+/**
+Returns the [`UnitDefinition`] and the exponents of `token`, if `token` is a
+unit of measurement.
+ */
+pub(crate) fn unit_definition(token: &Token) -> Option<(&'static UnitDefinition, &Exponents)> {
+    let (symbol, exponents) = match token {
+        Token::Second(exponents) => ("s", exponents),
+        Token::Meter(exponents) => ("m", exponents),
+        Token::Gram(exponents) => ("g", exponents),
+        Token::Ampere(exponents) => ("A", exponents),
+        Token::Kelvin(exponents) => ("K", exponents),
+        Token::Mol(exponents) => ("mol", exponents),
+        Token::Candela(exponents) => ("cd", exponents),
+        Token::Celsius(exponents) => ("°C", exponents),
+        Token::Volt(exponents) => ("V", exponents),
+        Token::Newton(exponents) => ("N", exponents),
+        Token::NewtonMeter(exponents) => ("Nm", exponents),
+        Token::Watt(exponents) => ("W", exponents),
+        Token::Joule(exponents) => ("J", exponents),
+        Token::Pascal(exponents) => ("Pa", exponents),
+        Token::Hertz(exponents) => ("Hz", exponents),
+        Token::RotationsPerMinute(exponents) => ("rpm", exponents),
+        Token::Weber(exponents) => ("Wb", exponents),
+        Token::Tesla(exponents) => ("T", exponents),
+        Token::Henry(exponents) => ("H", exponents),
+        Token::Siemens(exponents) => ("S", exponents),
+        Token::Coulomb(exponents) => ("C", exponents),
+        Token::Farad(exponents) => ("F", exponents),
+        Token::Ton(exponents) => ("t", exponents),
+        Token::Ohm(exponents) | Token::Omega(exponents) => ("Ω", exponents),
+        Token::Degree(exponents) => ("°", exponents),
+        Token::ArcMinute(exponents) => ("′", exponents),
+        Token::ArcSecond(exponents) => ("″", exponents),
+        Token::Minute(exponents) => ("min", exponents),
+        Token::Hour(exponents) => ("h", exponents),
+        Token::Day(exponents) => ("d", exponents),
+        Token::Foot(exponents) => ("ft", exponents),
+        Token::Yard(exponents) => ("yd", exponents),
+        Token::Inch(exponents) => ("in", exponents),
+        Token::Radians(exponents) => return Some((&RADIANS, exponents)),
+        _ => return None,
+    };
+    let definition = UNITS
+        .iter()
+        .find(|definition| definition.symbol == symbol)
+        .expect("all units are defined");
+    return Some((definition, exponents));
+}
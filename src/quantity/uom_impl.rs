@@ -32,14 +32,115 @@ let voltage_dyn_conv = DynQuantity::<f64>::from(ElectricPotential::new::<kilovol
 assert_eq!(voltage_dyn_conv.value, 20000.0);
 assert_eq!(voltage_dyn_conv.unit, Unit::from(PredefUnit::ElectricVoltage));
 ```
+
+# Kind of quantity
+
+uom distinguishes some quantities with identical units via the `Kind` type
+parameter of [`Quantity`], e.g. [`Torque`](uom::si::f64::Torque) (angle kind)
+and [`Energy`](uom::si::f64::Energy) (default kind). This is mirrored by
+the optional [`DynQuantity::kind`] tag:
+- Converting a [`Quantity`] with a non-default `Kind` into a [`DynQuantity`]
+  tags the result with the corresponding [`PredefUnit`], if it is unique.
+  Quantities with the default `Kind` are converted into untagged
+  [`DynQuantity`]s.
+- Converting a tagged [`DynQuantity`] into a [`Quantity`] fails with
+  [`ConversionError::KindMismatch`] if the `Kind` of the [`Quantity`] does not
+  match the tag. Untagged [`DynQuantity`]s can be converted into any [`Quantity`]
+  with the matching unit.
+
+```
+use uom::si::f64::{Energy, Torque};
+use uom::si::{energy::joule, torque::newton_meter};
+use dyn_quantity::{DynQuantity, PredefUnit};
+
+let torque = DynQuantity::<f64>::from(Torque::new::<newton_meter>(2.0));
+assert_eq!(torque.kind, Some(PredefUnit::Torque));
+assert!(Energy::try_from(torque).is_err());
+assert!(Torque::try_from(torque).is_ok());
+
+let energy = DynQuantity::<f64>::from(Energy::new::<joule>(2.0));
+assert_eq!(energy.kind, None);
+assert!(Torque::try_from(energy).is_ok());
+```
 */
 
+use std::any::TypeId;
+
 use num::{Zero, complex::Complex};
 use uom::si::*;
 
 use super::{DynQuantity, F64RealOrComplex};
 use crate::error::{ConversionError, NotConvertibleFromComplexF64};
-use crate::unit::Unit;
+use crate::unit::{PredefUnit, Unit};
+
+/**
+Returns the [`TypeId`] of the uom `Kind` trait object which is used for `kind`.
+ */
+fn uom_kind(kind: PredefUnit) -> TypeId {
+    match kind {
+        PredefUnit::Angle
+        | PredefUnit::AngularVelocity
+        | PredefUnit::AngularAcceleration
+        | PredefUnit::Torque => return TypeId::of::<dyn marker::AngleKind>(),
+        PredefUnit::SolidAngle => return TypeId::of::<dyn marker::SolidAngleKind>(),
+        PredefUnit::Temperature => return TypeId::of::<dyn marker::TemperatureKind>(),
+        PredefUnit::Radioactivity
+        | PredefUnit::NumberDensity
+        | PredefUnit::MolarConcentration
+        | PredefUnit::Molality
+        | PredefUnit::CatalyticActivityConcentration
+        | PredefUnit::ElectricCurrentDensity
+        | PredefUnit::ElectricChargeDensity
+        | PredefUnit::SurfaceChargeDensity
+        | PredefUnit::LinearChargeDensity => {
+            return TypeId::of::<dyn marker::ConstituentConcentrationKind>();
+        }
+        _ => return TypeId::of::<dyn uom::Kind>(),
+    }
+}
+
+/**
+Returns the [`PredefUnit`] with the given `unit` whose uom `Kind` is `K`, if
+there is exactly one.
+ */
+fn kind_from_type<K: ?Sized + 'static>(unit: Unit) -> Option<PredefUnit> {
+    let mut candidates = PredefUnit::candidates(unit)
+        .into_iter()
+        .filter(|candidate| uom_kind(*candidate) == TypeId::of::<K>());
+    let kind = candidates.next()?;
+    if candidates.next().is_some() {
+        return None;
+    }
+    return Some(kind);
+}
+
+/**
+Returns the kind of a [`DynQuantity`] created from a uom quantity with the
+`Kind` `K`. Quantities of the default kind are untagged.
+ */
+fn tag_from_type<K: ?Sized + 'static>(unit: Unit) -> Option<PredefUnit> {
+    if TypeId::of::<K>() == TypeId::of::<dyn uom::Kind>() {
+        return None;
+    }
+    return kind_from_type::<K>(unit);
+}
+
+/**
+Checks whether the kind of `quantity` (if any) matches the uom `Kind` `K`.
+ */
+fn check_kind<K: ?Sized + 'static, V: F64RealOrComplex>(
+    quantity: &DynQuantity<V>,
+) -> Result<(), ConversionError> {
+    if let Some(found) = quantity.kind
+        && uom_kind(found) != TypeId::of::<K>()
+    {
+        return Err(ConversionError::KindMismatch {
+            expected: kind_from_type::<K>(quantity.unit),
+            found,
+        });
+    }
+    return Ok(());
+}
 
 #[cfg(feature = "uom")]
 impl<L, M, T, I, Th, N, J, K> crate::unit::UnitFromType
@@ -77,7 +178,7 @@ where
     Th: uom::typenum::Integer,
     N: uom::typenum::Integer,
     J: uom::typenum::Integer,
-    K: ?Sized,
+    K: ?Sized + 'static,
     V: F64RealOrComplex,
 {
     type Error = ConversionError;
@@ -100,6 +201,7 @@ where
                 found: quantity.unit,
             });
         }
+        check_kind::<K, V>(&quantity)?;
 
         // Construct the uom quantity directly from raw data. This is feasible since
         // si_value() converts the quantity value to a coherent SI value (e.g by
@@ -138,7 +240,7 @@ where
     Th: uom::typenum::Integer,
     N: uom::typenum::Integer,
     J: uom::typenum::Integer,
-    K: ?Sized,
+    K: ?Sized + 'static,
     V: F64RealOrComplex,
 {
     type Error = ConversionError;
@@ -161,6 +263,7 @@ where
                 found: quantity.unit,
             });
         }
+        check_kind::<K, V>(&quantity)?;
 
         // Construct the uom quantity directly from raw data. This is feasible
         // since value converts the quantity value to a coherent SI value
@@ -186,7 +289,7 @@ where
     Th: uom::typenum::Integer,
     N: uom::typenum::Integer,
     J: uom::typenum::Integer,
-    K: ?Sized,
+    K: ?Sized + 'static,
     V: F64RealOrComplex,
 {
     fn from(
@@ -207,7 +310,7 @@ where
     Th: uom::typenum::Integer,
     N: uom::typenum::Integer,
     J: uom::typenum::Integer,
-    K: ?Sized,
+    K: ?Sized + 'static,
     V: F64RealOrComplex,
 {
     fn from(
@@ -222,7 +325,9 @@ where
             mol: N::to_i32(),
             candela: J::to_i32(),
         };
-        return DynQuantity::new(V::from_f64(quantity.value.clone()), exponents);
+        let mut output = DynQuantity::new(V::from_f64(quantity.value.clone()), exponents);
+        output.kind = tag_from_type::<K>(exponents);
+        return output;
     }
 }
 
@@ -242,7 +347,7 @@ where
     Th: uom::typenum::Integer,
     N: uom::typenum::Integer,
     J: uom::typenum::Integer,
-    K: ?Sized,
+    K: ?Sized + 'static,
     V: F64RealOrComplex,
 {
     type Error = NotConvertibleFromComplexF64;
@@ -265,6 +370,8 @@ where
             mol: N::to_i32(),
             candela: J::to_i32(),
         };
-        return Ok(DynQuantity::new(value, exponents));
+        let mut output = DynQuantity::new(value, exponents);
+        output.kind = tag_from_type::<K>(exponents);
+        return Ok(output);
     }
 }
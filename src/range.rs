@@ -0,0 +1,470 @@
+/*!
+This module contains the [`QuantityRange`] struct, which represents a nominal
+[`DynQuantity`] together with a lower and an upper bound. Such ranges appear in
+specifications, e.g. as `5 mm ± 0.1 mm`, `10 kΩ ±5%`, `10..20 V` or
+`-40 °C to 85 °C`.
+
+If the `from_str` feature is enabled, [`QuantityRange`] can be parsed from all
+of these notations via its [`FromStr`](std::str::FromStr) implementation. If
+the `serde` feature is enabled, it can be serialized and deserialized.
+*/
+
+#[cfg(feature = "from_str")]
+use std::ops::Range;
+#[cfg(feature = "from_str")]
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::DynQuantity;
+use crate::error::RangeError;
+#[cfg(feature = "from_str")]
+use crate::error::{ParseError, ParseErrorReason, UnitsNotEqual};
+#[cfg(feature = "from_str")]
+use crate::quantity::tokenize::{Operator, TokenKind, tokenize};
+
+/**
+A nominal [`DynQuantity`] together with a lower and an upper bound, e.g. a
+specification value with its tolerance. All three quantities are guaranteed to
+have the same unit and the lower bound is guaranteed to be smaller than or
+equal to the upper bound.
+
+# Parsing
+
+If the `from_str` feature is enabled, this struct can be parsed from a string
+with one of the following notations:
+- Tolerance: `5 mm ± 0.1 mm` (`+/-` and `+-` can be used instead of `±`). The
+  unit can be omitted on one side (`5 ± 0.1 mm`). The tolerance of a unit with
+  an offset is interpreted as a temperature difference, i.e. `20 °C ± 2 °C` is
+  the range from 18 °C to 22 °C.
+- Relative tolerance: `10 kΩ ±5%`.
+- Bounds: `10..20 V` or `-40 °C to 85 °C`. The unit can be omitted on one
+  side. The nominal value is the midpoint of the bounds.
+- A single quantity such as `5 V`, which results in a range without tolerance.
+
+Bounds, nominal values and tolerances must not be NaN.
+
+```
+use std::str::FromStr;
+use dyn_quantity::{DynQuantity, PredefUnit, QuantityRange};
+
+let range = QuantityRange::from_str("10 kΩ ±5%").expect("valid");
+assert_eq!(range.nominal().value, 10e3);
+assert_eq!(range.lower().value, 9.5e3);
+assert_eq!(range.upper().value, 10.5e3);
+
+let range = QuantityRange::from_str("10..20 V").expect("valid");
+assert_eq!(range.nominal().value, 15.0);
+assert!(range.contains(&DynQuantity::new(12.0, PredefUnit::ElectricVoltage)));
+assert!(!range.contains(&DynQuantity::new(21.0, PredefUnit::ElectricVoltage)));
+
+// Both ends must have the same unit
+assert!(QuantityRange::from_str("10 V..20 A").is_err());
+
+// Malformed separators and NaN bounds are rejected
+assert!(QuantityRange::from_str("5...10 V").is_err());
+assert!(QuantityRange::from_str("NaN..1").is_err());
+```
+
+# Serialization and deserialization
+
+If the `serde` feature is enabled, this struct is serialized as a struct with
+the fields `nominal`, `lower` and `upper`. It can be deserialized from this
+representation, from a single [`DynQuantity`] representation (resulting in a
+range without tolerance) and, if the `from_str` feature is enabled, from a
+string using the notations listed above.
+
+```
+use serde::Deserialize;
+use dyn_quantity::QuantityRange;
+use indoc::indoc;
+
+#[derive(Deserialize)]
+struct Specification {
+    voltage: QuantityRange,
+}
+
+let ser = indoc! {"
+---
+voltage: 230 V ± 10 %
+"};
+let spec: Specification = serde_yaml::from_str(&ser).unwrap();
+assert_eq!(spec.voltage.lower().value, 207.0);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct QuantityRange {
+    nominal: DynQuantity<f64>,
+    lower: DynQuantity<f64>,
+    upper: DynQuantity<f64>,
+}
+
+impl QuantityRange {
+    /**
+    Returns a new instance of `Self`. If `lower` is larger than `upper`, the
+    bounds are swapped. Returns an error if the units (or the kinds, see
+    [`DynQuantity::kind`]) of `nominal`, `lower` and `upper` are not identical,
+    if one of them is NaN or if `nominal` does not lie between the bounds.
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit, QuantityRange, RangeError};
+
+    let volt = |value| DynQuantity::new(value, PredefUnit::ElectricVoltage);
+    let range = QuantityRange::new(volt(5.0), volt(6.0), volt(4.0)).unwrap();
+    assert_eq!(range.lower(), volt(4.0));
+
+    assert_eq!(
+        QuantityRange::new(volt(7.0), volt(4.0), volt(6.0)).unwrap_err(),
+        RangeError::NominalOutOfBounds
+    );
+    assert_eq!(
+        QuantityRange::new(volt(5.0), volt(f64::NAN), volt(6.0)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    ```
+     */
+    pub fn new(
+        nominal: DynQuantity<f64>,
+        lower: DynQuantity<f64>,
+        upper: DynQuantity<f64>,
+    ) -> Result<Self, RangeError> {
+        nominal.try_add(&lower)?;
+        nominal.try_add(&upper)?;
+        if nominal.value.is_nan() || lower.value.is_nan() || upper.value.is_nan() {
+            return Err(RangeError::NotANumber);
+        }
+        let (lower, upper) = if lower.value > upper.value {
+            (upper, lower)
+        } else {
+            (lower, upper)
+        };
+        if nominal.value < lower.value || nominal.value > upper.value {
+            return Err(RangeError::NominalOutOfBounds);
+        }
+        return Ok(Self {
+            nominal,
+            lower,
+            upper,
+        });
+    }
+
+    /**
+    Returns a range from `lower` to `upper` whose nominal value is the midpoint
+    of the bounds. Returns an error if the units of `lower` and `upper` are not
+    identical or if the midpoint is NaN (e.g. for infinite bounds with
+    different signs).
+
+    # Examples
+    ```
+    use dyn_quantity::{DynQuantity, PredefUnit, QuantityRange};
+
+    let range = QuantityRange::from_bounds(
+        DynQuantity::new(1.0, PredefUnit::Length),
+        DynQuantity::new(2.0, PredefUnit::Length),
+    ).expect("units are identical");
+    assert_eq!(range.nominal().value, 1.5);
+    ```
+     */
+    pub fn from_bounds(
+        lower: DynQuantity<f64>,
+        upper: DynQuantity<f64>,
+    ) -> Result<Self, RangeError> {
+        let mut nominal = lower.try_add(&upper)?;
+        // Halving first prevents an overflow for large bounds
+        nominal.value = 0.5 * lower.value + 0.5 * upper.value;
+        return Self::new(nominal, lower, upper);
+    }
+
+    /**
+    Returns a range from `nominal - tolerance` to `nominal + tolerance`. The
+    sign of `tolerance` is ignored. Returns an error if the units of `nominal`
+    and `tolerance` are not identical or if a bound is NaN (e.g. for an
+    infinite nominal value and tolerance).
+     */
+    pub fn from_tolerance(
+        nominal: DynQuantity<f64>,
+        mut tolerance: DynQuantity<f64>,
+    ) -> Result<Self, RangeError> {
+        tolerance.value = tolerance.value.abs();
+        let lower = nominal.try_sub(&tolerance)?;
+        let upper = nominal.try_add(&tolerance)?;
+        return Self::new(nominal, lower, upper);
+    }
+
+    /**
+    Returns a range from `nominal * (1 - tolerance)` to
+    `nominal * (1 + tolerance)`. For example, a tolerance of 5 % is represented
+    by `tolerance = 0.05`. The sign of `tolerance` is ignored. Returns an error
+    if a bound is NaN (e.g. for a NaN tolerance).
+     */
+    pub fn from_relative_tolerance(
+        nominal: DynQuantity<f64>,
+        tolerance: f64,
+    ) -> Result<Self, RangeError> {
+        let mut deviation = nominal;
+        deviation.value = (nominal.value * tolerance).abs();
+        return Self::from_tolerance(nominal, deviation);
+    }
+
+    /// Returns the nominal value of the range.
+    pub fn nominal(&self) -> DynQuantity<f64> {
+        return self.nominal;
+    }
+
+    /// Returns the lower bound of the range.
+    pub fn lower(&self) -> DynQuantity<f64> {
+        return self.lower;
+    }
+
+    /// Returns the upper bound of the range.
+    pub fn upper(&self) -> DynQuantity<f64> {
+        return self.upper;
+    }
+
+    /**
+    Returns `true` if `quantity` can be added to the range (i.e. has the same
+    unit and a compatible kind, see [`DynQuantity::try_add`]) and lies between
+    the lower and the upper bound (inclusive).
+     */
+    pub fn contains(&self, quantity: &DynQuantity<f64>) -> bool {
+        return self.nominal.try_add(quantity).is_ok()
+            && self.lower.value <= quantity.value
+            && quantity.value <= self.upper.value;
+    }
+}
+
+/// Returns a range without tolerance or an error if `quantity` is NaN.
+impl TryFrom<DynQuantity<f64>> for QuantityRange {
+    type Error = RangeError;
+
+    fn try_from(quantity: DynQuantity<f64>) -> Result<Self, Self::Error> {
+        return Self::new(quantity, quantity, quantity);
+    }
+}
+
+#[cfg(feature = "from_str")]
+impl FromStr for QuantityRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let range_error = |err: RangeError| ParseError {
+            substring: s.to_string(),
+            span: 0..s.len(),
+            reason: err.into(),
+        };
+
+        for separator in ["±", "+/-", "+-"] {
+            let Some(index) = s.find(separator) else {
+                continue;
+            };
+            let nominal_span = 0..index;
+            let tolerance_span = index + separator.len()..s.len();
+
+            // Relative tolerance
+            if s[tolerance_span.clone()].trim_end().ends_with('%') {
+                let nominal = parse_bound(s, nominal_span, None)?;
+                let tolerance = parse_bound(s, tolerance_span, None)?;
+                if !tolerance.unit.is_dimensionless() {
+                    return Err(ParseError {
+                        substring: s.to_string(),
+                        span: 0..s.len(),
+                        reason: UnitsNotEqual(Default::default(), tolerance.unit).into(),
+                    });
+                }
+                return Self::from_relative_tolerance(nominal, tolerance.value)
+                    .map_err(range_error);
+            }
+
+            let nominal_units = unit_text(&s[nominal_span.clone()]);
+            let tolerance_units = unit_text(&s[tolerance_span.clone()]);
+            let nominal = parse_bound(s, nominal_span, Some(tolerance_units))?;
+
+            // The tolerance is a difference, hence the offset of units such as
+            // degree celsius must not be applied.
+            let units = if tolerance_units.is_empty() {
+                nominal_units
+            } else {
+                tolerance_units
+            };
+            let mut tolerance = parse_bound(s, tolerance_span, Some(nominal_units))?;
+            if !units.is_empty() {
+                let zero = DynQuantity::<f64>::from_str(&format!("0 {units}"))?;
+                tolerance.value -= zero.value;
+            }
+            return Self::from_tolerance(nominal, tolerance).map_err(range_error);
+        }
+
+        for separator in ["..", " to "] {
+            let Some(index) = s.find(separator) else {
+                continue;
+            };
+            let lower_span = 0..index;
+            let upper_span = index + separator.len()..s.len();
+
+            // "5...10" must not be interpreted as "5..(.10)"
+            if separator == ".." && s[upper_span.clone()].starts_with('.') {
+                return Err(ParseError {
+                    substring: ".".to_string(),
+                    span: upper_span.start..upper_span.start + 1,
+                    reason: ParseErrorReason::UnexpectedToken,
+                });
+            }
+            let lower_units = unit_text(&s[lower_span.clone()]);
+            let upper_units = unit_text(&s[upper_span.clone()]);
+            let lower = parse_bound(s, lower_span, Some(upper_units))?;
+            let upper = parse_bound(s, upper_span, Some(lower_units))?;
+            return Self::from_bounds(lower, upper).map_err(range_error);
+        }
+
+        return Self::try_from(parse_part(s, 0..s.len(), None)?).map_err(range_error);
+    }
+}
+
+/**
+Parses the part `span` of `s` on one side of a range or tolerance separator via
+[`parse_part`]. In contrast to a standalone quantity, such a part must contain
+a number: otherwise, `10 V ±` would be read as `10 V ± 1 V`.
+ */
+#[cfg(feature = "from_str")]
+fn parse_bound(
+    s: &str,
+    span: Range<usize>,
+    fallback_units: Option<&str>,
+) -> Result<DynQuantity<f64>, ParseError> {
+    let part = &s[span.clone()];
+    let trimmed = part.trim();
+    let start = span.start + part.len() - part.trim_start().len();
+    let trimmed_span = start..start + trimmed.len();
+    if trimmed.is_empty() {
+        return Err(ParseError {
+            substring: part.to_string(),
+            span,
+            reason: ParseErrorReason::InputIsEmpty,
+        });
+    }
+    if !tokenize(part).any(|(kind, _)| matches!(kind, TokenKind::Number(_)))
+    {
+        return Err(ParseError {
+            substring: trimmed.to_string(),
+            span: trimmed_span,
+            reason: ParseErrorReason::CouldNotParse,
+        });
+    }
+    return parse_part(s, span, fallback_units);
+}
+
+/**
+Parses the part `span` of `s` into a quantity. If the part does not contain any
+units, `fallback_units` are appended to it before parsing. The span of a
+returned error refers to `s`. Parts whose value is NaN are rejected.
+ */
+#[cfg(feature = "from_str")]
+fn parse_part(
+    s: &str,
+    span: Range<usize>,
+    fallback_units: Option<&str>,
+) -> Result<DynQuantity<f64>, ParseError> {
+    let part = &s[span.clone()];
+    let result = match fallback_units {
+        Some(units) if unit_text(part).is_empty() => {
+            DynQuantity::from_str(&format!("{part} {units}")).map_err(|mut err| {
+                // The error span must not point to the appended units
+                let trimmed = part.trim();
+                err.span.start = part.len() - part.trim_start().len();
+                err.span.end = err.span.start + trimmed.len();
+                err.substring = trimmed.to_string();
+                err
+            })
+        }
+        _ => DynQuantity::from_str(part),
+    };
+    let quantity: DynQuantity<f64> = result.map_err(|mut err| {
+        err.span = (err.span.start + span.start)..(err.span.end + span.start);
+        err
+    })?;
+    if quantity.value.is_nan() {
+        return Err(ParseError {
+            substring: part.to_string(),
+            span,
+            reason: ParseErrorReason::CouldNotParse,
+        });
+    }
+    return Ok(quantity);
+}
+
+/**
+Returns the unit expression of the quantity `s`, i.e. everything after the
+leading number and its sign. For example, the unit expression of `-5 mV` is
+`mV`.
+ */
+#[cfg(feature = "from_str")]
+fn unit_text(s: &str) -> &str {
+    for (kind, span) in tokenize(s) {
+        match kind {
+            TokenKind::Number(_)
+            | TokenKind::Operator(Operator::Add)
+            | TokenKind::Operator(Operator::Sub) => continue,
+            _ => return s[span.start..].trim_end(),
+        }
+    }
+    return "";
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for QuantityRange {
+    fn deserialize<D>(deserializer: D) -> Result<QuantityRange, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match RangeVariants::deserialize(deserializer)? {
+            RangeVariants::Range(range) => {
+                return Self::new(range.nominal, range.lower, range.upper)
+                    .map_err(serde::de::Error::custom);
+            }
+            #[cfg(feature = "from_str")]
+            RangeVariants::String(string) => {
+                return Self::from_str(&string).map_err(serde::de::Error::custom);
+            }
+            RangeVariants::Quantity(quantity) => {
+                return Self::try_from(quantity).map_err(serde::de::Error::custom);
+            }
+        }
+    }
+}
+
+/**
+A [`QuantityRange`] can be deserialized from a couple of different
+representations.
+ */
+#[cfg(feature = "serde")]
+#[derive(DeserializeUntaggedVerboseError)]
+enum RangeVariants {
+    /**
+    Native representation of [`QuantityRange`] (via an alias struct in order
+    to avoid infinite recursion).
+     */
+    Range(RangeAlias),
+    /**
+    String representation using the [`std::str::FromStr`] implementation for
+    [`QuantityRange`].
+
+    Only available if the `from_str` feature is enabled.
+     */
+    #[cfg(feature = "from_str")]
+    String(String),
+    /// A single quantity, which results in a range without tolerance.
+    Quantity(DynQuantity<f64>),
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RangeAlias {
+    nominal: DynQuantity<f64>,
+    lower: DynQuantity<f64>,
+    upper: DynQuantity<f64>,
+}
@@ -20,6 +20,11 @@ such as multiplication and division (via the [`Mul`], [`MulAssign`], [`Div`],
 [`DivAssign`] traits), exponentiation ([`Unit::powi`]) and a fallible version
 of root calculation ([`Unit::try_nthroot`]).
 
+The [`Display`](std::fmt::Display) implementation writes the exponents of all
+SI base units, e.g. `s^-3 m^0 kg^1 A^0 K^0 mol^0 cd^0`. Other representations
+(e.g. with named derived units such as `N` or `V`, LaTeX or HTML) are available
+via [`QuantityFormatter::format_unit`](crate::QuantityFormatter::format_unit).
+
 # Serialization and deserialization
 
 If the `serde` feature is enabled, this struct can be serialized and
@@ -27,8 +32,11 @@ deserialized. Serialization creates the "standard"
 [serde](https://crates.io/crates/serde) representation one would expect from the
 `Serialize` macro.
 
-An [`Unit`] can be deserialized from both its "standard" serialized
-representation and from a [`PredefUnit`] variant:
+An [`Unit`] can be deserialized from its "standard" serialized
+representation, from an array of the seven exponents (in the order second,
+meter, kilogram, ampere, kelvin, mol, candela), from a [`PredefUnit`] variant
+and, if the `from_str` feature is enabled, from a string (see the
+[`FromStr`](std::str::FromStr) implementation):
 ```
 use dyn_quantity::Unit;
 
@@ -36,10 +44,23 @@ use dyn_quantity::Unit;
 let str = "---\nsecond: -3\nmeter: 2\nkilogram: 1\nampere: -1\nkelvin: 0\nmol: 0\ncandela: 0";
 let unit_direct: Unit = serde_yaml::from_str(str).unwrap();
 
+// Deserialization from an array
+let str = "[-3, 2, 1, -1, 0, 0, 0]";
+let unit_array: Unit = serde_yaml::from_str(str).unwrap();
+assert_eq!(unit_array, unit_direct);
+
 // Deserialization from PredefUnit
 let str = "ElectricVoltage";
 let unit_predef: Unit = serde_yaml::from_str(str).unwrap();
 assert_eq!(unit_predef, unit_direct);
+
+// Deserialization from a string
+let str = "W/A";
+let unit_string: Unit = serde_yaml::from_str(str).unwrap();
+assert_eq!(unit_string, unit_direct);
+
+// Strings with a scale factor are rejected
+assert!(serde_yaml::from_str::<Unit>("mV").is_err());
 ```
  */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -61,6 +82,50 @@ pub struct Unit {
     pub candela: i32,
 }
 
+#[cfg(feature = "from_str")]
+impl std::str::FromStr for Unit {
+    type Err = crate::error::ParseError;
+
+    /**
+    Parses a unit expression such as `V/m`, `kg m^2 s^-3` or `N` using the
+    same syntax as the [`FromStr`](std::str::FromStr) implementation of
+    [`DynQuantity`](crate::DynQuantity). Since an [`Unit`] has no scale factor,
+    expressions which do not equal one of their SI base units (such as `mm`,
+    `2 m`, `%` or `°C`) are rejected with
+    [`ParseErrorReason::UnitWithScaleFactor`](crate::error::ParseErrorReason::UnitWithScaleFactor).
+    Use [`ScaledUnit`](crate::ScaledUnit) for such expressions.
+
+    # Examples
+    ```
+    use std::str::FromStr;
+    use dyn_quantity::{ParseErrorReason, PredefUnit, Unit};
+
+    assert_eq!(Unit::from_str("V/A").unwrap(), PredefUnit::ElectricResistance.into());
+    assert_eq!(Unit::from_str("kg m^2 s^-3").unwrap(), PredefUnit::Power.into());
+    assert_eq!(Unit::from_str("N").unwrap(), PredefUnit::Force.into());
+    assert_eq!(Unit::from_str("J/(N m)").unwrap(), Unit::default());
+
+    let error = Unit::from_str("mm").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::UnitWithScaleFactor);
+    ```
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::error::{ParseError, ParseErrorReason};
+
+        let quantity = crate::DynQuantity::<f64>::from_str(s)?;
+        // Allow for rounding errors of expressions such as "mm/km * Mm"
+        let factor_is_one = (quantity.value - 1.0).abs() <= 4.0 * f64::EPSILON;
+        if !factor_is_one {
+            return Err(ParseError {
+                substring: s.to_owned(),
+                span: 0..s.len(),
+                reason: ParseErrorReason::UnitWithScaleFactor,
+            });
+        }
+        return Ok(quantity.unit);
+    }
+}
+
 impl From<[i32; 7]> for Unit {
     /**
     Converts an array of seven `i32` values into `Unit`.
@@ -137,6 +202,31 @@ impl Unit {
         return self;
     }
 
+    /**
+    Raises `self` to an integer power. Returns `None` if any of the exponents
+    overflows.
+
+    # Examples
+    ```
+    use dyn_quantity::Unit;
+
+    let exponents = Unit::from([0, 1, 0, 2, 0, -2, 0]);
+    assert_eq!(exponents.checked_powi(2), Some(exponents.powi(2)));
+    assert_eq!(exponents.checked_powi(i32::MAX), None);
+    ```
+     */
+    pub fn checked_powi(self, n: i32) -> Option<Self> {
+        return Some(Unit {
+            second: self.second.checked_mul(n)?,
+            meter: self.meter.checked_mul(n)?,
+            kilogram: self.kilogram.checked_mul(n)?,
+            ampere: self.ampere.checked_mul(n)?,
+            kelvin: self.kelvin.checked_mul(n)?,
+            mol: self.mol.checked_mul(n)?,
+            candela: self.candela.checked_mul(n)?,
+        });
+    }
+
     /**
     Tries to calculate the `n`th root of self. This operation fails if any
     of the exponents is not divisible by `n`.
@@ -177,6 +267,114 @@ impl Unit {
         return Ok(self);
     }
 
+    /**
+    Returns the exponent of the given base unit.
+
+    # Examples
+    ```
+    use dyn_quantity::{BaseUnit, PredefUnit, Unit};
+
+    let unit: Unit = PredefUnit::ElectricVoltage.into();
+    assert_eq!(unit.exponent(BaseUnit::Second), -3);
+    assert_eq!(unit.exponent(BaseUnit::Kilogram), 1);
+    ```
+     */
+    pub fn exponent(&self, base_unit: BaseUnit) -> i32 {
+        match base_unit {
+            BaseUnit::Second => self.second,
+            BaseUnit::Meter => self.meter,
+            BaseUnit::Kilogram => self.kilogram,
+            BaseUnit::Ampere => self.ampere,
+            BaseUnit::Kelvin => self.kelvin,
+            BaseUnit::Mol => self.mol,
+            BaseUnit::Candela => self.candela,
+        }
+    }
+
+    /**
+    Writes `self` as a product of powers of the given `named_units` and a
+    residual in SI base units, choosing the simplest representation. Returns
+    the named units together with their exponents (in the order of
+    `named_units`) and the residual.
+
+    A representation is simpler if it consists of fewer factors, then if the
+    sum of the absolute values of the exponents is smaller and then if it
+    contains fewer named units and then if fewer named units have a negative
+    exponent. Up to two named units with exponents between -3 and 3 are
+    considered, where the exponents of two named units need to have the same
+    sign (e.g. `m^3/s` is not written as `W Pa^-1`) and the named units must
+    not be divided by a mass (e.g. `m/s^2` is not written as `N kg^-1`). If the
+    base unit representation is as simple as the best representation using
+    named units, no named units are used. Remaining ties are resolved in favour
+    of the named units which come first in `named_units`. This makes it
+    possible to state a preference for e.g. `N m` instead of `J` by omitting
+    [`NamedUnit::Joule`] from the list.
+
+    # Examples
+    ```
+    use dyn_quantity::{NamedUnit, PredefUnit, Unit};
+
+    let unit: Unit = PredefUnit::ElectricVoltage.into();
+    let (named, residual) = unit.decompose(&NamedUnit::ALL);
+    assert_eq!(named, vec![(NamedUnit::Volt, 1)]);
+    assert!(residual.is_dimensionless());
+
+    // Without joule, torque is written as N m
+    let unit: Unit = PredefUnit::Torque.into();
+    let (named, residual) = unit.decompose(&[NamedUnit::Newton, NamedUnit::Watt]);
+    assert_eq!(named, vec![(NamedUnit::Newton, 1)]);
+    assert_eq!(residual, PredefUnit::Length.into());
+
+    // Mass density stays in base units
+    let unit = Unit::from([0, -3, 1, 0, 0, 0, 0]);
+    let (named, residual) = unit.decompose(&NamedUnit::ALL);
+    assert!(named.is_empty());
+    assert_eq!(residual, unit);
+    ```
+     */
+    pub fn decompose(&self, named_units: &[NamedUnit]) -> (Vec<(NamedUnit, i32)>, Unit) {
+        const EXPONENTS: [i32; 6] = [-3, -2, -1, 1, 2, 3];
+
+        let mut best: Vec<(usize, i32)> = Vec::new();
+        let mut best_cost = decomposition_cost(&[], self);
+        let mut consider = |candidate: &[(usize, i32)]| {
+            let mut residual = *self;
+            for (index, exponent) in candidate.iter().copied() {
+                residual /= named_units[index].unit().powi(exponent);
+            }
+            // Named units must not be divided by a mass (e.g. N kg^-1)
+            if residual.kilogram * (self.kilogram - residual.kilogram) < 0 {
+                return;
+            }
+            let cost = decomposition_cost(candidate, &residual);
+            if cost < best_cost {
+                best_cost = cost;
+                best = candidate.to_vec();
+            }
+        };
+        for first in 0..named_units.len() {
+            for first_exponent in EXPONENTS {
+                consider(&[(first, first_exponent)]);
+                for second in (first + 1)..named_units.len() {
+                    for second_exponent in EXPONENTS {
+                        if first_exponent.signum() != second_exponent.signum() {
+                            continue;
+                        }
+                        consider(&[(first, first_exponent), (second, second_exponent)]);
+                    }
+                }
+            }
+        }
+
+        let mut residual = *self;
+        let mut named = Vec::with_capacity(best.len());
+        for (index, exponent) in best {
+            residual /= named_units[index].unit().powi(exponent);
+            named.push((named_units[index], exponent));
+        }
+        return (named, residual);
+    }
+
     /// Returns whether [`Unit`] is dimensionless (all exponents are zero) or
     /// not.
     pub fn is_dimensionless(&self) -> bool {
@@ -247,6 +445,177 @@ impl DivAssign for Unit {
         self.candela -= rhs.candela;
     }
 }
+
+/**
+Cost of a representation of an [`Unit`] by named units (given via their
+indices in the list of named units and their exponents) and a residual, see
+[`Unit::decompose`]. Smaller is simpler.
+ */
+fn decomposition_cost(
+    candidate: &[(usize, i32)],
+    residual: &Unit,
+) -> (usize, u32, usize, usize, usize) {
+    let base_exponents = BaseUnit::ALL.map(|base_unit| residual.exponent(base_unit));
+    let factors = candidate.len() + base_exponents.iter().filter(|e| **e != 0).count();
+    let exponent_sum: u32 = candidate
+        .iter()
+        .map(|(_, exponent)| exponent.unsigned_abs())
+        .chain(
+            base_exponents
+                .iter()
+                .map(|exponent| exponent.unsigned_abs()),
+        )
+        .sum();
+    let negative = candidate
+        .iter()
+        .filter(|(_, exponent)| *exponent < 0)
+        .count();
+    let rank: usize = candidate.iter().map(|(index, _)| *index).sum();
+    return (factors, exponent_sum, candidate.len(), negative, rank);
+}
+
+/**
+The seven SI base units which make up an [`Unit`]. This enum is e.g. used to
+specify the order in which the base units are written by a
+[`QuantityFormatter`](crate::quantity::format::QuantityFormatter).
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BaseUnit {
+    /// Second (s)
+    Second,
+    /// Meter (m)
+    Meter,
+    /// Kilogram (kg)
+    Kilogram,
+    /// Ampere (A)
+    Ampere,
+    /// Kelvin (K)
+    Kelvin,
+    /// Mol (mol)
+    Mol,
+    /// Candela (cd)
+    Candela,
+}
+
+impl BaseUnit {
+    /// All base units in the order of the fields of [`Unit`].
+    pub const ALL: [BaseUnit; 7] = [
+        BaseUnit::Second,
+        BaseUnit::Meter,
+        BaseUnit::Kilogram,
+        BaseUnit::Ampere,
+        BaseUnit::Kelvin,
+        BaseUnit::Mol,
+        BaseUnit::Candela,
+    ];
+
+    /// Returns the symbol of the base unit, e.g. `"kg"` for [`BaseUnit::Kilogram`].
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BaseUnit::Second => "s",
+            BaseUnit::Meter => "m",
+            BaseUnit::Kilogram => "kg",
+            BaseUnit::Ampere => "A",
+            BaseUnit::Kelvin => "K",
+            BaseUnit::Mol => "mol",
+            BaseUnit::Candela => "cd",
+        }
+    }
+}
+
+/**
+Named SI derived units which can be used to write an [`Unit`] in a more
+readable way, e.g. `N` instead of `s^-2 m kg` (see [`Unit::decompose`]). The
+hertz is not part of this enum, since `s^-1` is also used for e.g. angular
+velocity or rates.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamedUnit {
+    /// Watt (W): s^-3 m^2 kg
+    Watt,
+    /// Joule (J): s^-2 m^2 kg
+    Joule,
+    /// Newton (N): s^-2 m kg
+    Newton,
+    /// Pascal (Pa): s^-2 m^-1 kg
+    Pascal,
+    /// Coulomb (C): s A
+    Coulomb,
+    /// Volt (V): s^-3 m^2 kg A^-1
+    Volt,
+    /// Ohm (Ω): s^-3 m^2 kg A^-2
+    Ohm,
+    /// Siemens (S): s^3 m^-2 kg^-1 A^2
+    Siemens,
+    /// Farad (F): s^4 m^-2 kg^-1 A^2
+    Farad,
+    /// Weber (Wb): s^-2 m^2 kg A^-1
+    Weber,
+    /// Tesla (T): s^-2 kg A^-1
+    Tesla,
+    /// Henry (H): s^-2 m^2 kg A^-2
+    Henry,
+}
+
+impl NamedUnit {
+    /**
+    All named units. This list is the default preference order used when two
+    representations of an [`Unit`] are equally simple, see
+    [`Unit::decompose`].
+     */
+    pub const ALL: [NamedUnit; 12] = [
+        NamedUnit::Watt,
+        NamedUnit::Joule,
+        NamedUnit::Newton,
+        NamedUnit::Pascal,
+        NamedUnit::Coulomb,
+        NamedUnit::Volt,
+        NamedUnit::Ohm,
+        NamedUnit::Siemens,
+        NamedUnit::Farad,
+        NamedUnit::Weber,
+        NamedUnit::Tesla,
+        NamedUnit::Henry,
+    ];
+
+    /// Returns the symbol of the named unit, e.g. `"Ω"` for [`NamedUnit::Ohm`].
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            NamedUnit::Newton => "N",
+            NamedUnit::Pascal => "Pa",
+            NamedUnit::Joule => "J",
+            NamedUnit::Watt => "W",
+            NamedUnit::Coulomb => "C",
+            NamedUnit::Volt => "V",
+            NamedUnit::Farad => "F",
+            NamedUnit::Ohm => "Ω",
+            NamedUnit::Siemens => "S",
+            NamedUnit::Weber => "Wb",
+            NamedUnit::Tesla => "T",
+            NamedUnit::Henry => "H",
+        }
+    }
+
+    /// Returns the named unit expressed in SI base units.
+    pub fn unit(&self) -> Unit {
+        let array = match self {
+            NamedUnit::Newton => [-2, 1, 1, 0, 0, 0, 0],
+            NamedUnit::Pascal => [-2, -1, 1, 0, 0, 0, 0],
+            NamedUnit::Joule => [-2, 2, 1, 0, 0, 0, 0],
+            NamedUnit::Watt => [-3, 2, 1, 0, 0, 0, 0],
+            NamedUnit::Coulomb => [1, 0, 0, 1, 0, 0, 0],
+            NamedUnit::Volt => [-3, 2, 1, -1, 0, 0, 0],
+            NamedUnit::Farad => [4, -2, -1, 2, 0, 0, 0],
+            NamedUnit::Ohm => [-3, 2, 1, -2, 0, 0, 0],
+            NamedUnit::Siemens => [3, -2, -1, 2, 0, 0, 0],
+            NamedUnit::Weber => [-2, 2, 1, -1, 0, 0, 0],
+            NamedUnit::Tesla => [-2, 0, 1, -1, 0, 0, 0],
+            NamedUnit::Henry => [-2, 2, 1, -2, 0, 0, 0],
+        };
+        return array.into();
+    }
+}
+
 /**
 A trait to derive [`Unit`] from a type. This trait bridges the gap
 between (external) types representing physical quantities (such as e.g. the
@@ -304,6 +673,27 @@ use dyn_quantity::Unit;
 let str = "Volume";
 let unit: Unit = serde_yaml::from_str(str).unwrap();
 assert_eq!(unit.meter, 3);
+```
+
+Conversely, [`PredefUnit::candidates`] returns all variants matching a given
+[`Unit`]. Together with [`PredefUnit::name`] and [`PredefUnit::symbol`], this
+can be used to describe a [`Unit`] in a human-readable way. Note that some
+physical quantities share the same [`Unit`], e.g. [`PredefUnit::Torque`] and
+[`PredefUnit::Energy`] or [`PredefUnit::Frequency`] and
+[`PredefUnit::AngularVelocity`]:
+```
+use dyn_quantity::{Unit, PredefUnit};
+
+let unit = Unit::from(PredefUnit::Length).powi(2);
+let names: Vec<_> = PredefUnit::candidates(unit).iter().map(PredefUnit::name).collect();
+assert_eq!(names, vec!["area"]);
+
+let unit = Unit::from(PredefUnit::Time).powi(-1);
+let candidates = PredefUnit::candidates(unit);
+assert_eq!(
+    candidates,
+    vec![PredefUnit::Frequency, PredefUnit::AngularVelocity, PredefUnit::Radioactivity]
+);
 ```
  */
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -357,242 +747,516 @@ pub enum PredefUnit {
     ElectricConductance,
     /// SI base units representation: s^-3*m^2*kg*A^-2 (ohm)
     ElectricResistance,
-    /// SI base units representation: s^3*m^-2*kg^-1*A^2 (siemens)
+    /// SI base units representation: s^3*m^-3*kg^-1*A^2 (siemens per meter)
     ElectricConductivity,
-    /// SI base units representation: s^-3*m^3*kg*A^-2 (ohm)
+    /// SI base units representation: s^-3*m^3*kg*A^-2 (ohm meter)
     ElectricResistivity,
+    /// SI base units representation: none (radian)
+    Angle,
+    /// SI base units representation: none (steradian)
+    SolidAngle,
+    /// SI base units representation: m^-1 (1/m)
+    ReciprocalLength,
+    /// SI base units representation: s*m (m s)
+    Absement,
+    /// SI base units representation: s^-2*m (m/s^2)
+    Acceleration,
+    /// SI base units representation: s^-3*m (m/s^3)
+    Jerk,
+    /// SI base units representation: s^-2 (rad/s^2)
+    AngularAcceleration,
+    /// SI base units representation: s^-1*m^3 (m^3/s)
+    VolumeRate,
+    /// SI base units representation: s^-1*m^2 (m^2/s)
+    KinematicViscosity,
+    /// SI base units representation: s^-1*m^2 (m^2/s)
+    DiffusionCoefficient,
+    /// SI base units representation: m^-3 (1/m^3)
+    NumberDensity,
+    /// SI base units representation: m^-3*kg (kg/m^3)
+    MassDensity,
+    /// SI base units representation: m^-1*kg (kg/m)
+    LinearMassDensity,
+    /// SI base units representation: m^-2*kg (kg/m^2)
+    ArealMassDensity,
+    /// SI base units representation: m^3*kg^-1 (m^3/kg)
+    SpecificVolume,
+    /// SI base units representation: m^2*kg^-1 (m^2/kg)
+    SpecificArea,
+    /// SI base units representation: s^-1*kg (kg/s)
+    MassRate,
+    /// SI base units representation: s^-1*m^-2*kg (kg/(m^2 s))
+    MassFlux,
+    /// SI base units representation: s^-1*m*kg (kg m/s)
+    Momentum,
+    /// SI base units representation: s^-1*m^2*kg (kg m^2/s)
+    AngularMomentum,
+    /// SI base units representation: s^-1*m^2*kg (J s)
+    Action,
+    /// SI base units representation: m^2*kg (kg m^2)
+    MomentOfInertia,
+    /// SI base units representation: s^-2*m^-1*kg (Pa)
+    Pressure,
+    /// SI base units representation: s^-1*m^-1*kg (Pa s)
+    DynamicViscosity,
+    /// SI base units representation: s^-2*m^2 (J/kg)
+    SpecificEnergy,
+    /// SI base units representation: s^-2*m^2 (Gy)
+    AbsorbedDose,
+    /// SI base units representation: s^-2*m^2 (Sv)
+    EquivalentDose,
+    /// SI base units representation: s^-3*m^2 (W/kg)
+    SpecificPower,
+    /// SI base units representation: s^-4*m^2*kg (W/s)
+    PowerRate,
+    /// SI base units representation: s^-3*m*kg (W/m)
+    LinearPowerDensity,
+    /// SI base units representation: s^-3*kg (W/m^2)
+    HeatFluxDensity,
+    /// SI base units representation: s^-3*m^-1*kg (W/m^3)
+    VolumetricPowerDensity,
+    /// SI base units representation: s^-2*kg (J/m^2)
+    RadiantExposure,
+    /// SI base units representation: s^-1 (Bq)
+    Radioactivity,
+    /// SI base units representation: s*A (C)
+    ElectricCharge,
+    /// SI base units representation: s^4*m^-2*kg^-1*A^2 (F)
+    Capacitance,
+    /// SI base units representation: s^-3*m*kg*A^-1 (V/m)
+    ElectricFieldStrength,
+    /// SI base units representation: s^-3*m^3*kg*A^-1 (V m)
+    ElectricFlux,
+    /// SI base units representation: m^-2*A (A/m^2)
+    ElectricCurrentDensity,
+    /// SI base units representation: s*m^-3*A (C/m^3)
+    ElectricChargeDensity,
+    /// SI base units representation: s*m^-2*A (C/m^2)
+    SurfaceChargeDensity,
+    /// SI base units representation: s*m^-2*A (C/m^2)
+    ElectricDisplacementField,
+    /// SI base units representation: s*m^-1*A (C/m)
+    LinearChargeDensity,
+    /// SI base units representation: s*m*A (C m)
+    ElectricDipoleMoment,
+    /// SI base units representation: s^4*m^-3*kg^-1*A^2 (F/m)
+    Permittivity,
+    /// SI base units representation: s^-2*m*kg*A^-2 (H/m)
+    Permeability,
+    /// SI base units representation: m^2*A (A m^2)
+    MagneticMoment,
+    /// SI base units representation: s^2*kg^-1*A (m^2/(V s))
+    ElectricalMobility,
+    /// SI base units representation: s^-2*m^2*kg*K^-1 (J/K)
+    HeatCapacity,
+    /// SI base units representation: s^-2*m^2*kg*K^-1 (J/K)
+    Entropy,
+    /// SI base units representation: s^-2*m^2*K^-1 (J/(kg K))
+    SpecificHeatCapacity,
+    /// SI base units representation: s^-2*m^-1*kg*K^-1 (J/(m^3 K))
+    VolumetricHeatCapacity,
+    /// SI base units representation: s^-3*m*kg*K^-1 (W/(m K))
+    ThermalConductivity,
+    /// SI base units representation: s^-3*kg*K^-1 (W/(m^2 K))
+    HeatTransferCoefficient,
+    /// SI base units representation: m^-1*K (K/m)
+    TemperatureGradient,
+    /// SI base units representation: K^-1 (1/K)
+    TemperatureCoefficient,
+    /// SI base units representation: kg*mol^-1 (kg/mol)
+    MolarMass,
+    /// SI base units representation: m^3*mol^-1 (m^3/mol)
+    MolarVolume,
+    /// SI base units representation: m^-3*mol (mol/m^3)
+    MolarConcentration,
+    /// SI base units representation: kg^-1*mol (mol/kg)
+    Molality,
+    /// SI base units representation: s^-2*m^2*kg*mol^-1 (J/mol)
+    MolarEnergy,
+    /// SI base units representation: s^-2*m^2*kg*K^-1*mol^-1 (J/(mol K))
+    MolarHeatCapacity,
+    /// SI base units representation: s^-1*m^-2*mol (mol/(m^2 s))
+    MolarFlux,
+    /// SI base units representation: s^-1*mol (kat)
+    CatalyticActivity,
+    /// SI base units representation: s^-1*m^-3*mol (kat/m^3)
+    CatalyticActivityConcentration,
+    /// SI base units representation: cd (lm)
+    LuminousFlux,
+    /// SI base units representation: m^-2*cd (cd/m^2)
+    Luminance,
+    /// SI base units representation: m^-2*cd (lx)
+    Illuminance,
+}
+
+impl PredefUnit {
+    /// All variants of [`PredefUnit`] in the order of their declaration.
+    pub const ALL: [PredefUnit; 94] = [
+        PredefUnit::None,
+        PredefUnit::Time,
+        PredefUnit::Length,
+        PredefUnit::Mass,
+        PredefUnit::ElectricCurrent,
+        PredefUnit::Temperature,
+        PredefUnit::AmountOfSubstance,
+        PredefUnit::LuminousIntensity,
+        PredefUnit::Area,
+        PredefUnit::Volume,
+        PredefUnit::ElectricVoltage,
+        PredefUnit::Force,
+        PredefUnit::Torque,
+        PredefUnit::Power,
+        PredefUnit::Energy,
+        PredefUnit::Frequency,
+        PredefUnit::Velocity,
+        PredefUnit::AngularVelocity,
+        PredefUnit::MagneticFlux,
+        PredefUnit::MagneticFluxDensity,
+        PredefUnit::MagneticFieldStrength,
+        PredefUnit::Inductance,
+        PredefUnit::ElectricConductance,
+        PredefUnit::ElectricResistance,
+        PredefUnit::ElectricConductivity,
+        PredefUnit::ElectricResistivity,
+        PredefUnit::Angle,
+        PredefUnit::SolidAngle,
+        PredefUnit::ReciprocalLength,
+        PredefUnit::Absement,
+        PredefUnit::Acceleration,
+        PredefUnit::Jerk,
+        PredefUnit::AngularAcceleration,
+        PredefUnit::VolumeRate,
+        PredefUnit::KinematicViscosity,
+        PredefUnit::DiffusionCoefficient,
+        PredefUnit::NumberDensity,
+        PredefUnit::MassDensity,
+        PredefUnit::LinearMassDensity,
+        PredefUnit::ArealMassDensity,
+        PredefUnit::SpecificVolume,
+        PredefUnit::SpecificArea,
+        PredefUnit::MassRate,
+        PredefUnit::MassFlux,
+        PredefUnit::Momentum,
+        PredefUnit::AngularMomentum,
+        PredefUnit::Action,
+        PredefUnit::MomentOfInertia,
+        PredefUnit::Pressure,
+        PredefUnit::DynamicViscosity,
+        PredefUnit::SpecificEnergy,
+        PredefUnit::AbsorbedDose,
+        PredefUnit::EquivalentDose,
+        PredefUnit::SpecificPower,
+        PredefUnit::PowerRate,
+        PredefUnit::LinearPowerDensity,
+        PredefUnit::HeatFluxDensity,
+        PredefUnit::VolumetricPowerDensity,
+        PredefUnit::RadiantExposure,
+        PredefUnit::Radioactivity,
+        PredefUnit::ElectricCharge,
+        PredefUnit::Capacitance,
+        PredefUnit::ElectricFieldStrength,
+        PredefUnit::ElectricFlux,
+        PredefUnit::ElectricCurrentDensity,
+        PredefUnit::ElectricChargeDensity,
+        PredefUnit::SurfaceChargeDensity,
+        PredefUnit::ElectricDisplacementField,
+        PredefUnit::LinearChargeDensity,
+        PredefUnit::ElectricDipoleMoment,
+        PredefUnit::Permittivity,
+        PredefUnit::Permeability,
+        PredefUnit::MagneticMoment,
+        PredefUnit::ElectricalMobility,
+        PredefUnit::HeatCapacity,
+        PredefUnit::Entropy,
+        PredefUnit::SpecificHeatCapacity,
+        PredefUnit::VolumetricHeatCapacity,
+        PredefUnit::ThermalConductivity,
+        PredefUnit::HeatTransferCoefficient,
+        PredefUnit::TemperatureGradient,
+        PredefUnit::TemperatureCoefficient,
+        PredefUnit::MolarMass,
+        PredefUnit::MolarVolume,
+        PredefUnit::MolarConcentration,
+        PredefUnit::Molality,
+        PredefUnit::MolarEnergy,
+        PredefUnit::MolarHeatCapacity,
+        PredefUnit::MolarFlux,
+        PredefUnit::CatalyticActivity,
+        PredefUnit::CatalyticActivityConcentration,
+        PredefUnit::LuminousFlux,
+        PredefUnit::Luminance,
+        PredefUnit::Illuminance,
+    ];
+
+    /**
+    Returns the human-readable name of the physical quantity, e.g.
+    `"electric current"` for [`PredefUnit::ElectricCurrent`].
+
+    # Examples
+    ```
+    use dyn_quantity::PredefUnit;
+
+    assert_eq!(PredefUnit::ThermalConductivity.name(), "thermal conductivity");
+    ```
+     */
+    pub fn name(&self) -> &'static str {
+        return self.definition().0;
+    }
+
+    /**
+    Returns the symbol of the coherent SI unit of the physical quantity, e.g.
+    `"Pa"` for [`PredefUnit::Pressure`] or `"W/(m K)"` for
+    [`PredefUnit::ThermalConductivity`]. The symbol of [`PredefUnit::None`] is
+    `"1"`.
+     */
+    pub fn symbol(&self) -> &'static str {
+        return self.definition().1;
+    }
+
+    /**
+    Returns all variants whose [`Unit`] equals `unit` in the order of their
+    declaration. Since some physical quantities share the same [`Unit`]
+    (e.g. torque and energy), the returned vector can contain more than one
+    variant. It is empty if no variant matches.
+
+    # Examples
+    ```
+    use dyn_quantity::{PredefUnit, Unit};
+
+    let candidates = PredefUnit::candidates(PredefUnit::Pressure.into());
+    assert_eq!(candidates, vec![PredefUnit::Pressure]);
+
+    let candidates = PredefUnit::candidates(PredefUnit::Energy.into());
+    assert_eq!(
+        candidates,
+        vec![PredefUnit::Torque, PredefUnit::Energy]
+    );
+
+    let unit = Unit::from(PredefUnit::Length).powi(5);
+    assert!(PredefUnit::candidates(unit).is_empty());
+    ```
+     */
+    pub fn candidates(unit: Unit) -> Vec<PredefUnit> {
+        return PredefUnit::ALL
+            .into_iter()
+            .filter(|predef_unit| Unit::from(*predef_unit) == unit)
+            .collect();
+    }
+
+    /// Returns the name, the symbol and the SI base unit exponents of `self`.
+    fn definition(&self) -> (&'static str, &'static str, [i32; 7]) {
+        match self {
+            PredefUnit::None => return ("dimensionless", "1", [0, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::Time => return ("time", "s", [1, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::Length => return ("length", "m", [0, 1, 0, 0, 0, 0, 0]),
+            PredefUnit::Mass => return ("mass", "kg", [0, 0, 1, 0, 0, 0, 0]),
+            PredefUnit::ElectricCurrent => return ("electric current", "A", [0, 0, 0, 1, 0, 0, 0]),
+            PredefUnit::Temperature => return ("temperature", "K", [0, 0, 0, 0, 1, 0, 0]),
+            PredefUnit::AmountOfSubstance => {
+                return ("amount of substance", "mol", [0, 0, 0, 0, 0, 1, 0]);
+            }
+            PredefUnit::LuminousIntensity => {
+                return ("luminous intensity", "cd", [0, 0, 0, 0, 0, 0, 1]);
+            }
+            PredefUnit::Area => return ("area", "m^2", [0, 2, 0, 0, 0, 0, 0]),
+            PredefUnit::Volume => return ("volume", "m^3", [0, 3, 0, 0, 0, 0, 0]),
+            PredefUnit::ElectricVoltage => {
+                return ("electric voltage", "V", [-3, 2, 1, -1, 0, 0, 0]);
+            }
+            PredefUnit::Force => return ("force", "N", [-2, 1, 1, 0, 0, 0, 0]),
+            PredefUnit::Torque => return ("torque", "N m", [-2, 2, 1, 0, 0, 0, 0]),
+            PredefUnit::Power => return ("power", "W", [-3, 2, 1, 0, 0, 0, 0]),
+            PredefUnit::Energy => return ("energy", "J", [-2, 2, 1, 0, 0, 0, 0]),
+            PredefUnit::Frequency => return ("frequency", "Hz", [-1, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::Velocity => return ("velocity", "m/s", [-1, 1, 0, 0, 0, 0, 0]),
+            PredefUnit::AngularVelocity => {
+                return ("angular velocity", "rad/s", [-1, 0, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::MagneticFlux => return ("magnetic flux", "Wb", [-2, 2, 1, -1, 0, 0, 0]),
+            PredefUnit::MagneticFluxDensity => {
+                return ("magnetic flux density", "T", [-2, 0, 1, -1, 0, 0, 0]);
+            }
+            PredefUnit::MagneticFieldStrength => {
+                return ("magnetic field strength", "A/m", [0, -1, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::Inductance => return ("inductance", "H", [-2, 2, 1, -2, 0, 0, 0]),
+            PredefUnit::ElectricConductance => {
+                return ("electric conductance", "S", [3, -2, -1, 2, 0, 0, 0]);
+            }
+            PredefUnit::ElectricResistance => {
+                return ("electric resistance", "Ω", [-3, 2, 1, -2, 0, 0, 0]);
+            }
+            PredefUnit::ElectricConductivity => {
+                return ("electric conductivity", "S/m", [3, -3, -1, 2, 0, 0, 0]);
+            }
+            PredefUnit::ElectricResistivity => {
+                return ("electric resistivity", "Ω m", [-3, 3, 1, -2, 0, 0, 0]);
+            }
+            PredefUnit::Angle => return ("angle", "rad", [0, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::SolidAngle => return ("solid angle", "sr", [0, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::ReciprocalLength => {
+                return ("reciprocal length", "1/m", [0, -1, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::Absement => return ("absement", "m s", [1, 1, 0, 0, 0, 0, 0]),
+            PredefUnit::Acceleration => return ("acceleration", "m/s^2", [-2, 1, 0, 0, 0, 0, 0]),
+            PredefUnit::Jerk => return ("jerk", "m/s^3", [-3, 1, 0, 0, 0, 0, 0]),
+            PredefUnit::AngularAcceleration => {
+                return ("angular acceleration", "rad/s^2", [-2, 0, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::VolumeRate => return ("volume rate", "m^3/s", [-1, 3, 0, 0, 0, 0, 0]),
+            PredefUnit::KinematicViscosity => {
+                return ("kinematic viscosity", "m^2/s", [-1, 2, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::DiffusionCoefficient => {
+                return ("diffusion coefficient", "m^2/s", [-1, 2, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::NumberDensity => {
+                return ("number density", "1/m^3", [0, -3, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::MassDensity => return ("mass density", "kg/m^3", [0, -3, 1, 0, 0, 0, 0]),
+            PredefUnit::LinearMassDensity => {
+                return ("linear mass density", "kg/m", [0, -1, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::ArealMassDensity => {
+                return ("areal mass density", "kg/m^2", [0, -2, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::SpecificVolume => {
+                return ("specific volume", "m^3/kg", [0, 3, -1, 0, 0, 0, 0]);
+            }
+            PredefUnit::SpecificArea => return ("specific area", "m^2/kg", [0, 2, -1, 0, 0, 0, 0]),
+            PredefUnit::MassRate => return ("mass rate", "kg/s", [-1, 0, 1, 0, 0, 0, 0]),
+            PredefUnit::MassFlux => return ("mass flux", "kg/(m^2 s)", [-1, -2, 1, 0, 0, 0, 0]),
+            PredefUnit::Momentum => return ("momentum", "kg m/s", [-1, 1, 1, 0, 0, 0, 0]),
+            PredefUnit::AngularMomentum => {
+                return ("angular momentum", "kg m^2/s", [-1, 2, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::Action => return ("action", "J s", [-1, 2, 1, 0, 0, 0, 0]),
+            PredefUnit::MomentOfInertia => {
+                return ("moment of inertia", "kg m^2", [0, 2, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::Pressure => return ("pressure", "Pa", [-2, -1, 1, 0, 0, 0, 0]),
+            PredefUnit::DynamicViscosity => {
+                return ("dynamic viscosity", "Pa s", [-1, -1, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::SpecificEnergy => {
+                return ("specific energy", "J/kg", [-2, 2, 0, 0, 0, 0, 0]);
+            }
+            PredefUnit::AbsorbedDose => return ("absorbed dose", "Gy", [-2, 2, 0, 0, 0, 0, 0]),
+            PredefUnit::EquivalentDose => return ("equivalent dose", "Sv", [-2, 2, 0, 0, 0, 0, 0]),
+            PredefUnit::SpecificPower => return ("specific power", "W/kg", [-3, 2, 0, 0, 0, 0, 0]),
+            PredefUnit::PowerRate => return ("power rate", "W/s", [-4, 2, 1, 0, 0, 0, 0]),
+            PredefUnit::LinearPowerDensity => {
+                return ("linear power density", "W/m", [-3, 1, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::HeatFluxDensity => {
+                return ("heat flux density", "W/m^2", [-3, 0, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::VolumetricPowerDensity => {
+                return ("volumetric power density", "W/m^3", [-3, -1, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::RadiantExposure => {
+                return ("radiant exposure", "J/m^2", [-2, 0, 1, 0, 0, 0, 0]);
+            }
+            PredefUnit::Radioactivity => return ("radioactivity", "Bq", [-1, 0, 0, 0, 0, 0, 0]),
+            PredefUnit::ElectricCharge => return ("electric charge", "C", [1, 0, 0, 1, 0, 0, 0]),
+            PredefUnit::Capacitance => return ("capacitance", "F", [4, -2, -1, 2, 0, 0, 0]),
+            PredefUnit::ElectricFieldStrength => {
+                return ("electric field strength", "V/m", [-3, 1, 1, -1, 0, 0, 0]);
+            }
+            PredefUnit::ElectricFlux => return ("electric flux", "V m", [-3, 3, 1, -1, 0, 0, 0]),
+            PredefUnit::ElectricCurrentDensity => {
+                return ("electric current density", "A/m^2", [0, -2, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::ElectricChargeDensity => {
+                return ("electric charge density", "C/m^3", [1, -3, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::SurfaceChargeDensity => {
+                return ("surface charge density", "C/m^2", [1, -2, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::ElectricDisplacementField => {
+                return (
+                    "electric displacement field",
+                    "C/m^2",
+                    [1, -2, 0, 1, 0, 0, 0],
+                );
+            }
+            PredefUnit::LinearChargeDensity => {
+                return ("linear charge density", "C/m", [1, -1, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::ElectricDipoleMoment => {
+                return ("electric dipole moment", "C m", [1, 1, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::Permittivity => return ("permittivity", "F/m", [4, -3, -1, 2, 0, 0, 0]),
+            PredefUnit::Permeability => return ("permeability", "H/m", [-2, 1, 1, -2, 0, 0, 0]),
+            PredefUnit::MagneticMoment => {
+                return ("magnetic moment", "A m^2", [0, 2, 0, 1, 0, 0, 0]);
+            }
+            PredefUnit::ElectricalMobility => {
+                return ("electrical mobility", "m^2/(V s)", [2, 0, -1, 1, 0, 0, 0]);
+            }
+            PredefUnit::HeatCapacity => return ("heat capacity", "J/K", [-2, 2, 1, 0, -1, 0, 0]),
+            PredefUnit::Entropy => return ("entropy", "J/K", [-2, 2, 1, 0, -1, 0, 0]),
+            PredefUnit::SpecificHeatCapacity => {
+                return (
+                    "specific heat capacity",
+                    "J/(kg K)",
+                    [-2, 2, 0, 0, -1, 0, 0],
+                );
+            }
+            PredefUnit::VolumetricHeatCapacity => {
+                return (
+                    "volumetric heat capacity",
+                    "J/(m^3 K)",
+                    [-2, -1, 1, 0, -1, 0, 0],
+                );
+            }
+            PredefUnit::ThermalConductivity => {
+                return ("thermal conductivity", "W/(m K)", [-3, 1, 1, 0, -1, 0, 0]);
+            }
+            PredefUnit::HeatTransferCoefficient => {
+                return (
+                    "heat transfer coefficient",
+                    "W/(m^2 K)",
+                    [-3, 0, 1, 0, -1, 0, 0],
+                );
+            }
+            PredefUnit::TemperatureGradient => {
+                return ("temperature gradient", "K/m", [0, -1, 0, 0, 1, 0, 0]);
+            }
+            PredefUnit::TemperatureCoefficient => {
+                return ("temperature coefficient", "1/K", [0, 0, 0, 0, -1, 0, 0]);
+            }
+            PredefUnit::MolarMass => return ("molar mass", "kg/mol", [0, 0, 1, 0, 0, -1, 0]),
+            PredefUnit::MolarVolume => return ("molar volume", "m^3/mol", [0, 3, 0, 0, 0, -1, 0]),
+            PredefUnit::MolarConcentration => {
+                return ("molar concentration", "mol/m^3", [0, -3, 0, 0, 0, 1, 0]);
+            }
+            PredefUnit::Molality => return ("molality", "mol/kg", [0, 0, -1, 0, 0, 1, 0]),
+            PredefUnit::MolarEnergy => return ("molar energy", "J/mol", [-2, 2, 1, 0, 0, -1, 0]),
+            PredefUnit::MolarHeatCapacity => {
+                return ("molar heat capacity", "J/(mol K)", [-2, 2, 1, 0, -1, -1, 0]);
+            }
+            PredefUnit::MolarFlux => return ("molar flux", "mol/(m^2 s)", [-1, -2, 0, 0, 0, 1, 0]),
+            PredefUnit::CatalyticActivity => {
+                return ("catalytic activity", "kat", [-1, 0, 0, 0, 0, 1, 0]);
+            }
+            PredefUnit::CatalyticActivityConcentration => {
+                return (
+                    "catalytic activity concentration",
+                    "kat/m^3",
+                    [-1, -3, 0, 0, 0, 1, 0],
+                );
+            }
+            PredefUnit::LuminousFlux => return ("luminous flux", "lm", [0, 0, 0, 0, 0, 0, 1]),
+            PredefUnit::Luminance => return ("luminance", "cd/m^2", [0, -2, 0, 0, 0, 0, 1]),
+            PredefUnit::Illuminance => return ("illuminance", "lx", [0, -2, 0, 0, 0, 0, 1]),
+        }
+    }
 }
 
 impl From<PredefUnit> for Unit {
     fn from(value: PredefUnit) -> Self {
-        match value {
-            PredefUnit::None => Default::default(),
-            PredefUnit::Time => Self {
-                second: 1,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Length => Self {
-                second: 0,
-                meter: 1,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Mass => Self {
-                second: 0,
-                meter: 0,
-                kilogram: 1,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricCurrent => Self {
-                second: 0,
-                meter: 0,
-                kilogram: 0,
-                ampere: 1,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Temperature => Self {
-                second: 0,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 1,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::AmountOfSubstance => Self {
-                second: 0,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 1,
-                candela: 0,
-            },
-            PredefUnit::LuminousIntensity => Self {
-                second: 0,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 1,
-            },
-            PredefUnit::Area => Self {
-                second: 0,
-                meter: 2,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Volume => Self {
-                second: 0,
-                meter: 3,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricVoltage => Self {
-                second: -3,
-                meter: 2,
-                kilogram: 1,
-                ampere: -1,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Force => Self {
-                second: -2,
-                meter: 1,
-                kilogram: 1,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Torque => Self {
-                second: -2,
-                meter: 2,
-                kilogram: 1,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Power => Self {
-                second: -3,
-                meter: 2,
-                kilogram: 1,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Energy => Self {
-                second: -2,
-                meter: 2,
-                kilogram: 1,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Frequency => Self {
-                second: -1,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Velocity => Self {
-                second: -1,
-                meter: 1,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::AngularVelocity => Self {
-                second: -1,
-                meter: 0,
-                kilogram: 0,
-                ampere: 0,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::MagneticFlux => Self {
-                second: -2,
-                meter: 2,
-                kilogram: 1,
-                ampere: -1,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::MagneticFluxDensity => Self {
-                second: -2,
-                meter: 0,
-                kilogram: 1,
-                ampere: -1,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::MagneticFieldStrength => Self {
-                second: 0,
-                meter: -1,
-                kilogram: 0,
-                ampere: 1,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::Inductance => Self {
-                second: -2,
-                meter: 2,
-                kilogram: 1,
-                ampere: -2,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricConductance => Self {
-                second: 3,
-                meter: -2,
-                kilogram: -1,
-                ampere: 2,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricResistance => Self {
-                second: -3,
-                meter: 2,
-                kilogram: 1,
-                ampere: -2,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricConductivity => Self {
-                second: 3,
-                meter: -3,
-                kilogram: -1,
-                ampere: 2,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-            PredefUnit::ElectricResistivity => Self {
-                second: -3,
-                meter: 3,
-                kilogram: 1,
-                ampere: -2,
-                kelvin: 0,
-                mol: 0,
-                candela: 0,
-            },
-        }
+        return value.definition().2.into();
     }
 }
 
@@ -647,9 +1311,20 @@ mod serde_impl {
          */
         Unit(UnitAlias),
         /**
+        Deserialization from an array of the seven exponents in the order
+        second, meter, kilogram, ampere, kelvin, mol, candela.
+         */
+        Array([i32; 7]),
+        /**
         Deserialization from a [`PredefUnit`].
          */
         PredefUnit(PredefUnit),
+        /**
+        Deserialization from a string such as `V/m`, see the
+        [`FromStr`](std::str::FromStr) implementation of [`Unit`]. This
+        requires the `from_str` feature.
+         */
+        String(String),
     }
 
     impl<'de> Deserialize<'de> for Unit {
@@ -670,9 +1345,21 @@ mod serde_impl {
                         candela: alias.candela,
                     });
                 }
+                UnitVariants::Array(exponents) => {
+                    return Ok(exponents.into());
+                }
                 UnitVariants::PredefUnit(common_units) => {
                     return Ok(common_units.into());
                 }
+                UnitVariants::String(string) => {
+                    #[cfg(feature = "from_str")]
+                    return std::str::FromStr::from_str(&string).map_err(serde::de::Error::custom);
+
+                    #[cfg(not(feature = "from_str"))]
+                    return Err(serde::de::Error::custom(format!(
+                        "cannot parse unit {string}: the from_str feature is disabled"
+                    )));
+                }
             }
         }
     }
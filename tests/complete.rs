@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+
+fn texts(input: &str, cursor: usize, expected: Option<Unit>) -> Vec<String> {
+    return complete(input, cursor, expected)
+        .into_iter()
+        .map(|completion| completion.text)
+        .collect();
+}
+
+#[test]
+fn test_complete_prefix() {
+    let texts = texts("12 k", 4, None);
+    for text in ["kV", "kW", "kN", "kg", "kHz", "kNm", "kOhm", "kΩ"] {
+        assert!(texts.contains(&text.to_string()), "{text}");
+    }
+    // Units which can't be combined with prefixes or don't start with "k"
+    for text in ["rpm", "krpm", "k°C", "K", "V"] {
+        assert!(!texts.contains(&text.to_string()), "{text}");
+    }
+}
+
+#[test]
+fn test_complete_expected_unit() {
+    assert_eq!(
+        texts("12 m", 4, Some(PredefUnit::Length.into())),
+        vec!["m", "mm"]
+    );
+    assert_eq!(
+        texts("12 M", 4, Some(PredefUnit::Mass.into())),
+        vec!["Mg", "Mt"]
+    );
+    assert!(texts("12 k", 4, Some(PredefUnit::MagneticFluxDensity.into())).contains(&"kT".into()));
+    assert!(texts("12 x", 4, Some(PredefUnit::Length.into())).is_empty());
+}
+
+#[test]
+fn test_complete_cursor_position() {
+    // Completion in the middle of the input
+    let completions = complete("1 kV + 2 mV", 10, Some(PredefUnit::ElectricVoltage.into()));
+    assert_eq!(completions.len(), 1);
+    assert_eq!(completions[0].text, "mV");
+    assert_eq!(completions[0].span, 9..10);
+
+    // Directly after the number
+    let completions = complete("12°", 4, None);
+    let texts: Vec<_> = completions.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts, vec!["°C", "°"]);
+    assert_eq!(completions[0].span, 2..4);
+
+    // Empty partial input offers all units
+    assert!(complete("12 ", 3, None).len() > 100);
+
+    // No completion after an exponent or inside a multibyte character
+    assert!(complete("2 m^", 4, None).is_empty());
+    assert!(complete("12°", 3, None).is_empty());
+    assert!(complete("12", 5, None).is_empty());
+}
+
+#[test]
+fn test_complete_parses() {
+    // Every candidate must be accepted by the parser and result in its unit
+    for completion in complete("1 ", 2, None) {
+        let input = format!("1 {}", completion.text);
+        let quantity = DynQuantity::<f64>::from_str(&input).unwrap();
+        assert_eq!(quantity.unit, completion.unit.unit, "{input}");
+    }
+}
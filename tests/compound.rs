@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+use num::Complex;
+
+#[test]
+fn test_parse_compound() {
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("1 h 30 min").unwrap();
+        assert_eq!(quantity, DynQuantity::new(5400.0, PredefUnit::Time));
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("2 d 4 h 0.5 s").unwrap();
+        approx::assert_abs_diff_eq!(quantity.value, 187200.5, epsilon = 1e-8);
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("1 km 30 m 5 mm").unwrap();
+        approx::assert_abs_diff_eq!(quantity.value, 1030.005, epsilon = 1e-8);
+        assert_eq!(quantity.unit, PredefUnit::Length.into());
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("3yd 2ft").unwrap();
+        approx::assert_abs_diff_eq!(quantity.value, 3.3528, epsilon = 1e-8);
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("5 ft 3 in").unwrap();
+        approx::assert_abs_diff_eq!(quantity.value, 1.6002, epsilon = 1e-12);
+        assert_eq!(quantity.unit, PredefUnit::Length.into());
+
+        let quantity = DynQuantity::<f64>::from_str_compound("1 yd 1ft 1in").unwrap();
+        approx::assert_abs_diff_eq!(quantity.value, 1.2446, epsilon = 1e-12);
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str_compound("-1 h 30 min").unwrap();
+        assert_eq!(quantity.value, -5400.0);
+    }
+    {
+        let quantity = DynQuantity::<Complex<f64>>::from_str_compound("1 h 30 min").unwrap();
+        assert_eq!(quantity.value, Complex::new(5400.0, 0.0));
+    }
+}
+
+#[test]
+fn test_parse_compound_single_part() {
+    // Without a number following a unit, the string is parsed as usual
+    for string in ["90 min", "3 A * 2 V", "5 m/s^2", "2 (3 + 4) s"] {
+        assert_eq!(
+            DynQuantity::<f64>::from_str_compound(string).unwrap(),
+            DynQuantity::<f64>::from_str(string).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_parse_compound_errors() {
+    {
+        let error = DynQuantity::<f64>::from_str_compound("1 h 30 m").unwrap_err();
+        assert_eq!(error.span, 4..8);
+        assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    }
+    {
+        let error = DynQuantity::<f64>::from_str_compound("5 ft 3").unwrap_err();
+        assert_eq!(error.substring, "3");
+        assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    }
+    {
+        let error = DynQuantity::<f64>::from_str_compound("30 min 1 h").unwrap_err();
+        assert_eq!(error.substring, "1 h");
+        assert_eq!(error.reason, ParseErrorReason::CompoundNotDescending);
+    }
+    {
+        // Same unit twice
+        let error = DynQuantity::<f64>::from_str_compound("1 min 2 min").unwrap_err();
+        assert_eq!(error.span, 6..11);
+        assert_eq!(error.reason, ParseErrorReason::CompoundNotDescending);
+    }
+    {
+        // The span of errors within a part refers to the entire string
+        let error = DynQuantity::<f64>::from_str_compound("1 h 30 min $").unwrap_err();
+        assert_eq!(error.span, 11..12);
+        assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+    }
+}
+
+#[test]
+fn test_display_compound() {
+    let duration = DynQuantity::new(5415.0, PredefUnit::Time);
+    let compound = duration.compound(&["h", "min", "s"]).unwrap();
+    assert_eq!(compound.to_string(), "1 h 30 min 15 s");
+    assert_eq!(
+        compound.parts(),
+        vec![("h", 1.0), ("min", 30.0), ("s", 15.0)]
+    );
+
+    let duration = DynQuantity::new(-5400.0, PredefUnit::Time);
+    let compound = duration.compound(&["h", "min", "s"]).unwrap();
+    assert_eq!(compound.to_string(), "-1 h 30 min");
+
+    let duration = DynQuantity::new(0.0, PredefUnit::Time);
+    let compound = duration.compound(&["h", "min", "s"]).unwrap();
+    assert_eq!(compound.to_string(), "0 s");
+
+    // The last part contains the fractional remainder
+    let duration = DynQuantity::new(3599.996, PredefUnit::Time);
+    let compound = duration.compound(&["h", "min", "s"]).unwrap();
+    assert_eq!(format!("{compound:.3}"), "59 min 59.996 s");
+
+    // Rounding must not result in "59 min 60.00 s"
+    assert_eq!(format!("{compound:.2}"), "1 h");
+}
+
+#[test]
+fn test_display_compound_roundtrip() {
+    let duration = DynQuantity::new(187230.0, PredefUnit::Time);
+    let compound = duration.compound(&["d", "h", "min", "s"]).unwrap();
+    assert_eq!(compound.to_string(), "2 d 4 h 30 s");
+    assert_eq!(
+        DynQuantity::<f64>::from_str_compound(&compound.to_string()).unwrap(),
+        duration
+    );
+}
+
+#[test]
+fn test_display_compound_errors() {
+    let duration = DynQuantity::new(60.0, PredefUnit::Time);
+    assert!(matches!(
+        duration.compound(&["h", "m"]).unwrap_err().reason,
+        ParseErrorReason::UnitsNotEqual(_)
+    ));
+    assert_eq!(
+        duration.compound(&["s", "min"]).unwrap_err().reason,
+        ParseErrorReason::CompoundNotDescending
+    );
+    assert_eq!(
+        duration.compound(&["hour"]).unwrap_err().reason,
+        ParseErrorReason::UnexpectedToken
+    );
+    assert_eq!(
+        duration.compound(&[]).unwrap_err().reason,
+        ParseErrorReason::InputIsEmpty
+    );
+}
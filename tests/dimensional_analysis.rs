@@ -0,0 +1,139 @@
+use dyn_quantity::dimensional_analysis::{PiGroup, pi_groups};
+use dyn_quantity::*;
+use proptest::prelude::*;
+
+fn group(exponents: &[(&str, i32)]) -> PiGroup {
+    return PiGroup {
+        exponents: exponents
+            .iter()
+            .map(|(name, exponent)| (name.to_string(), *exponent))
+            .collect(),
+    };
+}
+
+#[test]
+fn test_pi_groups() {
+    // Pendulum: the period does not depend on the mass
+    let groups = pi_groups(&[
+        ("l", PredefUnit::Length),
+        ("g", PredefUnit::Acceleration),
+        ("m", PredefUnit::Mass),
+        ("T", PredefUnit::Time),
+    ])
+    .unwrap();
+    assert_eq!(groups, vec![group(&[("l", -1), ("g", 1), ("T", 2)])]);
+    assert_eq!(groups[0].exponent("T"), 2);
+    assert_eq!(groups[0].exponent("m"), 0);
+    assert_eq!(groups[0].to_string(), "l^-1 g T^2");
+
+    // Independent variables have no π-group
+    let groups = pi_groups(&[
+        ("l", PredefUnit::Length),
+        ("m", PredefUnit::Mass),
+        ("T", PredefUnit::Time),
+    ])
+    .unwrap();
+    assert!(groups.is_empty());
+
+    // Dimensionless variables form a π-group on their own
+    let groups = pi_groups(&[
+        ("l", PredefUnit::Length),
+        ("phi", PredefUnit::Angle),
+        ("eta", PredefUnit::None),
+    ])
+    .unwrap();
+    assert_eq!(groups, vec![group(&[("phi", 1)]), group(&[("eta", 1)])]);
+
+    // Fractional exponents are scaled to integers
+    let groups = pi_groups(&[("A", PredefUnit::Area), ("V", PredefUnit::Volume)]).unwrap();
+    assert_eq!(groups, vec![group(&[("A", -3), ("V", 2)])]);
+
+    assert!(pi_groups::<&str, Unit>(&[]).unwrap().is_empty());
+
+    // Exponents which do not fit into i32
+    let error = pi_groups(&[
+        ("x", Unit::from([100000, 1, 0, 0, 0, 0, 0])),
+        ("y", Unit::from([1, 100000, 0, 0, 0, 0, 0])),
+        ("z", Unit::from([1, 0, 0, 0, 0, 0, 0])),
+    ])
+    .unwrap_err();
+    assert_eq!(
+        error,
+        ConversionError::Custom(
+            "exponent 9999999999 of variable z does not fit into i32".to_string()
+        )
+    );
+    let error = pi_groups(&[
+        ("x", Unit::from([i32::MAX, 1, 0, 0, 0, 0, 0])),
+        ("y", Unit::from([1, i32::MAX, 0, 0, 0, 0, 0])),
+        ("z", Unit::from([1, 0, 0, 0, 0, 0, 0])),
+    ])
+    .unwrap_err();
+    assert_eq!(
+        error,
+        ConversionError::Custom(
+            "exponents of the variables are out of range of the integer arithmetic".to_string()
+        )
+    );
+}
+
+#[test]
+fn test_evaluate() {
+    let variables = [
+        ("U", DynQuantity::new(230.0, PredefUnit::ElectricVoltage)),
+        ("I", DynQuantity::new(2.0, PredefUnit::ElectricCurrent)),
+        ("P", DynQuantity::new(368.0, PredefUnit::Power)),
+    ];
+    let groups = pi_groups(&variables).unwrap();
+    assert_eq!(groups, vec![group(&[("U", -1), ("I", -1), ("P", 1)])]);
+    approx::assert_abs_diff_eq!(
+        groups[0].evaluate(&variables).unwrap(),
+        0.8,
+        epsilon = 1e-12
+    );
+
+    // Missing variable
+    assert_eq!(
+        groups[0].evaluate(&variables[..2]).unwrap_err(),
+        ConversionError::Custom("value of variable P is missing".to_string())
+    );
+
+    // Wrong unit
+    let variables = [
+        ("U", DynQuantity::new(230.0, PredefUnit::ElectricVoltage)),
+        ("I", DynQuantity::new(2.0, PredefUnit::ElectricCurrent)),
+        ("P", DynQuantity::new(368.0, PredefUnit::Energy)),
+    ];
+    assert!(matches!(
+        groups[0].evaluate(&variables),
+        Err(ConversionError::UnitMismatch { .. })
+    ));
+}
+
+proptest! {
+    #[test]
+    fn pi_groups_are_dimensionless(
+        units in prop::collection::vec(prop::array::uniform7(-2i32..=2), 0..9)
+    ) {
+        let names: Vec<String> = (0..units.len()).map(|index| format!("x{index}")).collect();
+        let variables: Vec<(&str, Unit)> = names
+            .iter()
+            .zip(units.iter())
+            .map(|(name, unit)| (name.as_str(), Unit::from(*unit)))
+            .collect();
+        let groups = pi_groups(&variables).unwrap();
+
+        // Number of groups = number of variables - rank
+        let rank = variables.len() - groups.len();
+        prop_assert!(rank <= 7);
+
+        for group in groups.iter() {
+            prop_assert!(!group.exponents.is_empty());
+            let mut product = Unit::default();
+            for (name, unit) in variables.iter() {
+                product *= unit.powi(group.exponent(name));
+            }
+            prop_assert!(product.is_dimensionless());
+        }
+    }
+}
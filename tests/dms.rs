@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+
+fn degrees(s: &str) -> f64 {
+    let quantity = DynQuantity::<f64>::from_str(s).unwrap();
+    assert!(quantity.unit.is_dimensionless());
+    return quantity.value.to_degrees();
+}
+
+#[test]
+fn test_parse_dms() {
+    approx::assert_abs_diff_eq!(degrees("12°30'15\""), 12.504166666666666, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("12° 30′ 15″"), 12.504166666666666, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("45° 30'"), 45.5, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("12° 15\""), 12.004166666666666, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("30'15\""), 0.5041666666666667, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("45 deg 30'"), 45.5, epsilon = 1e-12);
+}
+
+#[test]
+fn test_parse_decimal_minutes() {
+    approx::assert_abs_diff_eq!(degrees("45° 30.5'"), 45.50833333333333, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("-45° 30.5'"), -45.50833333333333, epsilon = 1e-12);
+}
+
+#[test]
+fn test_parse_dms_sign_and_context() {
+    approx::assert_abs_diff_eq!(degrees("-12°30'"), -12.5, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("(12°30') * 2"), 25.0, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(degrees("12°30' + 30'"), 13.0, epsilon = 1e-12);
+
+    // Explicit operators and exponents are not part of the notation
+    let quantity = DynQuantity::<f64>::from_str("2° * 3'").unwrap();
+    approx::assert_abs_diff_eq!(
+        quantity.value,
+        2f64.to_radians() * (3.0 / 60.0f64).to_radians(),
+        epsilon = 1e-15
+    );
+    let quantity = DynQuantity::<f64>::from_str("2 deg^2 30'").unwrap();
+    approx::assert_abs_diff_eq!(
+        quantity.value,
+        2.0 * 1f64.to_radians().powi(2) * 30.0 * (1.0 / 60.0f64).to_radians(),
+        epsilon = 1e-15
+    );
+
+    // Units must be given in descending size, otherwise the parts are multiplied
+    let quantity = DynQuantity::<f64>::from_str("30' 12°").unwrap();
+    approx::assert_abs_diff_eq!(
+        quantity.value,
+        (0.5f64).to_radians() * 12.0 * 1f64.to_radians(),
+        epsilon = 1e-15
+    );
+}
+
+#[test]
+fn test_format_dms() {
+    let angle = DynQuantity::<f64>::from_str("12°30'15\"").unwrap();
+    let dms = angle.to_dms().unwrap();
+    assert_eq!(dms.to_string(), "12°30'15\"");
+    assert_eq!(format!("{dms:.3}"), "12°30'15.000\"");
+
+    let angle = DynQuantity::<f64>::from_str("-0.5 deg").unwrap();
+    assert_eq!(angle.to_dms().unwrap().to_string(), "-0°30'0\"");
+
+    // Rounding is carried over
+    let angle = DynQuantity::<f64>::from_str("10°59'59.9996\"").unwrap();
+    assert_eq!(angle.to_dms().unwrap().to_string(), "11°0'0\"");
+    assert_eq!(format!("{:.2}", angle.to_dms().unwrap()), "11°0'0.00\"");
+    assert_eq!(format!("{:.4}", angle.to_dms().unwrap()), "10°59'59.9996\"");
+
+    // Values which round to zero have no sign
+    let angle = DynQuantity::<f64>::from_str("-0.1\"").unwrap();
+    assert_eq!(angle.to_dms().unwrap().to_string(), "0°0'0\"");
+
+    assert!(DynQuantity::new(1.0, PredefUnit::Length).to_dms().is_err());
+}
+
+#[test]
+fn test_format_dms_roundtrip() {
+    for string in ["0°0'0\"", "12°30'15\"", "-179°59'59\"", "359°0'1\""] {
+        let angle = DynQuantity::<f64>::from_str(string).unwrap();
+        assert_eq!(angle.to_dms().unwrap().to_string(), string);
+    }
+}
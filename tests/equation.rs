@@ -0,0 +1,386 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+use num::Complex;
+use proptest::prelude::*;
+
+fn mechanics() -> Vec<(&'static str, PredefUnit)> {
+    return vec![
+        ("F", PredefUnit::Force),
+        ("m", PredefUnit::Mass),
+        ("a", PredefUnit::Acceleration),
+        ("v", PredefUnit::Velocity),
+        ("t", PredefUnit::Time),
+        ("s", PredefUnit::Length),
+        ("E", PredefUnit::Energy),
+        ("phi", PredefUnit::Angle),
+    ];
+}
+
+#[test]
+fn test_consistent_equations() {
+    let variables = mechanics();
+    assert_eq!(
+        check_equation(&variables, "F = m * a").unwrap(),
+        PredefUnit::Force.into()
+    );
+    assert!(check_equation(&variables, "E = 0.5 m v^2").is_ok());
+    assert!(check_equation(&variables, "E = 1/2 * m * v ^ 2 + m a s").is_ok());
+    assert!(check_equation(&variables, "s = v t + a t^2 / 2").is_ok());
+    assert!(check_equation(&variables, "v = sqrt(2 a s)").is_ok());
+    assert!(check_equation(&variables, "t = cbrt(t^3) - abs(-t)").is_ok());
+    assert!(check_equation(&variables, "E = F s cos(phi)").is_ok());
+    assert!(
+        check_equation(
+            &variables,
+            "v = s / t * exp(-t / (2 t)) * (sin(phi)^2 + cos(phi)^2)"
+        )
+        .is_ok()
+    );
+    assert!(check_equation(&variables, "s^2 = (v t)^2 * (t^3)^(1/3) / t").is_ok());
+
+    // Declared variables shadow units, other identifiers are units
+    assert!(check_equation(&variables, "s = 3 km + 2 m/kg").is_err());
+    assert!(check_equation(&variables, "s = 3 km + 2 mm").is_ok());
+    assert!(check_equation(&variables, "v = 3.6 km/h * 1e-3").is_ok());
+    assert!(check_equation(&variables, "F = 5 kN * 10 %").is_ok());
+    assert!(check_equation(&variables, "phi = 30 ° + 0.1").is_ok());
+    assert!(check_equation(&variables, "2 pi = 360 °").is_ok());
+}
+
+#[test]
+fn test_quantity_syntax() {
+    // Every quantity accepted by FromStr can be used within an equation
+    for quantity in [
+        "30'",
+        "12°30'15\"",
+        "0x10 m",
+        "0b1010 mA",
+        "1_000 mV",
+        "1.5E3 s",
+        "5. kV",
+        "NaN",
+        "-inf",
+        "2 i V",
+        "10 %",
+        "20 °C",
+        "3.6 km/h",
+        "2 pi rad",
+        "1 / 2 m",
+        "2*10^3 Hz",
+        "4 e-3 Ω",
+        "2^10",
+        "2^-1 m",
+        "2 m^-1",
+        "2 m^2^3",
+        "s^4^0.5",
+        "m^2.0",
+        "(2 km)^(3)",
+        "(2 m^2)^0.5",
+        "m^-1/2",
+        "(2 + 3) mm / (4 - 1) s",
+    ] {
+        let expected = DynQuantity::<Complex<f64>>::from_str(quantity)
+            .unwrap()
+            .unit;
+        assert_eq!(
+            expression_unit::<&str, Unit>(&[], quantity).unwrap(),
+            expected,
+            "{quantity}"
+        );
+        let equation = format!("x = {quantity}");
+        assert_eq!(
+            check_equation(&[("x", expected)], &equation).unwrap(),
+            expected,
+            "{equation}"
+        );
+    }
+
+    let variables = [("a", PredefUnit::Angle), ("L", PredefUnit::Length)];
+    assert!(check_equation(&variables, "a = 30'").is_ok());
+    assert!(check_equation(&variables, "L = 0x10 m").is_ok());
+    assert!(check_equation(&variables, "L = 2 a m^4^0.5 / m^1.0").is_ok());
+
+    // Quantities rejected by FromStr are rejected as well
+    for quantity in ["m^0.5", "2 k°C", "°C^2", "2 m $"] {
+        let error = DynQuantity::<f64>::from_str(quantity).unwrap_err();
+        assert_eq!(
+            expression_unit::<&str, Unit>(&[], quantity)
+                .unwrap_err()
+                .reason,
+            error.reason,
+            "{quantity}"
+        );
+    }
+}
+
+#[test]
+fn test_inconsistent_equations() {
+    let variables = mechanics();
+
+    let equation = "F = m * v";
+    let error = check_equation(&variables, equation).unwrap_err();
+    assert_eq!(error.span, 4..9);
+    assert_eq!(error.substring, "m * v");
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::UnitsNotEqual(UnitsNotEqual(
+            PredefUnit::Force.into(),
+            PredefUnit::Momentum.into()
+        ))
+    );
+    assert_eq!(
+        error.to_string(),
+        "could not parse m * v: unit s^-2 m^1 kg^1 A^0 K^0 mol^0 cd^0 not equal to unit s^-1 m^1 kg^1 A^0 K^0 mol^0 cd^0"
+    );
+
+    // The first summand determines the unit of a sum
+    let equation = "s = v t + a t + s";
+    let error = check_equation(&variables, equation).unwrap_err();
+    assert_eq!(error.substring, "a t");
+    assert_eq!(error.span, 10..13);
+
+    let error = check_equation(&variables, "E = F (s - t)").unwrap_err();
+    assert_eq!(error.substring, "t");
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::UnitsNotEqual(UnitsNotEqual(
+            PredefUnit::Length.into(),
+            PredefUnit::Time.into()
+        ))
+    );
+
+    // Functions with dimensionless arguments
+    let error = check_equation(&variables, "phi = exp(t) * ln(v / v)").unwrap_err();
+    assert_eq!(error.substring, "t");
+    assert_eq!(error.span, 10..11);
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::UnitsNotEqual(UnitsNotEqual(Unit::default(), PredefUnit::Time.into()))
+    );
+
+    // Roots
+    let error = check_equation(&variables, "t = sqrt( t )").unwrap_err();
+    assert_eq!(error.substring, "t");
+    assert!(matches!(error.reason, ParseErrorReason::RootError(_)));
+    let error = check_equation(&variables, "t = s^(1/2)").unwrap_err();
+    assert_eq!(error.substring, "s^(1/2)");
+    assert!(matches!(error.reason, ParseErrorReason::RootError(_)));
+
+    // Exponents
+    let error = check_equation(&variables, "t = t^t").unwrap_err();
+    assert_eq!(error.span, 6..7);
+    assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    let error = check_equation(&variables, "t = t^phi").unwrap_err();
+    assert_eq!(error.substring, "phi");
+    assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+    assert!(check_equation(&variables, "phi = 2^phi").is_ok());
+
+    // The exponents of the unit overflow
+    let error = check_equation(&variables, "t = (t^50000)^50000").unwrap_err();
+    assert_eq!(error.substring, "(t^50000)^50000");
+    assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+}
+
+#[test]
+fn test_syntax_errors() {
+    let variables = mechanics();
+
+    let error = check_equation(&variables, "").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::InputIsEmpty);
+
+    let error = check_equation(&variables, "F = m a = E / s").unwrap_err();
+    assert_eq!(error.span, 8..9);
+    assert_eq!(error.reason, ParseErrorReason::NotAnEquation);
+
+    let error = check_equation(&variables, "F").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::NotAnEquation);
+
+    assert!(check_equation(&variables, "F=m*a").is_ok());
+
+    // Identifiers must be separated from a preceding number, "2m" is a length
+    let error = check_equation(&variables, "F = m 2a").unwrap_err();
+    assert_eq!(error.substring, "2a");
+    assert_eq!(error.reason, ParseErrorReason::UnknownIdentifier);
+    assert!(check_equation(&variables, "F = 2m a").is_err());
+    assert!(check_equation(&variables, "F = 2 m a").is_ok());
+
+    let error = check_equation(&variables, "F = m * x").unwrap_err();
+    assert_eq!(error.substring, "x");
+    assert_eq!(error.reason, ParseErrorReason::UnknownIdentifier);
+
+    let error = check_equation(&variables, "F = 1__0 m a").unwrap_err();
+    assert_eq!(error.substring, "1__0");
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::InvalidNumber(LexingError::InvalidFloat("1__0".to_owned()))
+    );
+
+    let error = check_equation(&variables, "F = m * (a").unwrap_err();
+    assert_eq!(error.span, 8..9);
+    assert_eq!(error.reason, ParseErrorReason::UnbalancedBrackets);
+
+    let error = check_equation(&variables, "F = m * a)").unwrap_err();
+    assert_eq!(error.span, 9..10);
+    assert_eq!(error.reason, ParseErrorReason::UnbalancedBrackets);
+
+    let error = check_equation(&variables, "F = m *").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::TwoOperatorsWithoutNumber);
+
+    let error = check_equation(&variables, "F = m $ a").unwrap_err();
+    assert_eq!(error.span, 6..7);
+    assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+
+    let error = check_equation(&variables, "F = * m").unwrap_err();
+    assert_eq!(error.substring, "*");
+    assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+}
+
+#[test]
+fn test_expression_unit() {
+    let variables = [
+        ("U", DynQuantity::new(230.0, PredefUnit::ElectricVoltage)),
+        ("I", DynQuantity::new(2.0, PredefUnit::ElectricCurrent)),
+        ("phi", DynQuantity::new(0.5, PredefUnit::Angle)),
+    ];
+    assert_eq!(
+        expression_unit(&variables, "U I cos(phi)").unwrap(),
+        PredefUnit::Power.into()
+    );
+    assert_eq!(
+        expression_unit(&variables, "U / I").unwrap(),
+        PredefUnit::ElectricResistance.into()
+    );
+    assert_eq!(
+        expression_unit(&variables, "2 pi * 50 Hz").unwrap(),
+        PredefUnit::Frequency.into()
+    );
+    assert!(expression_unit(&variables, "U = U").is_err());
+    assert!(expression_unit(&variables, "U + I").is_err());
+}
+
+#[test]
+fn test_solve_unit() {
+    let variables = mechanics();
+    assert_eq!(
+        solve_unit::<&str, Unit>(&[], "x * 3 s = 5 m", "x").unwrap(),
+        PredefUnit::Velocity.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "F = k v^2", "k").unwrap(),
+        Unit::from([0, -1, 1, 0, 0, 0, 0])
+    );
+    assert_eq!(
+        solve_unit(&variables, "E = 0.5 m v^2 + x", "x").unwrap(),
+        PredefUnit::Energy.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "s = v t + 1/2 g t^2", "g").unwrap(),
+        PredefUnit::Acceleration.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "s = s exp(-t / tau)", "tau").unwrap(),
+        PredefUnit::Time.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "v = cbrt(V) / t^(2 - 1)", "V").unwrap(),
+        PredefUnit::Volume.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "phi = omega t", "omega").unwrap(),
+        PredefUnit::Frequency.into()
+    );
+
+    // Quantities can use the entire syntax of FromStr
+    assert_eq!(
+        solve_unit::<&str, Unit>(&[], "x * 0x10 s = 30' m", "x").unwrap(),
+        PredefUnit::Velocity.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "phi = 12°30'15\" * omega t", "omega").unwrap(),
+        PredefUnit::Frequency.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "s = k * 1_000 mm^4^0.5", "k").unwrap(),
+        Unit::from([0, -1, 0, 0, 0, 0, 0])
+    );
+
+    // The unknown shadows the declaration of the variable
+    assert_eq!(
+        solve_unit(&variables, "F = m a", "a").unwrap(),
+        PredefUnit::Acceleration.into()
+    );
+    assert_eq!(
+        solve_unit(&variables, "F = m a", "m").unwrap(),
+        PredefUnit::Mass.into()
+    );
+}
+
+#[test]
+fn test_solve_unit_errors() {
+    let variables = mechanics();
+
+    let error = solve_unit(&variables, "F = m a", "x").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::UndeterminedUnit);
+    assert_eq!(error.span, 0..7);
+
+    let error = solve_unit(&variables, "F = m a * x / x", "x").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::UndeterminedUnit);
+
+    // Fractional exponents
+    let error = solve_unit(&variables, "F = x^2", "x").unwrap_err();
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::RootError(RootError {
+            n: 2,
+            unit: PredefUnit::Force.into()
+        })
+    );
+
+    // The solution of the first sum does not fit the second sum
+    let equation = "F = m (x + a) + m (x + v)";
+    let error = solve_unit(&variables, equation, "x").unwrap_err();
+    assert_eq!(error.substring, "v");
+    assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+
+    // The sum determines the solution, hence the sides do not match
+    let error = solve_unit(&variables, "F = x + m v", "x").unwrap_err();
+    assert_eq!(error.substring, "x + m v");
+
+    // Inconsistent parts without the unknown
+    let error = solve_unit(&variables, "F = x + m (a + v)", "x").unwrap_err();
+    assert_eq!(error.substring, "v");
+
+    let error = solve_unit(&variables, "F = x +", "x").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::TwoOperatorsWithoutNumber);
+
+    // Exponents which overflow
+    let error = solve_unit(&variables, "x = (m^50000)^50000", "x").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+    let equation = "x = ((((m^50000)^50000)^50000)^50000)^50000";
+    let error = solve_unit(&variables, equation, "x").unwrap_err();
+    assert_eq!(error.substring, "((((m^50000)^50000)^50000)^50000)^50000");
+    assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+}
+
+proptest! {
+    #[test]
+    fn solve_unit_of_products(
+        x in prop::array::uniform7(-3i32..=3),
+        a in prop::array::uniform7(-3i32..=3),
+        power in 1i32..=3,
+    ) {
+        // Even exponents, so that the square roots exist
+        let x = Unit::from(x).powi(2);
+        let a = Unit::from(a);
+        let y = x.powi(2 * power) / a;
+        let variables = [("a", a), ("y", y)];
+
+        let equation = format!("y * a = 2 x^{power} * sqrt(x^{power}) * sqrt(x)^{power}");
+        prop_assert_eq!(solve_unit(&variables, &equation, "x").unwrap(), x);
+        prop_assert_eq!(check_equation(&[("x", x), ("a", a), ("y", y)], &equation).unwrap(), y * a);
+
+        let equation = format!("(y + x^{power} x^{power} / a) / y = a / a + 1");
+        prop_assert_eq!(solve_unit(&variables, &equation, "x").unwrap(), x);
+    }
+}
@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use approx::assert_abs_diff_eq;
+use dyn_quantity::*;
+use num::Complex;
+
+fn unit(s: &str) -> Unit {
+    return DynQuantity::<f64>::from_str(s).unwrap().unit;
+}
+
+#[test]
+fn test_integer_exponents() {
+    let quantity = DynQuantity::<f64>::from_str("2^10").unwrap();
+    assert_eq!(quantity.value, 1024.0);
+    assert!(quantity.unit.is_dimensionless());
+
+    let quantity = DynQuantity::<f64>::from_str("2^-2 m").unwrap();
+    assert_eq!(quantity.value, 0.25);
+    assert_eq!(quantity.unit, unit("m"));
+
+    // Exponentiation is right-associative
+    let quantity = DynQuantity::<f64>::from_str("2^3^2").unwrap();
+    assert_eq!(quantity.value, 512.0);
+
+    let quantity = DynQuantity::<f64>::from_str("2^2^3").unwrap();
+    assert_eq!(quantity.value, 256.0);
+
+    let quantity = DynQuantity::<f64>::from_str("m^2^3").unwrap();
+    assert_eq!(quantity.unit, unit("m^8"));
+
+    let quantity = DynQuantity::<f64>::from_str("(2 m)^2^2").unwrap();
+    assert_eq!(quantity.value, 16.0);
+    assert_eq!(quantity.unit, unit("m^4"));
+
+    let quantity = DynQuantity::<f64>::from_str("(2^3)^2").unwrap();
+    assert_eq!(quantity.value, 64.0);
+
+    let quantity = DynQuantity::<f64>::from_str("(2 km)^(3)").unwrap();
+    assert_abs_diff_eq!(quantity.value, 8e9, epsilon = 1e-3);
+    assert_eq!(quantity.unit, unit("m^3"));
+
+    let quantity = DynQuantity::<f64>::from_str("(2 km)^-1").unwrap();
+    assert_eq!(quantity.value, 5e-4);
+    assert_eq!(quantity.unit, unit("1/m"));
+
+    // The exponent only applies to the factor directly in front of it
+    let quantity = DynQuantity::<f64>::from_str("3 m^-1/2").unwrap();
+    assert_eq!(quantity.value, 1.5);
+    assert_eq!(quantity.unit, unit("1/m"));
+
+    let quantity = DynQuantity::<f64>::from_str("1 / 2^2 s").unwrap();
+    assert_eq!(quantity.value, 0.25);
+    assert_eq!(quantity.unit, unit("s"));
+}
+
+#[test]
+fn test_negative_exponents() {
+    // Real numbers are raised using real arithmetic
+    let quantity = DynQuantity::<Complex<f64>>::from_str("10^-3").unwrap();
+    assert_eq!(quantity.value, Complex::new(0.001, 0.0));
+    assert!(quantity.value.im.is_sign_positive());
+
+    let quantity = DynQuantity::<f64>::from_str("(10)^(-3)").unwrap();
+    assert_eq!(quantity.value, 0.001);
+
+    let quantity = DynQuantity::<f64>::from_str("(2 s)^-3").unwrap();
+    assert_eq!(quantity.value, 0.125);
+    assert_eq!(quantity.unit, unit("s^-3"));
+
+    let quantity = DynQuantity::<f64>::from_str("(-2)^-3").unwrap();
+    assert_eq!(quantity.value, -0.125);
+
+    // The sign applies to the result of the exponentiation of the exponent
+    let quantity = DynQuantity::<f64>::from_str("2^-2^2").unwrap();
+    assert_eq!(quantity.value, 0.0625);
+}
+
+#[test]
+fn test_exponent_overflow() {
+    let quantity = DynQuantity::<f64>::from_str("2^99999").unwrap();
+    assert_eq!(quantity.value, f64::INFINITY);
+
+    let quantity = DynQuantity::<f64>::from_str("(-2)^99999").unwrap();
+    assert_eq!(quantity.value, f64::NEG_INFINITY);
+
+    let quantity = DynQuantity::<f64>::from_str("2^-99999").unwrap();
+    assert_eq!(quantity.value, 0.0);
+}
+
+#[test]
+fn test_bracket_exponents() {
+    let quantity = DynQuantity::<f64>::from_str("2^(1+1)").unwrap();
+    assert_eq!(quantity.value, 4.0);
+
+    let quantity = DynQuantity::<f64>::from_str("(8 m^3)^(2 / 6)").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0, epsilon = 1e-12);
+    assert_eq!(quantity.unit, unit("m"));
+
+    let quantity = DynQuantity::<f64>::from_str("2^(3)^2").unwrap();
+    assert_eq!(quantity.value, 512.0);
+
+    let quantity = DynQuantity::<f64>::from_str("4^((1 m)/(2 m))").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_rational_exponents() {
+    let quantity = DynQuantity::<f64>::from_str("4^0.5").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0, epsilon = 1e-12);
+
+    let quantity = DynQuantity::<f64>::from_str("(4 m^2)^(1/2)").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0, epsilon = 1e-12);
+    assert_eq!(quantity.unit, unit("m"));
+
+    let quantity = DynQuantity::<f64>::from_str("(4 m^2)^0.5").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0, epsilon = 1e-12);
+    assert_eq!(quantity.unit, unit("m"));
+
+    let quantity = DynQuantity::<f64>::from_str("(8 s^3)^(-2/3)").unwrap();
+    assert_abs_diff_eq!(quantity.value, 0.25, epsilon = 1e-12);
+    assert_eq!(quantity.unit, unit("s^-2"));
+
+    let quantity = DynQuantity::<f64>::from_str("pi^0.5").unwrap();
+    assert_abs_diff_eq!(quantity.value, std::f64::consts::PI.sqrt(), epsilon = 1e-12);
+
+    let quantity = DynQuantity::<f64>::from_str("2 * 10^2.5").unwrap();
+    assert_abs_diff_eq!(quantity.value, 2.0 * 10f64.powf(2.5), epsilon = 1e-9);
+}
+
+#[test]
+fn test_roots_of_negative_numbers() {
+    let quantity = DynQuantity::<f64>::from_str("(-8)^(1/3)").unwrap();
+    assert_abs_diff_eq!(quantity.value, -2.0, epsilon = 1e-12);
+
+    let quantity = DynQuantity::<f64>::from_str("(-8 m^3)^(2/3)").unwrap();
+    assert_abs_diff_eq!(quantity.value, 4.0, epsilon = 1e-12);
+    assert_eq!(quantity.unit, unit("m^2"));
+
+    // Even roots of negative numbers are complex
+    let quantity = DynQuantity::<Complex<f64>>::from_str("(-4)^0.5").unwrap();
+    assert_abs_diff_eq!(quantity.value.re, 0.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(quantity.value.im, 2.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_invalid_exponents() {
+    {
+        let error = DynQuantity::<f64>::from_str("2^V").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "V");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2^").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "^");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2^(1 m)").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "(1 m)");
+    }
+    {
+        let error = DynQuantity::<Complex<f64>>::from_str("2^(1 + 2i)").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "(1 + 2i)");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2^(1/0)").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "(1/0)");
+    }
+    {
+        // The exponents of the unit overflow
+        let error = DynQuantity::<f64>::from_str("(m^50000)^50000").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InvalidExponent);
+        assert_eq!(error.substring, "(m^50000)^50000");
+        assert_eq!(
+            error.to_string(),
+            "could not parse (m^50000)^50000: exponent must be a number or a dimensionless, real expression in brackets"
+        );
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2^(1/2").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::UnbalancedBrackets);
+        assert_eq!(error.substring, "(");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("^2").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::MustNotStartWith);
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2 *^ 3").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::TwoOperatorsWithoutNumber);
+        assert_eq!(error.substring, "^");
+    }
+}
+
+#[test]
+fn test_invalid_roots() {
+    {
+        let error = DynQuantity::<f64>::from_str("m^(1/2)").unwrap_err();
+        assert!(matches!(error.reason, ParseErrorReason::RootError(_)));
+        assert_eq!(error.substring, "m^(1/2)");
+        assert_eq!(error.span, 0..7);
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2 m^0.5").unwrap_err();
+        assert!(matches!(error.reason, ParseErrorReason::RootError(_)));
+        assert_eq!(error.substring, "m^0.5");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("(8 m^2)^(1/3)").unwrap_err();
+        match error.reason {
+            ParseErrorReason::RootError(error) => assert_eq!(error.n, 3),
+            _ => panic!("wrong error type"),
+        }
+    }
+}
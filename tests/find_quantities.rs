@@ -0,0 +1,104 @@
+use dyn_quantity::*;
+
+fn find(text: &str) -> Vec<(&str, DynQuantity<f64>)> {
+    return find_quantities::<f64>(text)
+        .into_iter()
+        .map(|(quantity, span)| (&text[span], quantity))
+        .collect();
+}
+
+fn substrings(text: &str) -> Vec<&str> {
+    return find(text).into_iter().map(|(s, _)| s).collect();
+}
+
+#[test]
+fn test_find_simple() {
+    let text = "the motor delivers 5 kW at 3000 rpm and 400 V";
+    let found = find(text);
+    assert_eq!(found.len(), 3);
+    assert_eq!(found[0].0, "5 kW");
+    assert_eq!(found[0].1, DynQuantity::new(5000.0, PredefUnit::Power));
+    assert_eq!(found[1].0, "3000 rpm");
+    assert_eq!(
+        found[1].1,
+        DynQuantity::new(50.0, PredefUnit::AngularVelocity)
+    );
+    assert_eq!(found[2].0, "400 V");
+    assert_eq!(
+        found[2].1,
+        DynQuantity::new(400.0, PredefUnit::ElectricVoltage)
+    );
+}
+
+#[test]
+fn test_find_compound_units() {
+    assert_eq!(
+        substrings("an acceleration of 9.81 m/s^2 and a torque of 12 N * m."),
+        vec!["9.81 m/s^2", "12 N * m"]
+    );
+    let found = find("g = 9.81 m / s^2");
+    assert_eq!(
+        found[0].1.unit,
+        Unit::from(PredefUnit::Velocity) / Unit::from(PredefUnit::Time)
+    );
+
+    // The operator is not part of the quantity if no unit follows
+    assert_eq!(substrings("5 kW / the rest"), vec!["5 kW"]);
+}
+
+#[test]
+fn test_find_number_formats() {
+    assert_eq!(
+        substrings("between -20 °C and +85°C, 1.5e-3 A or .5 mm"),
+        vec!["-20 °C", "+85°C", "1.5e-3 A", ".5 mm"]
+    );
+    let found = find("efficiency: 95 %");
+    assert_eq!(found[0].0, "95 %");
+    approx::assert_relative_eq!(found[0].1.value, 0.95);
+
+    // Signs which are part of a word are not included
+    assert_eq!(substrings("range 10-20 V"), vec!["20 V"]);
+    assert_eq!(substrings("x-5 V"), vec!["5 V"]);
+
+    // All number literals of the lexer are recognized
+    let found = find("a 1E3 W heater, 1_000 mm long, mask 0x1F V, up to 2.5*10^3 rpm");
+    let values: Vec<(&str, f64)> = found.iter().map(|(s, q)| (*s, q.value)).collect();
+    assert_eq!(
+        values,
+        vec![
+            ("1E3 W", 1000.0),
+            ("1_000 mm", 1.0),
+            ("0x1F V", 31.0),
+            ("2.5*10^3 rpm", 2500.0 / 60.0),
+        ]
+    );
+}
+
+#[test]
+fn test_find_skips_non_quantities() {
+    // Plain numbers, words and unknown units are skipped
+    assert!(find("In 2024, 3 engineers wrote 12 reports").is_empty());
+    assert!(find("version 1.2.3 m").is_empty());
+    assert!(find("1,000 kW").is_empty());
+    assert!(find("x5 kW and 5kWatt").is_empty());
+    assert!(find("5 Watts, 3 Amps or 2 VA").is_empty());
+    assert!(find("5 apples").is_empty());
+
+    // Common words which look like units
+    assert!(find("meet at 5 pm, 5 as usual, 5 at most, 5 am").is_empty());
+    assert_eq!(substrings("5 V in series, 3 in parallel"), vec!["5 V"]);
+
+    // Units directly attached to the number and punctuation
+    assert_eq!(
+        substrings("(5kW), 3 mm; 2 s. [4 A]"),
+        vec!["5kW", "3 mm", "2 s", "4 A"]
+    );
+}
+
+#[test]
+fn test_find_complex() {
+    let found = find_quantities::<num::Complex<f64>>("a current of 2 A");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0.value, num::Complex::new(2.0, 0.0));
+    assert_eq!(found[0].1, 13..16);
+}
@@ -23,6 +23,17 @@ fn test_unexpected_token() {
         assert_eq!(error.span.end, 3);
         assert_eq!(error.substring, "$");
     }
+    // The offset of °C is not defined for prefixes and exponents
+    {
+        let error = DynQuantity::<f64>::from_str("2 °C^2").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+        assert_eq!(error.substring, "°C^2");
+    }
+    {
+        let error = DynQuantity::<f64>::from_str("2 k°C").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+        assert_eq!(error.substring, "k°C");
+    }
 }
 
 #[test]
@@ -129,3 +140,103 @@ fn test_two_operators_without_number() {
         }
     }
 }
+
+#[test]
+fn test_collect_errors() {
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors("2 l $ 3").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].substring, "l");
+        assert_eq!(errors[0].reason, ParseErrorReason::UnexpectedToken);
+        assert_eq!(errors[1].substring, "$");
+        assert_eq!(errors[1].reason, ParseErrorReason::UnexpectedToken);
+    }
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors("1 ** 2 + / 3").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].span, 3..4);
+        assert_eq!(errors[0].reason, ParseErrorReason::TwoOperatorsWithoutNumber);
+        assert_eq!(errors[1].span, 9..10);
+        assert_eq!(errors[1].reason, ParseErrorReason::TwoOperatorsWithoutNumber);
+    }
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors("(1 A + 2 V) + 3 s)").unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].substring, "+ 2 V");
+        match &errors[0].reason {
+            ParseErrorReason::UnitsNotEqual(UnitsNotEqual(first, second)) => {
+                assert_eq!(first.ampere, 1);
+                assert_eq!(second.ampere, -1);
+            }
+            _ => panic!("wrong error type"),
+        }
+        assert_eq!(errors[1].span, 17..18);
+        assert_eq!(errors[1].reason, ParseErrorReason::UnbalancedBrackets);
+        assert_eq!(errors[2].substring, "+ 3 s");
+    }
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors("((2) * (3").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].span, 7..8);
+        assert_eq!(errors[1].span, 0..1);
+    }
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors("2i m").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0].reason {
+            ParseErrorReason::NotConvertibleFromComplexF64(_) => (),
+            _ => panic!("wrong error type"),
+        }
+    }
+    {
+        let errors = DynQuantity::<f64>::from_str_collect_errors(" ").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].reason, ParseErrorReason::InputIsEmpty);
+    }
+    {
+        // A sign after "*" starts a new summand, just like with from_str
+        let quantity = DynQuantity::<f64>::from_str_collect_errors("2 * -3").unwrap();
+        assert_eq!(quantity.value, -1.0);
+        let errors = DynQuantity::<f64>::from_str_collect_errors("3 A*-2").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0].reason, ParseErrorReason::UnitsNotEqual(_)));
+        assert!(DynQuantity::<f64>::from_str("3 A*-2").is_err());
+    }
+}
+
+#[test]
+fn test_malformed_numbers() {
+    for (string, substring, lexing_error) in [
+        ("1__0", "1__0", LexingError::InvalidFloat("1__0".to_owned())),
+        (
+            "1__000 m",
+            "1__000",
+            LexingError::InvalidFloat("1__000".to_owned()),
+        ),
+        ("2 * 1_ m", "1_", LexingError::InvalidFloat("1_".to_owned())),
+        (
+            "0b102",
+            "0b102",
+            LexingError::InvalidInt("0b102".to_owned()),
+        ),
+        (
+            "1e_3 V",
+            "1e_3",
+            LexingError::InvalidFloat("1e_3".to_owned()),
+        ),
+    ] {
+        let error = DynQuantity::<f64>::from_str(string).unwrap_err();
+        assert_eq!(
+            error.reason,
+            ParseErrorReason::InvalidNumber(lexing_error),
+            "{string}"
+        );
+        assert_eq!(error.substring, substring, "{string}");
+    }
+
+    let error = DynQuantity::<f64>::from_str("1_ m").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "could not parse 1_: could not interpret 1_ as a floating-point number"
+    );
+}
@@ -1156,6 +1156,34 @@ fn test_parse_power_of_ten() {
     }
 }
 
+#[test]
+fn test_parse_numeric_literals() {
+    for (string, value) in [
+        ("1.5E3 m", 1.5e3),
+        ("0.1e-3 m", 0.1e-3),
+        ("2e+3 m", 2e3),
+        ("1_000_000 m", 1e6),
+        ("5. m", 5.0),
+        ("0x1F m", 31.0),
+        ("0b1010 m", 10.0),
+        ("0xFF_FF m", 65535.0),
+    ] {
+        let quantity = DynQuantity::<f64>::from_str(string).unwrap();
+        assert_eq!(quantity.value, value, "{string}");
+        assert_eq!(quantity.unit, PredefUnit::Length.into(), "{string}");
+    }
+    {
+        let quantity = DynQuantity::<f64>::from_str("2 NaN V").unwrap();
+        assert!(quantity.value.is_nan());
+        assert_eq!(quantity.unit, PredefUnit::ElectricVoltage.into());
+    }
+    {
+        let quantity = DynQuantity::<Complex<f64>>::from_str("nan").unwrap();
+        assert!(quantity.value.re.is_nan());
+        assert_eq!(quantity.value.im, 0.0);
+    }
+}
+
 #[test]
 fn test_parse_angle() {
     let quantity = DynQuantity::<f64>::from_str("180 degree/s").unwrap();
@@ -1301,6 +1329,14 @@ fn test_parse_no_value() {
 
 #[test]
 fn test_parse_infinite() {
+    {
+        let quantity = DynQuantity::<f64>::from_str("2 + inf").unwrap();
+        assert_eq!(quantity.value, INFINITY);
+    }
+    {
+        let quantity = DynQuantity::<Complex<f64>>::from_str("(2 - inf i) A").unwrap();
+        assert_eq!(quantity.value, Complex::new(2.0, NEG_INFINITY));
+    }
     {
         let quantity = DynQuantity::<f64>::from_str("inf A").unwrap();
         assert_eq!(quantity.value, INFINITY);
@@ -1415,3 +1451,103 @@ fn test_parse_infinite() {
         );
     }
 }
+
+#[test]
+fn test_parse_non_metric_units() {
+    // Breaking change: "ft" was femto-tonne (5e-12 kg) and is now foot
+    let length = DynQuantity::<f64>::from_str("5 ft").unwrap();
+    approx::assert_abs_diff_eq!(length.value, 1.524, epsilon = 1e-12);
+    assert_eq!(length.unit, PredefUnit::Length.into());
+
+    // "h", "d" and "min" are hour, day and minute
+    assert_eq!(DynQuantity::<f64>::from_str("2 h").unwrap().value, 7200.0);
+    assert_eq!(DynQuantity::<f64>::from_str("2 d").unwrap().value, 172800.0);
+    assert_eq!(DynQuantity::<f64>::from_str("2 min").unwrap().value, 120.0);
+    approx::assert_abs_diff_eq!(
+        DynQuantity::<f64>::from_str("1 yd").unwrap().value,
+        0.9144,
+        epsilon = 1e-12
+    );
+    approx::assert_abs_diff_eq!(
+        DynQuantity::<f64>::from_str("3 in").unwrap().value,
+        0.0762,
+        epsilon = 1e-12
+    );
+    approx::assert_abs_diff_eq!(
+        DynQuantity::<f64>::from_str("2 in^2").unwrap().value,
+        0.00129032,
+        epsilon = 1e-12
+    );
+
+    // Imaginary numbers followed by a unit are not affected by the inch
+    assert_eq!(
+        DynQuantity::<Complex<f64>>::from_str("3 iV").unwrap(),
+        DynQuantity::<Complex<f64>>::from_str("3 i V").unwrap()
+    );
+    assert!(
+        DynQuantity::<f64>::from_str("2 inf")
+            .unwrap()
+            .value
+            .is_infinite()
+    );
+
+    // Metric prefixes are still recognized in combination with other units
+    approx::assert_abs_diff_eq!(
+        DynQuantity::<f64>::from_str("2 dm").unwrap().value,
+        0.2,
+        epsilon = 1e-12
+    );
+    assert_eq!(DynQuantity::<f64>::from_str("2 kt").unwrap().value, 2e6);
+}
+
+#[test]
+fn test_value_in() {
+    let velocity = DynQuantity::<f64>::from_str("0.5 m/s").unwrap();
+    approx::assert_abs_diff_eq!(
+        velocity.value_in("mm/min").unwrap(),
+        30000.0,
+        epsilon = 1e-8
+    );
+    approx::assert_abs_diff_eq!(velocity.value_in("km/h").unwrap(), 1.8, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(
+        velocity.value_in("100 km/h").unwrap(),
+        0.018,
+        epsilon = 1e-12
+    );
+
+    let torque = DynQuantity::<Complex<f64>>::from_str("(2 + 1j) kN m").unwrap();
+    assert_eq!(
+        torque.value_in("N * m").unwrap(),
+        Complex::new(2000.0, 1000.0)
+    );
+
+    // Targets with an offset
+    let temperature = DynQuantity::<f64>::from_str("300 K").unwrap();
+    approx::assert_abs_diff_eq!(temperature.value_in("°C").unwrap(), 26.85, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(temperature.value_in("K").unwrap(), 300.0, epsilon = 1e-12);
+    let temperature = DynQuantity::<f64>::from_str("20 °C").unwrap();
+    approx::assert_abs_diff_eq!(temperature.value_in("°C").unwrap(), 20.0, epsilon = 1e-12);
+
+    // Dimensionless targets
+    let ratio = DynQuantity::<f64>::from_str("0.25").unwrap();
+    approx::assert_abs_diff_eq!(ratio.value_in("%").unwrap(), 25.0, epsilon = 1e-12);
+
+    // Unit mismatch
+    match velocity.value_in("mm").unwrap_err() {
+        ConversionError::UnitMismatch { expected, found } => {
+            assert_eq!(expected, PredefUnit::Length.into());
+            assert_eq!(found, PredefUnit::Velocity.into());
+        }
+        _ => panic!("wrong error type"),
+    }
+
+    // Invalid and complex targets
+    assert!(matches!(
+        velocity.value_in("m/(s"),
+        Err(ConversionError::Custom(_))
+    ));
+    assert!(matches!(
+        velocity.value_in("2j m/s"),
+        Err(ConversionError::Custom(_))
+    ));
+}
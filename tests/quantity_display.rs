@@ -30,3 +30,336 @@ fn test_display() {
         assert_eq!(&quantity.to_string(), "(1+2i) A");
     }
 }
+
+#[test]
+fn test_display_flags() {
+    let quantity = DynQuantity::<f64>::from_str("-1234.5678 m/s").unwrap();
+    assert_eq!(format!("{quantity:.2}"), "-1234.57 s^-1 m");
+    assert_eq!(format!("{quantity:e}"), "-1.2345678e3 s^-1 m");
+    assert_eq!(format!("{quantity:.1E}"), "-1.2E3 s^-1 m");
+    assert_eq!(format!("{quantity:>20.0}"), "        -1235 s^-1 m");
+    assert_eq!(format!("{quantity:*<20.0}"), "-1235 s^-1 m********");
+    assert_eq!(format!("{quantity:^14.0}"), " -1235 s^-1 m ");
+
+    let quantity = DynQuantity::<f64>::from_str("2 A").unwrap();
+    assert_eq!(format!("{quantity:+}"), "+2 A");
+    assert_eq!(format!("{quantity:3}"), "2 A");
+
+    let quantity = DynQuantity::<Complex<f64>>::from_str("(1.234 - 2.5i) V").unwrap();
+    assert_eq!(format!("{quantity:.1}"), "(1.2-2.5i) s^-3 m^2 kg A^-1");
+}
+
+#[test]
+fn test_quantity_formatter() {
+    let quantity = DynQuantity::<f64>::from_str("0.000123456 kg m^2/(s^3 A)").unwrap();
+    {
+        let formatter = QuantityFormatter::new().significant_digits(3);
+        assert_eq!(
+            formatter.format(&quantity).to_string(),
+            "0.000123 s^-3 m^2 kg A^-1"
+        );
+    }
+    {
+        let formatter = QuantityFormatter::new()
+            .notation(Notation::Scientific)
+            .significant_digits(2)
+            .fraction(true);
+        assert_eq!(
+            formatter.format(&quantity).to_string(),
+            "1.2e-4 m^2 kg/(s^3 A)"
+        );
+    }
+    {
+        let formatter = QuantityFormatter::new()
+            .notation(Notation::Engineering)
+            .precision(2)
+            .separator("")
+            .unit_order(&[BaseUnit::Kilogram, BaseUnit::Meter]);
+        assert_eq!(
+            formatter.format(&quantity).to_string(),
+            "123.46e-6kg m^2 s^-3 A^-1"
+        );
+    }
+}
+
+#[test]
+fn test_significant_digits() {
+    for (value, digits, expected) in [
+        (1234.5, 2, "1200"),
+        (1234.5, 6, "1234.50"),
+        (0.0012345, 3, "0.00123"),
+        (9.999, 3, "10.0"),
+        (-0.5, 1, "-0.5"),
+        (0.0, 3, "0.00"),
+    ] {
+        let quantity = DynQuantity::new(value, Unit::default());
+        let formatter = QuantityFormatter::new().significant_digits(digits);
+        assert_eq!(formatter.format(&quantity).to_string(), expected);
+    }
+}
+
+#[test]
+fn test_engineering_notation() {
+    for (value, expected) in [
+        (1.0, "1e0"),
+        (12345.0, "12.345e3"),
+        (0.00047, "470e-6"),
+        (-1.5e-9, "-1.5e-9"),
+        (999.96, "999.96e0"),
+    ] {
+        let quantity = DynQuantity::new(value, Unit::default());
+        let formatter = QuantityFormatter::new().notation(Notation::Engineering);
+        assert_eq!(formatter.format(&quantity).to_string(), expected);
+    }
+
+    // Rounding can change the exponent
+    let quantity = DynQuantity::new(999.96, Unit::default());
+    let formatter = QuantityFormatter::new()
+        .notation(Notation::Engineering)
+        .precision(1);
+    assert_eq!(formatter.format(&quantity).to_string(), "1.0e3");
+
+    // Fraction without numerator
+    let quantity = DynQuantity::<f64>::from_str("50 Hz").unwrap();
+    let formatter = QuantityFormatter::new().fraction(true);
+    assert_eq!(formatter.format(&quantity).to_string(), "50 1/s");
+}
+
+#[test]
+fn test_named_units() {
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    for (input, expected) in [
+        ("9.81 kg m s^-2", "9.81 N"),
+        ("2 s^-3 m^2 kg A^-1", "2 V"),
+        ("3 kPa", "3000 Pa"),
+        ("4 A s", "4 C"),
+        ("5 mS / m", "0.005 S m^-1"),
+        ("6 W/(m K)", "6 W m^-1 K^-1"),
+        ("7 kg m^2 / s", "7 J s"),
+        ("8 kg/m^3", "8 m^-3 kg"),
+        ("9 m^3/s", "9 s^-1 m^3"),
+        ("2 kg^2 m^2 s^-4", "2 N^2"),
+        ("1 rad", "1"),
+    ] {
+        let quantity = DynQuantity::<f64>::from_str(input).unwrap();
+        assert_eq!(formatter.format(&quantity).to_string(), expected, "{input}");
+    }
+
+    // Named units together with fraction style and unit order
+    let quantity = DynQuantity::<f64>::from_str("0.5 W/(m^2 K)").unwrap();
+    let formatter = QuantityFormatter::new()
+        .named_units(&NamedUnit::ALL)
+        .fraction(true)
+        .unit_order(&[BaseUnit::Kelvin]);
+    assert_eq!(formatter.format(&quantity).to_string(), "0.5 W/(K m^2)");
+
+    // Preference for N m instead of J
+    let quantity = DynQuantity::<f64>::from_str("2 J").unwrap();
+    let formatter = QuantityFormatter::new().named_units(&[NamedUnit::Newton, NamedUnit::Watt]);
+    assert_eq!(formatter.format(&quantity).to_string(), "2 N m");
+
+    // Named units are parsed back into the same quantity
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    for named_unit in NamedUnit::ALL {
+        let quantity = DynQuantity::new(1.5, named_unit.unit());
+        let string = formatter.format(&quantity).to_string();
+        assert_eq!(string, format!("1.5 {}", named_unit.symbol()));
+        assert_eq!(DynQuantity::<f64>::from_str(&string).unwrap(), quantity);
+    }
+}
+
+#[test]
+fn test_si_prefix() {
+    let formatter = QuantityFormatter::new()
+        .si_prefix(true)
+        .named_units(&NamedUnit::ALL);
+    for (input, expected) in [
+        ("0.0012 A", "1.2 mA"),
+        ("4.7e-9 F", "4.7 nF"),
+        ("2.5e12 W", "2.5 TW"),
+        ("47000 Ω", "47 kΩ"),
+        ("2 kg", "2 kg"),
+        ("0.0012 kg", "1.2 g"),
+        ("1200 kg", "1.2 Mg"),
+        ("1.2e-5 m^2", "12 mm^2"),
+        ("0.5 m/s^2", "500 s^-2 mm"),
+        ("0 A", "0 A"),
+        ("50 Hz", "50 s^-1"),
+        ("0.002", "0.002"),
+    ] {
+        let quantity = DynQuantity::<f64>::from_str(input).unwrap();
+        assert_eq!(formatter.format(&quantity).to_string(), expected, "{input}");
+    }
+
+    // Rounding up to the next prefix
+    let quantity = DynQuantity::<f64>::from_str("999.96 m").unwrap();
+    assert_eq!(format!("{:.1}", formatter.format(&quantity)), "1.0 km");
+
+    // Fraction style
+    let quantity = DynQuantity::<f64>::from_str("0.0012 kg/s").unwrap();
+    let fraction = formatter.clone().fraction(true);
+    assert_eq!(fraction.format(&quantity).to_string(), "1.2 g/s");
+
+    // Complex values
+    let quantity = DynQuantity::new(Complex::new(0.003, -0.0004), PredefUnit::ElectricCurrent);
+    assert_eq!(formatter.format(&quantity).to_string(), "(3-0.4i) mA");
+}
+
+#[test]
+fn test_scale_series() {
+    let quantities: Vec<DynQuantity<f64>> = ["0.5 W", "1200 W", "3 kW"]
+        .into_iter()
+        .map(|s| DynQuantity::from_str(s).unwrap())
+        .collect();
+
+    // Without prefix
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    let series = formatter.scale_series(&quantities).unwrap();
+    assert_eq!(series.values(), &[0.5, 1200.0, 3000.0]);
+    assert_eq!(series.label("Power"), "Power / W");
+
+    // With prefix
+    let formatter = formatter.si_prefix(true);
+    let series = formatter.scale_series(&quantities).unwrap();
+    assert_eq!(series.unit(), "kW");
+    assert_eq!(series.into_values(), vec![0.0005, 1.2, 3.0]);
+
+    // Masses
+    let quantities: Vec<DynQuantity<f64>> = ["12 g", "0.5 g"]
+        .into_iter()
+        .map(|s| DynQuantity::from_str(s).unwrap())
+        .collect();
+    let series = formatter.scale_series(&quantities).unwrap();
+    assert_eq!(series.label("Mass"), "Mass / g");
+    assert_eq!(series.values(), &[12.0, 0.5]);
+
+    // Complex values
+    let quantities = [
+        DynQuantity::new(Complex::new(0.0, 0.002), PredefUnit::ElectricVoltage),
+        DynQuantity::new(Complex::new(0.001, 0.0), PredefUnit::ElectricVoltage),
+    ];
+    let series = formatter.scale_series(&quantities).unwrap();
+    assert_eq!(series.unit(), "mV");
+    assert_eq!(series.values()[0], Complex::new(0.0, 2.0));
+
+    // Dimensionless and empty series
+    let quantities = [DynQuantity::new(0.25, Unit::default())];
+    let series = formatter.scale_series(&quantities).unwrap();
+    assert_eq!(series.label("Ratio"), "Ratio");
+    assert_eq!(series.values(), &[0.25]);
+    assert!(
+        formatter
+            .scale_series::<f64>(&[])
+            .unwrap()
+            .values()
+            .is_empty()
+    );
+
+    // Mixed dimensions
+    let quantities = [
+        DynQuantity::new(1.0, PredefUnit::ElectricCurrent),
+        DynQuantity::new(1.0, PredefUnit::ElectricVoltage),
+    ];
+    match formatter.scale_series(&quantities).unwrap_err() {
+        ConversionError::UnitMismatch { expected, found } => {
+            assert_eq!(expected, PredefUnit::ElectricCurrent.into());
+            assert_eq!(found, PredefUnit::ElectricVoltage.into());
+        }
+        _ => panic!("wrong error type"),
+    }
+}
+
+#[test]
+fn test_markup() {
+    let acceleration = DynQuantity::<f64>::from_str("9.81 m/s^2").unwrap();
+    let formatter = QuantityFormatter::new().unit_order(&[BaseUnit::Meter]);
+
+    // Unicode
+    let unicode = formatter.clone().markup(Markup::Unicode);
+    assert_eq!(unicode.format(&acceleration).to_string(), "9.81 m·s⁻²");
+    assert_eq!(
+        unicode
+            .clone()
+            .fraction(true)
+            .format(&acceleration)
+            .to_string(),
+        "9.81 m/s²"
+    );
+    let quantity = DynQuantity::<f64>::from_str("1.5e-12 m^12").unwrap();
+    assert_eq!(
+        unicode
+            .clone()
+            .notation(Notation::Scientific)
+            .format(&quantity)
+            .to_string(),
+        "1.5×10⁻¹² m¹²"
+    );
+
+    // HTML
+    let html = formatter.clone().markup(Markup::Html);
+    assert_eq!(
+        html.format(&acceleration).to_string(),
+        "9.81 m&middot;s<sup>-2</sup>"
+    );
+    let quantity = DynQuantity::new(Complex::new(1500.0, -2.0), PredefUnit::ElectricVoltage);
+    assert_eq!(
+        html.clone()
+            .notation(Notation::Scientific)
+            .named_units(&NamedUnit::ALL)
+            .format(&quantity)
+            .to_string(),
+        "(1.5&times;10<sup>3</sup>-2&times;10<sup>0</sup>i) V"
+    );
+
+    // LaTeX
+    let latex = formatter.markup(Markup::Latex);
+    assert_eq!(
+        latex.format(&acceleration).to_string(),
+        r"\qty{9.81}{\metre\per\second\squared}"
+    );
+    assert_eq!(
+        latex
+            .format(&DynQuantity::new(0.5, Unit::default()))
+            .to_string(),
+        r"\num{0.5}"
+    );
+    let quantity = DynQuantity::new(Complex::new(1.0, 2.0), PredefUnit::ElectricVoltage);
+    let latex = latex.named_units(&NamedUnit::ALL).si_prefix(true);
+    assert_eq!(latex.format(&quantity).to_string(), r"\qty{1+2i}{\volt}");
+    let quantity = DynQuantity::<f64>::from_str("2.5 g/mm^4").unwrap();
+    assert_eq!(
+        latex.format(&quantity).to_string(),
+        r"\qty{2.5}{\per\metre\tothe{4}\tera\gram}"
+    );
+    let quantity = DynQuantity::<f64>::from_str("2.5 kg/mm^4").unwrap();
+    assert_eq!(
+        latex.format(&quantity).to_string(),
+        r"\qty{2.5}{\per\metre\tothe{4}\peta\gram}"
+    );
+    let quantity = DynQuantity::<f64>::from_str("25 kg m^3").unwrap();
+    assert_eq!(
+        latex.format(&quantity).to_string(),
+        r"\qty{25}{\metre\cubed\kilogram}"
+    );
+    let quantity = DynQuantity::<f64>::from_str("2.5 kg m^-3").unwrap();
+    assert_eq!(
+        latex.format(&quantity).to_string(),
+        r"\qty{2.5}{\per\metre\cubed\kilogram}"
+    );
+    assert_eq!(
+        latex.clone().fraction(true).format(&quantity).to_string(),
+        r"\qty{2.5}{\kilogram\per\metre\cubed}"
+    );
+
+    // Units on their own
+    let unit = DynQuantity::<f64>::from_str("1 kg m^2 s^-3 A^-1")
+        .unwrap()
+        .unit;
+    assert_eq!(latex.format_unit(&unit), r"\unit{\volt}");
+    assert_eq!(latex.format_unit(&Unit::default()), "");
+    let html = QuantityFormatter::new().markup(Markup::Html).fraction(true);
+    assert_eq!(
+        html.format_unit(&unit),
+        "m<sup>2</sup>&middot;kg/(s<sup>3</sup>&middot;A)"
+    );
+}
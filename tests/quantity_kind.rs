@@ -0,0 +1,186 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+use uom::si::f64::{AngularVelocity, Energy, Frequency, Torque};
+use uom::si::{angular_velocity::radian_per_second, frequency::hertz, torque::newton_meter};
+
+fn tagged(value: f64, kind: PredefUnit) -> DynQuantity<f64> {
+    return DynQuantity::new(value, kind).with_kind(kind).unwrap();
+}
+
+#[test]
+fn test_with_kind() {
+    let quantity = DynQuantity::new(1.0, PredefUnit::Frequency);
+    assert_eq!(quantity.kind, None);
+    assert_eq!(
+        quantity
+            .with_kind(PredefUnit::AngularVelocity)
+            .unwrap()
+            .kind,
+        Some(PredefUnit::AngularVelocity)
+    );
+    assert_eq!(
+        quantity.with_kind(PredefUnit::Length).unwrap_err(),
+        ConversionError::UnitMismatch {
+            expected: PredefUnit::Length.into(),
+            found: PredefUnit::Frequency.into()
+        }
+    );
+}
+
+#[test]
+fn test_equality() {
+    let untagged = DynQuantity::new(2.0, PredefUnit::Torque);
+    let torque = untagged.with_kind(PredefUnit::Torque).unwrap();
+    let energy = untagged.with_kind(PredefUnit::Energy).unwrap();
+    assert_eq!(torque, untagged.with_kind(PredefUnit::Torque).unwrap());
+    assert_ne!(torque, untagged);
+    assert_ne!(torque, energy);
+}
+
+#[test]
+fn test_try_add_and_sub() {
+    let torque = tagged(2.0, PredefUnit::Torque);
+    let energy = tagged(3.0, PredefUnit::Energy);
+    let untagged = DynQuantity::new(1.0, PredefUnit::Energy);
+
+    assert_eq!(
+        torque.try_add(&energy).unwrap_err(),
+        AdditionError::KindsNotEqual(KindsNotEqual(PredefUnit::Torque, PredefUnit::Energy))
+    );
+    assert_eq!(
+        torque.try_sub(&energy).unwrap_err().to_string(),
+        "kind torque not equal to kind energy"
+    );
+    assert!(matches!(
+        torque.try_add(&DynQuantity::new(1.0, PredefUnit::Force)),
+        Err(AdditionError::UnitsNotEqual(_))
+    ));
+
+    // An untagged quantity can be added to a tagged one
+    let sum = untagged.try_add(&torque).unwrap();
+    assert_eq!(sum.value, 3.0);
+    assert_eq!(sum.kind, Some(PredefUnit::Torque));
+
+    let mut difference = energy;
+    difference.try_sub_assign(&untagged).unwrap();
+    assert_eq!(difference.value, 2.0);
+    assert_eq!(difference.kind, Some(PredefUnit::Energy));
+
+    // A failed assignment does not modify the quantity
+    let mut cpy = torque;
+    assert!(cpy.try_add_assign(&energy).is_err());
+    assert_eq!(cpy, torque);
+}
+
+#[test]
+fn test_arithmetic() {
+    let torque = tagged(2.0, PredefUnit::Torque);
+    let factor = DynQuantity::new(4.0, PredefUnit::None);
+
+    assert_eq!((torque * 2.0).kind, Some(PredefUnit::Torque));
+    assert_eq!((torque / 2.0).kind, Some(PredefUnit::Torque));
+    assert_eq!((torque * factor).kind, Some(PredefUnit::Torque));
+    assert_eq!((factor * torque).kind, Some(PredefUnit::Torque));
+    assert_eq!((torque / factor).kind, Some(PredefUnit::Torque));
+    assert_eq!((factor / torque).kind, None);
+    assert_eq!((torque * torque).kind, None);
+    assert_eq!((torque / torque).kind, None);
+    assert_eq!(torque.powi(1).kind, Some(PredefUnit::Torque));
+    assert_eq!(torque.powi(2).kind, None);
+    assert_eq!(
+        torque.try_nthroot(1).unwrap().kind,
+        Some(PredefUnit::Torque)
+    );
+
+    // A tagged dimensionless quantity changes the kind
+    let angle = tagged(0.5, PredefUnit::Angle);
+    assert_eq!((torque * angle).kind, None);
+
+    // Conversion between real and complex quantities keeps the kind
+    let complex = DynQuantity::<num::Complex<f64>>::from(torque);
+    assert_eq!(complex.kind, Some(PredefUnit::Torque));
+    let real = DynQuantity::<f64>::try_from(complex).unwrap();
+    assert_eq!(real, torque);
+}
+
+#[test]
+fn test_from_str_with_kind() {
+    let quantity =
+        DynQuantity::<f64>::from_str_with_kind("60 rad/s", PredefUnit::AngularVelocity).unwrap();
+    assert_eq!(quantity.value, 60.0);
+    assert_eq!(quantity.kind, Some(PredefUnit::AngularVelocity));
+
+    let error =
+        DynQuantity::<f64>::from_str_with_kind("60 m/s", PredefUnit::Frequency).unwrap_err();
+    assert_eq!(error.span, 0..6);
+    assert_eq!(
+        error.reason,
+        ParseErrorReason::UnitsNotEqual(UnitsNotEqual(
+            PredefUnit::Frequency.into(),
+            PredefUnit::Velocity.into()
+        ))
+    );
+
+    // Parsing without kind yields an untagged quantity
+    assert_eq!(DynQuantity::<f64>::from_str("60 rad/s").unwrap().kind, None);
+}
+
+#[test]
+fn test_uom() {
+    let torque = DynQuantity::<f64>::from(Torque::new::<newton_meter>(2.0));
+    assert_eq!(torque.kind, Some(PredefUnit::Torque));
+    assert_eq!(
+        Energy::try_from(torque).unwrap_err(),
+        ConversionError::KindMismatch {
+            expected: Some(PredefUnit::Energy),
+            found: PredefUnit::Torque
+        }
+    );
+    assert_eq!(Torque::try_from(torque).unwrap().get::<newton_meter>(), 2.0);
+
+    let angular_velocity = DynQuantity::<f64>::from(AngularVelocity::new::<radian_per_second>(3.0));
+    assert_eq!(angular_velocity.kind, Some(PredefUnit::AngularVelocity));
+    assert!(Frequency::try_from(angular_velocity).is_err());
+
+    let frequency = DynQuantity::<f64>::from(Frequency::new::<hertz>(3.0));
+    assert_eq!(frequency.kind, None);
+    assert!(AngularVelocity::try_from(frequency).is_ok());
+    assert!(Frequency::try_from(tagged(3.0, PredefUnit::Frequency)).is_ok());
+    assert!(Frequency::try_from(tagged(3.0, PredefUnit::Radioactivity)).is_err());
+
+    // Kinds are checked when adding quantities converted from uom
+    assert!(torque.try_add(&tagged(1.0, PredefUnit::Energy)).is_err());
+}
+
+#[test]
+fn test_serde() {
+    let torque = tagged(2.0, PredefUnit::Torque);
+    let serialized = serde_yaml::to_string(&torque).unwrap();
+    assert!(serialized.contains("kind: Torque"));
+    let deserialized: DynQuantity<f64> = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, torque);
+
+    // Untagged quantities do not serialize the kind field
+    let energy = DynQuantity::new(2.0, PredefUnit::Energy);
+    let serialized = serde_yaml::to_string(&energy).unwrap();
+    assert!(!serialized.contains("kind"));
+    let deserialized: DynQuantity<f64> = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, energy);
+
+    // The kind must match the unit
+    let yaml = indoc::indoc! {"
+        value: 2.0
+        unit: Length
+        kind: Torque
+    "};
+    assert!(serde_yaml::from_str::<DynQuantity<f64>>(yaml).is_err());
+
+    let yaml = indoc::indoc! {"
+        value: 2.0
+        unit: Energy
+        kind: Energy
+    "};
+    let quantity: DynQuantity<f64> = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(quantity.kind, Some(PredefUnit::Energy));
+}
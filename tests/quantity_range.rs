@@ -0,0 +1,288 @@
+use std::str::FromStr;
+
+use approx::assert_relative_eq;
+use dyn_quantity::*;
+use indoc::indoc;
+
+fn bounds(s: &str) -> (f64, f64, f64) {
+    let range = QuantityRange::from_str(s).unwrap();
+    return (
+        range.lower().value,
+        range.nominal().value,
+        range.upper().value,
+    );
+}
+
+#[test]
+fn test_parse_tolerance() {
+    {
+        let range = QuantityRange::from_str("5 mm ± 0.1 mm").unwrap();
+        assert_eq!(range.nominal().unit, Unit::from(PredefUnit::Length));
+        assert_relative_eq!(range.lower().value, 4.9e-3);
+        assert_relative_eq!(range.nominal().value, 5e-3);
+        assert_relative_eq!(range.upper().value, 5.1e-3);
+    }
+    {
+        let (lower, nominal, upper) = bounds("5 +/- 0.1 mm");
+        assert_relative_eq!(lower, 4.9e-3);
+        assert_relative_eq!(nominal, 5e-3);
+        assert_relative_eq!(upper, 5.1e-3);
+    }
+    {
+        let (lower, _, upper) = bounds("5 mm +- 0.1");
+        assert_relative_eq!(lower, 4.9e-3);
+        assert_relative_eq!(upper, 5.1e-3);
+    }
+    {
+        // The tolerance is a temperature difference
+        let (lower, nominal, upper) = bounds("20 °C ± 2 °C");
+        assert_relative_eq!(lower, 291.15);
+        assert_relative_eq!(nominal, 293.15);
+        assert_relative_eq!(upper, 295.15);
+
+        let (lower, _, upper) = bounds("20 °C ± 2 K");
+        assert_relative_eq!(lower, 291.15);
+        assert_relative_eq!(upper, 295.15);
+    }
+    {
+        let range = QuantityRange::from_str("10 kΩ ±5%").unwrap();
+        assert_eq!(
+            range.nominal().unit,
+            Unit::from(PredefUnit::ElectricResistance)
+        );
+        assert_relative_eq!(range.lower().value, 9500.0);
+        assert_relative_eq!(range.upper().value, 10500.0);
+    }
+    {
+        let (lower, _, upper) = bounds("-10 V ± 10 %");
+        assert_relative_eq!(lower, -11.0);
+        assert_relative_eq!(upper, -9.0);
+    }
+}
+
+#[test]
+fn test_parse_bounds() {
+    {
+        let range = QuantityRange::from_str("10..20 V").unwrap();
+        assert_eq!(
+            range.nominal().unit,
+            Unit::from(PredefUnit::ElectricVoltage)
+        );
+        assert_eq!(bounds("10..20 V"), (10.0, 15.0, 20.0));
+    }
+    assert_eq!(bounds("10 mV..20 mV"), bounds("10..20 mV"));
+    assert_eq!(bounds("20..10 V"), (10.0, 15.0, 20.0));
+    {
+        let (lower, nominal, upper) = bounds("-40 °C to 85 °C");
+        assert_relative_eq!(lower, 233.15);
+        assert_relative_eq!(nominal, 295.65);
+        assert_relative_eq!(upper, 358.15);
+    }
+    {
+        let (lower, _, upper) = bounds("-40 to 85 °C");
+        assert_relative_eq!(lower, 233.15);
+        assert_relative_eq!(upper, 358.15);
+    }
+    assert_eq!(bounds("5 V"), (5.0, 5.0, 5.0));
+}
+
+#[test]
+fn test_parse_errors() {
+    {
+        let error = QuantityRange::from_str("10 V..20 A").unwrap_err();
+        assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    }
+    {
+        let error = QuantityRange::from_str("5 mm ± 0.1 s").unwrap_err();
+        assert!(matches!(error.reason, ParseErrorReason::UnitsNotEqual(_)));
+    }
+    {
+        // The span refers to the entire string
+        let error = QuantityRange::from_str("10 V..20 $").unwrap_err();
+        assert_eq!(error.span, 9..10);
+        assert_eq!(error.substring, "$");
+    }
+    {
+        let error = QuantityRange::from_str("5 ± 0.1 l").unwrap_err();
+        assert_eq!(error.span, 0..1);
+        assert_eq!(error.substring, "5");
+    }
+    {
+        let error = QuantityRange::from_str("5...10 V").unwrap_err();
+        assert_eq!(error.span, 3..4);
+        assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+    }
+    {
+        let error = QuantityRange::from_str("NaN..1").unwrap_err();
+        assert_eq!(error.span, 0..3);
+        assert_eq!(error.reason, ParseErrorReason::CouldNotParse);
+        assert!(QuantityRange::from_str("1 V..NaN V").is_err());
+        assert!(QuantityRange::from_str("5 V ± NaN V").is_err());
+        assert!(QuantityRange::from_str("NaN V").is_err());
+    }
+    {
+        // Empty parts are not completed by the units of the other part
+        let error = QuantityRange::from_str("10 V ±").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InputIsEmpty);
+        assert_eq!(error.span, 7..7);
+
+        let error = QuantityRange::from_str("± 5 V").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InputIsEmpty);
+        assert_eq!(error.span, 0..0);
+
+        let error = QuantityRange::from_str("5 V..").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InputIsEmpty);
+        assert_eq!(error.span, 5..5);
+
+        let error = QuantityRange::from_str("..5 V").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::InputIsEmpty);
+        assert_eq!(error.span, 0..0);
+    }
+    {
+        // Parts without a number are rejected as well
+        let error = QuantityRange::from_str("V..5 V").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::CouldNotParse);
+        assert_eq!(error.span, 0..1);
+        assert_eq!(error.substring, "V");
+
+        let error = QuantityRange::from_str("10 V ± mV").unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::CouldNotParse);
+        assert_eq!(error.span, 8..10);
+    }
+}
+
+#[test]
+fn test_contains() {
+    let range = QuantityRange::from_str("5 mm ± 0.1 mm").unwrap();
+    assert!(range.contains(&DynQuantity::new(5.05e-3, PredefUnit::Length)));
+    assert!(range.contains(&range.lower()));
+    assert!(range.contains(&range.upper()));
+    assert!(!range.contains(&DynQuantity::new(5.2e-3, PredefUnit::Length)));
+    assert!(!range.contains(&DynQuantity::new(5e-3, PredefUnit::Time)));
+
+    // Quantities of a different kind are not contained
+    let range = QuantityRange::from_bounds(
+        DynQuantity::new(1.0, PredefUnit::Torque)
+            .with_kind(PredefUnit::Torque)
+            .unwrap(),
+        DynQuantity::new(2.0, PredefUnit::Torque),
+    )
+    .unwrap();
+    let energy = DynQuantity::new(1.5, PredefUnit::Energy)
+        .with_kind(PredefUnit::Energy)
+        .unwrap();
+    assert!(!range.contains(&energy));
+    assert!(range.contains(&DynQuantity::new(1.5, PredefUnit::Energy)));
+}
+
+#[test]
+fn test_constructors() {
+    let volt = |value| DynQuantity::new(value, PredefUnit::ElectricVoltage);
+    let range = QuantityRange::from_tolerance(volt(10.0), volt(-1.0)).unwrap();
+    assert_eq!(range.lower(), volt(9.0));
+    assert_eq!(range.upper(), volt(11.0));
+
+    assert!(
+        QuantityRange::new(
+            volt(1.0),
+            volt(0.0),
+            DynQuantity::new(2.0, PredefUnit::Time)
+        )
+        .is_err()
+    );
+    assert!(
+        QuantityRange::from_bounds(volt(1.0), DynQuantity::new(2.0, PredefUnit::Time)).is_err()
+    );
+
+    let range = QuantityRange::try_from(volt(3.0)).unwrap();
+    assert_eq!(range.lower(), range.upper());
+}
+
+#[test]
+fn test_constructor_errors() {
+    let volt = |value| DynQuantity::new(value, PredefUnit::ElectricVoltage);
+
+    assert_eq!(
+        QuantityRange::new(volt(f64::NAN), volt(0.0), volt(1.0)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    assert_eq!(
+        QuantityRange::new(volt(0.5), volt(0.0), volt(f64::NAN)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    assert_eq!(
+        QuantityRange::new(volt(2.0), volt(0.0), volt(1.0)).unwrap_err(),
+        RangeError::NominalOutOfBounds
+    );
+    assert_eq!(
+        QuantityRange::new(volt(-1.0), volt(1.0), volt(0.0)).unwrap_err(),
+        RangeError::NominalOutOfBounds
+    );
+    assert_eq!(
+        QuantityRange::try_from(volt(f64::NAN)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    assert_eq!(
+        QuantityRange::from_bounds(volt(f64::NEG_INFINITY), volt(f64::INFINITY)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    assert_eq!(
+        QuantityRange::from_tolerance(volt(f64::INFINITY), volt(f64::INFINITY)).unwrap_err(),
+        RangeError::NotANumber
+    );
+    assert_eq!(
+        QuantityRange::from_relative_tolerance(volt(1.0), f64::NAN).unwrap_err(),
+        RangeError::NotANumber
+    );
+
+    // The midpoint of large bounds does not overflow
+    let range = QuantityRange::from_bounds(volt(f64::MAX), volt(f64::MAX)).unwrap();
+    assert_eq!(range.nominal(), volt(f64::MAX));
+
+    // Parsing reports the same errors
+    let error = QuantityRange::from_str("-inf..inf").unwrap_err();
+    assert_eq!(error.reason, ParseErrorReason::CouldNotParse);
+    assert!(QuantityRange::from_str("inf ± inf").is_err());
+}
+
+#[test]
+fn test_serde() {
+    #[derive(serde::Deserialize, serde::Serialize)]
+    struct Specification {
+        string: QuantityRange,
+        quantity: QuantityRange,
+        native: QuantityRange,
+    }
+
+    let ser = indoc! {"
+    ---
+    string: 10..20 V
+    quantity: 2 A
+    native:
+      nominal: 1 m
+      lower: 0.5 m
+      upper: 1.5 m
+    "};
+    let spec: Specification = serde_yaml::from_str(ser).unwrap();
+    assert_eq!(spec.string.upper().value, 20.0);
+    assert_eq!(
+        spec.quantity.nominal().unit,
+        Unit::from(PredefUnit::ElectricCurrent)
+    );
+    assert_eq!(spec.native.lower().value, 0.5);
+
+    // Round trip of the native representation
+    let ser = serde_yaml::to_string(&spec).unwrap();
+    let de: Specification = serde_yaml::from_str(&ser).unwrap();
+    assert_eq!(de.native, spec.native);
+    assert_eq!(de.string, spec.string);
+
+    // Units of the bounds must match
+    let ser = indoc! {"
+    ---
+    string: 10 V..20 A
+    quantity: 2 A
+    native: 1 m
+    "};
+    assert!(serde_yaml::from_str::<Specification>(ser).is_err());
+}
@@ -0,0 +1,125 @@
+use dyn_quantity::*;
+
+#[test]
+fn test_parse_rkm() {
+    for (string, value) in [
+        ("4k7", 4.7e3),
+        ("R47", 0.47),
+        ("2M2", 2.2e6),
+        ("2n2", 2.2e-9),
+        ("47k", 47e3),
+        ("47R", 47.0),
+        ("4R7", 4.7),
+        ("100n", 100e-9),
+        ("1u5", 1.5e-6),
+        ("1µ5", 1.5e-6),
+        ("m33", 0.33e-3),
+        ("6p8", 6.8e-12),
+        ("1G0", 1e9),
+        ("-4k7", -4.7e3),
+        ("  +4k7  ", 4.7e3),
+    ] {
+        let quantity = DynQuantity::<f64>::from_str_rkm(string).unwrap();
+        assert_eq!(quantity.value, value, "{string}");
+        assert!(quantity.unit.is_dimensionless());
+    }
+}
+
+#[test]
+fn test_parse_rkm_with_unit() {
+    let quantity = DynQuantity::<f64>::from_str_rkm("4k7 Ω").unwrap();
+    assert_eq!(
+        quantity,
+        DynQuantity::new(4.7e3, PredefUnit::ElectricResistance)
+    );
+
+    let quantity = DynQuantity::<f64>::from_str_rkm("4k7Ohm").unwrap();
+    assert_eq!(
+        quantity,
+        DynQuantity::new(4.7e3, PredefUnit::ElectricResistance)
+    );
+
+    let quantity = DynQuantity::<f64>::from_str_rkm("2M2 V/A").unwrap();
+    assert_eq!(
+        quantity,
+        DynQuantity::new(2.2e6, PredefUnit::ElectricResistance)
+    );
+
+    // The span of an error in the unit expression refers to the entire string
+    let error = DynQuantity::<f64>::from_str_rkm("4k7 $").unwrap_err();
+    assert_eq!(error.span, 4..5);
+}
+
+#[test]
+fn test_parse_rkm_fallback() {
+    // Strings which are no RKM code are parsed as usual
+    for string in [
+        "5 ms",
+        "5ms",
+        "47kΩ",
+        "4.7 kV",
+        "100",
+        "1 h 30 min",
+        "2e3 A",
+    ] {
+        assert_eq!(
+            DynQuantity::<f64>::from_str_rkm(string).unwrap(),
+            string.parse::<DynQuantity<f64>>().unwrap(),
+            "{string}"
+        );
+    }
+    assert!(DynQuantity::<f64>::from_str_rkm("4k7.5").is_err());
+    assert!(DynQuantity::<f64>::from_str_rkm("k").is_err());
+    assert!(DynQuantity::<f64>::from_str_rkm("").is_err());
+}
+
+#[test]
+fn test_format_rkm() {
+    for (value, string) in [
+        (4.7e3, "4k7"),
+        (0.47, "R47"),
+        (2.2e6, "2M2"),
+        (2.2e-9, "2n2"),
+        (47e3, "47k"),
+        (47.0, "47R"),
+        (4.7, "4R7"),
+        (100e-9, "100n"),
+        (1.5e-6, "1µ5"),
+        (0.033, "33m"),
+        (1e12, "1T"),
+        (4.7e15, "4700T"),
+        (1e-14, "p01"),
+        (-4.7e3, "-4k7"),
+        (0.0, "0R"),
+    ] {
+        let quantity = DynQuantity::new(value, PredefUnit::ElectricResistance);
+        assert_eq!(quantity.to_rkm().to_string(), string, "{value}");
+    }
+}
+
+#[test]
+fn test_format_rkm_precision() {
+    let rkm = DynQuantity::new(4.7e3, PredefUnit::None).to_rkm();
+    assert_eq!(format!("{rkm:.1}"), "5k");
+    assert_eq!(format!("{rkm:.2}"), "4k7");
+    assert_eq!(format!("{rkm:.4}"), "4k700");
+
+    // Rounding may change the letter
+    let rkm = DynQuantity::new(999.96, PredefUnit::None).to_rkm();
+    assert_eq!(format!("{rkm:.3}"), "1k00");
+    let rkm = DynQuantity::new(0.99996, PredefUnit::None).to_rkm();
+    assert_eq!(format!("{rkm:.2}"), "1R0");
+}
+
+#[test]
+fn test_rkm_roundtrip() {
+    for value in [4.7e3, 0.47, 2.2e-9, 1e9, 68.0, -0.15, 3.3e-12] {
+        let string = DynQuantity::new(value, PredefUnit::None)
+            .to_rkm()
+            .to_string();
+        assert_eq!(
+            DynQuantity::<f64>::from_str_rkm(&string).unwrap().value,
+            value
+        );
+    }
+}
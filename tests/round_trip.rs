@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use dyn_quantity::quantity::F64RealOrComplex;
+use dyn_quantity::*;
+use num::Complex;
+use proptest::prelude::*;
+
+fn unit_strategy() -> impl Strategy<Value = Unit> {
+    return proptest::array::uniform7(-20i32..=20).prop_map(Unit::from);
+}
+
+/// Finite values including subnormals, zeros and extreme exponents as well as
+/// infinities.
+fn value_strategy() -> impl Strategy<Value = f64> {
+    return prop_oneof![
+        proptest::num::f64::NORMAL
+            | proptest::num::f64::SUBNORMAL
+            | proptest::num::f64::ZERO
+            | proptest::num::f64::POSITIVE
+            | proptest::num::f64::NEGATIVE
+            | proptest::num::f64::INFINITE,
+        -1e3..1e3f64,
+    ];
+}
+
+fn assert_round_trip<V: F64RealOrComplex>(quantity: DynQuantity<V>)
+where
+    DynQuantity<V>: FromStr<Err = ParseError>,
+{
+    let string = quantity.to_canonical_string();
+    let parsed = DynQuantity::<V>::from_str(&string)
+        .unwrap_or_else(|err| panic!("could not parse {string}: {err}"));
+    assert_eq!(parsed, quantity, "{string}");
+}
+
+#[test]
+fn test_every_unit() {
+    // Every combination of exponents between -2 and 2
+    let range = -2..=2;
+    for second in range.clone() {
+        for meter in range.clone() {
+            for kilogram in range.clone() {
+                for ampere in range.clone() {
+                    for kelvin in range.clone() {
+                        for mol in range.clone() {
+                            for candela in range.clone() {
+                                let unit = Unit::from([
+                                    second, meter, kilogram, ampere, kelvin, mol, candela,
+                                ]);
+                                assert_round_trip(DynQuantity::new(-1.25e-3, unit));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_special_values() {
+    let unit = Unit::from(PredefUnit::Velocity);
+    for value in [
+        0.0,
+        -0.0,
+        f64::MIN_POSITIVE,
+        5e-324,
+        f64::MAX,
+        f64::MIN,
+        0.1,
+        1.0 / 3.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_round_trip(DynQuantity::new(value, unit));
+        assert_round_trip(DynQuantity::new(value, Unit::default()));
+        assert_round_trip(DynQuantity::new(Complex::new(1.5, value), unit));
+        assert_round_trip(DynQuantity::new(Complex::new(value, -2.5), unit));
+    }
+
+    // Not-a-number values stay not-a-number values
+    let quantity = DynQuantity::new(f64::NAN, unit);
+    assert_eq!(quantity.to_canonical_string(), "NaN s^-1 m");
+    let parsed = DynQuantity::<f64>::from_str(&quantity.to_canonical_string()).unwrap();
+    assert!(parsed.value.is_nan());
+    assert_eq!(parsed.unit, unit);
+
+    let quantity = DynQuantity::new(Complex::new(1.0, f64::NAN), unit);
+    let parsed = DynQuantity::<Complex<f64>>::from_str(&quantity.to_canonical_string()).unwrap();
+    assert!(parsed.value.im.is_nan());
+    assert_eq!(parsed.unit, unit);
+}
+
+#[test]
+fn test_canonical_string() {
+    let quantity = DynQuantity::new(-1500.0, Unit::from([-2, 1, 1, 0, 0, 0, 0]));
+    assert_eq!(quantity.to_canonical_string(), "-1.5e3 s^-2 m kg");
+
+    let quantity = DynQuantity::new(Complex::new(0.0, 2.0), Unit::default());
+    assert_eq!(quantity.to_canonical_string(), "(0e0 + 2e0 i)");
+
+    let quantity = DynQuantity::new(Complex::new(f64::NEG_INFINITY, -1e-7), PredefUnit::Mass);
+    assert_eq!(quantity.to_canonical_string(), "(-inf - 1e-7 i) kg");
+
+    let quantity = DynQuantity::new(Complex::new(3.0, 0.0), PredefUnit::LuminousIntensity);
+    assert_eq!(quantity.to_canonical_string(), "3e0 cd");
+}
+
+#[test]
+fn test_kind_is_dropped() {
+    let torque = DynQuantity::new(-2.5, PredefUnit::Torque)
+        .with_kind(PredefUnit::Torque)
+        .unwrap();
+    let string = torque.to_canonical_string();
+    assert_eq!(string, "-2.5e0 s^-2 m^2 kg");
+
+    let parsed = DynQuantity::<f64>::from_str(&string).unwrap();
+    assert_eq!(parsed.kind, None);
+    assert_ne!(parsed, torque);
+    assert_eq!(parsed.with_kind(PredefUnit::Torque).unwrap(), torque);
+}
+
+proptest! {
+    #[test]
+    fn test_round_trip_real(value in value_strategy(), unit in unit_strategy()) {
+        assert_round_trip(DynQuantity::new(value, unit));
+    }
+
+    #[test]
+    fn test_round_trip_complex(
+        re in value_strategy(),
+        im in value_strategy(),
+        unit in unit_strategy()
+    ) {
+        assert_round_trip(DynQuantity::new(Complex::new(re, im), unit));
+    }
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+use num::Complex;
+
+#[test]
+fn test_from_str() {
+    {
+        let unit = ScaledUnit::from_str("kN/mm^2").unwrap();
+        assert_eq!(unit.unit, NamedUnit::Pascal.unit());
+        approx::assert_abs_diff_eq!(unit.factor, 1e9, epsilon = 1e-3);
+        assert_eq!(unit.offset, 0.0);
+    }
+    {
+        let unit = ScaledUnit::from_str("100 km").unwrap();
+        assert_eq!(unit.unit, PredefUnit::Length.into());
+        assert_eq!(unit.factor, 1e5);
+    }
+    {
+        let unit = ScaledUnit::from_str("1/h").unwrap();
+        assert_eq!(unit.unit, PredefUnit::Frequency.into());
+        approx::assert_abs_diff_eq!(unit.factor, 1.0 / 3600.0, epsilon = 1e-15);
+    }
+    {
+        let unit = ScaledUnit::from_str("°C").unwrap();
+        assert_eq!(unit.unit, PredefUnit::Temperature.into());
+        assert_eq!(unit.factor, 1.0);
+        assert_eq!(unit.offset, 273.15);
+    }
+    {
+        let unit = ScaledUnit::from_str("").unwrap_err();
+        assert_eq!(unit.reason, ParseErrorReason::InputIsEmpty);
+    }
+    {
+        // Offsets within a compound unit are ambiguous
+        let error = ScaledUnit::from_str("°C/s").unwrap_err();
+        assert_eq!(error.span, 0..3);
+        assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+    }
+    {
+        // Complex factors are not supported
+        assert!(ScaledUnit::from_str("2j V").is_err());
+    }
+    {
+        // The offset of prefixed units or powers of units is not defined
+        for input in ["°C^2", "k°C", "m°C^-1"] {
+            let error = ScaledUnit::from_str(input).unwrap_err();
+            assert_eq!(error.span, 0..input.len());
+            assert_eq!(error.reason, ParseErrorReason::UnexpectedToken);
+        }
+    }
+}
+
+#[test]
+fn test_conversion() {
+    let unit = ScaledUnit::from_str("mm/min").unwrap();
+    let quantity = unit.quantity(600.0);
+    assert_eq!(quantity.unit, PredefUnit::Velocity.into());
+    approx::assert_abs_diff_eq!(quantity.value, 0.01, epsilon = 1e-15);
+    approx::assert_abs_diff_eq!(unit.value_of(&quantity).unwrap(), 600.0, epsilon = 1e-9);
+
+    let quantity = unit.quantity(Complex::new(60.0, -120.0));
+    let value = unit.value_of(&quantity).unwrap();
+    approx::assert_abs_diff_eq!(value.re, 60.0, epsilon = 1e-9);
+    approx::assert_abs_diff_eq!(value.im, -120.0, epsilon = 1e-9);
+
+    let celsius = ScaledUnit::from_str("°C").unwrap();
+    let quantity = celsius.quantity(-273.15);
+    assert_eq!(quantity.value, 0.0);
+    approx::assert_abs_diff_eq!(
+        celsius
+            .value_of(&DynQuantity::new(300.0, PredefUnit::Temperature))
+            .unwrap(),
+        26.85,
+        epsilon = 1e-12
+    );
+
+    match unit
+        .value_of(&DynQuantity::new(1.0, PredefUnit::Length))
+        .unwrap_err()
+    {
+        ConversionError::UnitMismatch { expected, found } => {
+            assert_eq!(expected, PredefUnit::Velocity.into());
+            assert_eq!(found, PredefUnit::Length.into());
+        }
+        _ => panic!("wrong error type"),
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    let kilonewton = ScaledUnit::from_str("kN").unwrap();
+    let millimeter = ScaledUnit::from_str("mm").unwrap();
+
+    let torque = kilonewton * millimeter;
+    assert_eq!(torque.unit, PredefUnit::Torque.into());
+    approx::assert_abs_diff_eq!(torque.factor, 1.0, epsilon = 1e-12);
+
+    let mut stress = kilonewton / millimeter.powi(2);
+    assert_eq!(stress, ScaledUnit::from_str("kN/mm^2").unwrap());
+    stress *= millimeter;
+    stress /= kilonewton;
+    assert_eq!(stress.unit, Unit::from(PredefUnit::Length).powi(-1));
+    approx::assert_abs_diff_eq!(stress.factor, 1e3, epsilon = 1e-9);
+
+    // The offset is dropped when combining units
+    let rate = ScaledUnit::from_str("°C").unwrap() / ScaledUnit::from_str("min").unwrap();
+    assert_eq!(rate.offset, 0.0);
+    approx::assert_abs_diff_eq!(rate.factor, 1.0 / 60.0, epsilon = 1e-15);
+
+    assert_eq!(ScaledUnit::default(), ScaledUnit::from(Unit::default()));
+    assert_eq!(
+        ScaledUnit::from(PredefUnit::Power),
+        ScaledUnit::new(1.0, PredefUnit::Power)
+    );
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use dyn_quantity::*;
+use num::Complex;
+
+fn unit_token(s: &str) -> UnitToken {
+    let mut tokens: Vec<_> = tokenize(s).collect();
+    assert_eq!(tokens.len(), 1);
+    match tokens.remove(0).0 {
+        TokenKind::Unit(unit) => return unit,
+        other => panic!("expected a unit, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_tokenize_kinds() {
+    let kinds: Vec<_> = tokenize("-2.5 + 3i * (4 / Inf)^2 e3 %")
+        .map(|(kind, _)| kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Operator(Operator::Sub),
+            TokenKind::Number(Complex::new(2.5, 0.0)),
+            TokenKind::Operator(Operator::Add),
+            TokenKind::Number(Complex::new(0.0, 3.0)),
+            TokenKind::Operator(Operator::Mul),
+            TokenKind::Bracket(Bracket::Open),
+            TokenKind::Number(Complex::new(4.0, 0.0)),
+            TokenKind::Operator(Operator::Div),
+            TokenKind::Number(Complex::new(f64::INFINITY, 0.0)),
+            TokenKind::Bracket(Bracket::Close { exponent: 2 }),
+            TokenKind::Number(Complex::new(1000.0, 0.0)),
+            TokenKind::Operator(Operator::Percent),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_spans() {
+    let input = "1 kN*m / $ms";
+    let tokens: Vec<_> = tokenize(input).collect();
+    let substrings: Vec<_> = tokens
+        .iter()
+        .map(|(_, span)| &input[span.clone()])
+        .collect();
+    assert_eq!(substrings, vec!["1", "kN", "*", "m", "/", "$", "ms"]);
+    assert_eq!(tokens[5].0, TokenKind::Invalid);
+
+    // Units with an offset can neither be prefixed nor raised to a power
+    let tokens: Vec<_> = tokenize("°C k°C °C^2").map(|(kind, _)| kind).collect();
+    assert!(matches!(tokens[0], TokenKind::Unit(_)));
+    assert_eq!(tokens[1..], [TokenKind::Invalid, TokenKind::Invalid]);
+}
+
+#[test]
+fn test_tokenize_units() {
+    {
+        let unit = unit_token("mV");
+        assert_eq!(unit.symbol, "V");
+        assert_eq!(unit.prefix, -3);
+        assert_eq!(unit.exponent, 1);
+        assert_eq!(unit.unit, Unit::from(PredefUnit::ElectricVoltage));
+        assert_eq!(unit.factor, 1e-3);
+        assert_eq!(unit.offset, 0.0);
+    }
+    {
+        let unit = unit_token("kg");
+        assert_eq!(unit.symbol, "g");
+        assert_eq!(unit.prefix, 3);
+        assert_eq!(unit.unit, Unit::from(PredefUnit::Mass));
+        assert_eq!(unit.factor, 1.0);
+    }
+    {
+        let unit = unit_token("ms^-2");
+        assert_eq!(unit.symbol, "s");
+        assert_eq!(unit.exponent, -2);
+        assert_eq!(unit.unit, Unit::from(PredefUnit::Time).powi(-2));
+        assert_eq!(unit.factor, 1e6);
+    }
+    {
+        let unit = unit_token("rpm");
+        assert_eq!(unit.unit, Unit::from(PredefUnit::AngularVelocity));
+        assert_eq!(unit.factor, 1.0 / 60.0);
+    }
+    {
+        let unit = unit_token("°C");
+        assert_eq!(unit.symbol, "°C");
+        assert_eq!(unit.unit, Unit::from(PredefUnit::Temperature));
+        assert_eq!(unit.offset, 273.15);
+    }
+    {
+        // Both spellings of ohm share the same symbol
+        assert_eq!(unit_token("Ohm"), unit_token("Ω"));
+    }
+}
+
+#[test]
+fn test_tokenize_matches_parser() {
+    // The SI breakdown of a unit token must match the result of the parser
+    for input in [
+        "s", "km", "mg", "kA", "K", "mol", "cd", "MV", "N", "kNm", "W", "GJ", "kHz", "rpm", "Wb",
+        "mT", "H", "S", "t", "kOhm", "deg", "rad", "mm^2", "ms^-3", "ut^2",
+    ] {
+        let unit = unit_token(input);
+        let quantity = DynQuantity::<f64>::from_str(input).unwrap();
+        assert_eq!(quantity.unit, unit.unit, "{input}");
+        approx::assert_relative_eq!(quantity.value, unit.factor, max_relative = 1e-12);
+    }
+}
@@ -1,4 +1,6 @@
-use dyn_quantity::{PredefUnit, Unit};
+use std::str::FromStr;
+
+use dyn_quantity::{NamedUnit, ParseErrorReason, PredefUnit, QuantityFormatter, Unit};
 
 #[test]
 fn test_multiplication() {
@@ -50,3 +52,172 @@ fn test_deserialize_from_common_units() {
         assert_eq!(unit.field.ampere, 1);
     }
 }
+
+#[test]
+fn test_deserialize_from_array_and_string() {
+    #[derive(Debug, serde::Deserialize)]
+    struct UnitWrapper {
+        field: Unit,
+    }
+    {
+        let str = "---\nfield: [-3, 2, 1, -1, 0, 0, 0]";
+        let unit: UnitWrapper = serde_yaml::from_str(str).unwrap();
+        assert_eq!(unit.field, PredefUnit::ElectricVoltage.into());
+    }
+    {
+        let str = "---\nfield: V/m";
+        let unit: UnitWrapper = serde_yaml::from_str(str).unwrap();
+        assert_eq!(
+            unit.field,
+            Unit::from(PredefUnit::ElectricVoltage) / PredefUnit::Length.into()
+        );
+    }
+    {
+        let str = "---\nfield: kg m^2 s^-3";
+        let unit: UnitWrapper = serde_yaml::from_str(str).unwrap();
+        assert_eq!(unit.field, PredefUnit::Power.into());
+    }
+    {
+        let str = "---\nfield: mm";
+        assert!(serde_yaml::from_str::<UnitWrapper>(str).is_err());
+    }
+    {
+        let str = "---\nfield: [1, 2, 3]";
+        assert!(serde_yaml::from_str::<UnitWrapper>(str).is_err());
+    }
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Unit::from_str("N").unwrap(), PredefUnit::Force.into());
+    assert_eq!(Unit::from_str("N m").unwrap(), PredefUnit::Torque.into());
+    assert_eq!(
+        Unit::from_str("Wb/m^2").unwrap(),
+        PredefUnit::MagneticFluxDensity.into()
+    );
+    assert_eq!(Unit::from_str("1/s").unwrap(), PredefUnit::Frequency.into());
+    assert_eq!(Unit::from_str("1").unwrap(), Unit::default());
+    assert_eq!(
+        Unit::from_str("mm/km * Mm").unwrap(),
+        PredefUnit::Length.into()
+    );
+    assert_eq!(Unit::from_str("kg").unwrap(), PredefUnit::Mass.into());
+
+    for s in ["mm", "2 m", "%", "°C", "g", "h", "NaN m", "inf"] {
+        let error = Unit::from_str(s).unwrap_err();
+        assert_eq!(error.reason, ParseErrorReason::UnitWithScaleFactor, "{s}");
+        assert_eq!(error.span, 0..s.len());
+    }
+    assert_eq!(
+        Unit::from_str("").unwrap_err().reason,
+        ParseErrorReason::InputIsEmpty
+    );
+    assert_eq!(
+        Unit::from_str("m^").unwrap_err().reason,
+        ParseErrorReason::InvalidExponent
+    );
+}
+
+#[test]
+fn test_decompose() {
+    {
+        let unit: Unit = PredefUnit::ElectricResistivity.into();
+        let (named, residual) = unit.decompose(&NamedUnit::ALL);
+        assert_eq!(named, vec![(NamedUnit::Ohm, 1)]);
+        assert_eq!(residual, PredefUnit::Length.into());
+    }
+    {
+        // Siemens is preferred over the inverse of ohm
+        let unit: Unit = PredefUnit::ElectricConductance.into();
+        let (named, residual) = unit.decompose(&NamedUnit::ALL);
+        assert_eq!(named, vec![(NamedUnit::Siemens, 1)]);
+        assert!(residual.is_dimensionless());
+
+        // ... unless siemens is not available
+        let (named, _) = unit.decompose(&[NamedUnit::Ohm]);
+        assert_eq!(named, vec![(NamedUnit::Ohm, -1)]);
+    }
+    {
+        // Base units are kept if named units do not simplify the unit
+        let unit: Unit = PredefUnit::MagneticFieldStrength.into();
+        let (named, residual) = unit.decompose(&NamedUnit::ALL);
+        assert!(named.is_empty());
+        assert_eq!(residual, unit);
+    }
+    {
+        let unit = Unit::default();
+        let (named, residual) = unit.decompose(&NamedUnit::ALL);
+        assert!(named.is_empty());
+        assert!(residual.is_dimensionless());
+    }
+}
+
+#[test]
+fn test_display() {
+    // Display writes base units only, named units are opt-in
+    assert_eq!(
+        Unit::from(PredefUnit::Force).to_string(),
+        "s^-2 m^1 kg^1 A^0 K^0 mol^0 cd^0"
+    );
+    assert_eq!(
+        Unit::from(PredefUnit::None).to_string(),
+        "s^0 m^0 kg^0 A^0 K^0 mol^0 cd^0"
+    );
+
+    let formatter = QuantityFormatter::new().named_units(&NamedUnit::ALL);
+    assert_eq!(formatter.format_unit(&PredefUnit::Force.into()), "N");
+    assert_eq!(formatter.format_unit(&PredefUnit::Torque.into()), "J");
+    assert_eq!(formatter.format_unit(&PredefUnit::Inductance.into()), "H");
+    assert_eq!(formatter.format_unit(&PredefUnit::Area.into()), "m^2");
+    assert_eq!(
+        formatter.format_unit(&PredefUnit::AngularVelocity.into()),
+        "s^-1"
+    );
+    assert_eq!(
+        formatter.format_unit(&Unit::from([0, -3, 1, 0, 0, 0, 0])),
+        "m^-3 kg"
+    );
+    assert_eq!(formatter.format_unit(&PredefUnit::None.into()), "");
+}
+
+#[test]
+fn test_predef_candidates() {
+    // Every variant is contained in the candidates of its own unit
+    for predef_unit in PredefUnit::ALL {
+        assert!(PredefUnit::candidates(predef_unit.into()).contains(&predef_unit));
+    }
+
+    assert_eq!(
+        PredefUnit::candidates(Unit::default()),
+        vec![PredefUnit::None, PredefUnit::Angle, PredefUnit::SolidAngle]
+    );
+    assert_eq!(
+        PredefUnit::candidates(PredefUnit::Torque.into()),
+        vec![PredefUnit::Torque, PredefUnit::Energy]
+    );
+    assert_eq!(
+        PredefUnit::candidates(PredefUnit::ThermalConductivity.into()),
+        vec![PredefUnit::ThermalConductivity]
+    );
+    assert_eq!(
+        PredefUnit::candidates(Unit::from([1, 2, 3, 4, 5, 6, 7])),
+        vec![]
+    );
+
+    // Names and symbols
+    assert_eq!(PredefUnit::None.name(), "dimensionless");
+    assert_eq!(PredefUnit::None.symbol(), "1");
+    assert_eq!(PredefUnit::Pressure.name(), "pressure");
+    assert_eq!(PredefUnit::Pressure.symbol(), "Pa");
+    assert_eq!(PredefUnit::ElectricCharge.symbol(), "C");
+    assert_eq!(PredefUnit::Capacitance.symbol(), "F");
+    assert_eq!(PredefUnit::MassDensity.symbol(), "kg/m^3");
+    assert_eq!(PredefUnit::DynamicViscosity.name(), "dynamic viscosity");
+    assert_eq!(PredefUnit::ThermalConductivity.symbol(), "W/(m K)");
+
+    // Names are unique
+    let mut names: Vec<_> = PredefUnit::ALL.iter().map(PredefUnit::name).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), PredefUnit::ALL.len());
+}
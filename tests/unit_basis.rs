@@ -0,0 +1,146 @@
+use dyn_quantity::*;
+use num::rational::Ratio;
+use proptest::prelude::*;
+
+fn integers<const N: usize>(exponents: [i32; N]) -> Vec<Ratio<i32>> {
+    return exponents.map(Ratio::from_integer).to_vec();
+}
+
+fn electrical_basis() -> UnitBasis {
+    return UnitBasis::new([
+        NamedUnit::Volt.unit(),
+        PredefUnit::ElectricCurrent.into(),
+        PredefUnit::Time.into(),
+        PredefUnit::Length.into(),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn test_express() {
+    let basis = electrical_basis();
+    assert_eq!(basis.units().len(), 4);
+    assert_eq!(
+        basis.express(PredefUnit::ElectricResistance).unwrap(),
+        integers([1, -1, 0, 0])
+    );
+    assert_eq!(
+        basis.express(PredefUnit::Energy).unwrap(),
+        integers([1, 1, 1, 0])
+    );
+    assert_eq!(
+        basis.express(PredefUnit::Force).unwrap(),
+        integers([1, 1, 1, -1])
+    );
+    assert_eq!(
+        basis.express(PredefUnit::Capacitance).unwrap(),
+        integers([-1, 1, 1, 0])
+    );
+    assert_eq!(
+        basis.express(PredefUnit::None).unwrap(),
+        integers([0, 0, 0, 0])
+    );
+    assert_eq!(
+        basis.express(PredefUnit::ThermalConductivity).unwrap_err(),
+        BasisError::NotRepresentable {
+            unit: PredefUnit::ThermalConductivity.into()
+        }
+    );
+}
+
+#[test]
+fn test_rational_exponents() {
+    let basis = UnitBasis::new([PredefUnit::Volume, PredefUnit::Time]).unwrap();
+    assert_eq!(
+        basis.express(PredefUnit::Area).unwrap(),
+        vec![Ratio::new(2, 3), Ratio::from_integer(0)]
+    );
+    assert!(basis.express(PredefUnit::Mass).is_err());
+
+    let basis =
+        UnitBasis::new([Unit::from([2, 0, 0, 0, 0, 0, 0]), NamedUnit::Farad.unit()]).unwrap();
+    assert_eq!(
+        basis.express(PredefUnit::Time).unwrap(),
+        vec![Ratio::new(1, 2), Ratio::from_integer(0)]
+    );
+}
+
+#[test]
+fn test_linearly_dependent() {
+    assert_eq!(
+        UnitBasis::new([PredefUnit::Length, PredefUnit::None]).unwrap_err(),
+        BasisError::LinearlyDependent {
+            index: 1,
+            unit: Unit::default()
+        }
+    );
+    assert_eq!(
+        UnitBasis::new([PredefUnit::Length, PredefUnit::Area]).unwrap_err(),
+        BasisError::LinearlyDependent {
+            index: 1,
+            unit: PredefUnit::Area.into()
+        }
+    );
+    assert!(UnitBasis::new([PredefUnit::Force, PredefUnit::Torque, PredefUnit::Length]).is_err());
+
+    // An empty basis can only express dimensionless units
+    let basis = UnitBasis::new(Vec::<Unit>::new()).unwrap();
+    assert_eq!(basis.express(PredefUnit::None).unwrap(), vec![]);
+    assert!(basis.express(PredefUnit::Length).is_err());
+}
+
+#[test]
+fn test_large_exponents() {
+    // m s and m s^-1
+    let basis = UnitBasis::new([
+        Unit::from([1, 1, 0, 0, 0, 0, 0]),
+        Unit::from([-1, 1, 0, 0, 0, 0, 0]),
+    ])
+    .unwrap();
+
+    // The intermediate results exceed i32, but the exponents do not
+    let unit = Unit::from([i32::MAX, i32::MAX, 0, 0, 0, 0, 0]);
+    assert_eq!(basis.express(unit).unwrap(), integers([i32::MAX, 0]));
+
+    // (m s)^((2^32 - 3) / 2) (m s^-1)^(1 / 2)
+    let unit = Unit::from([i32::MAX - 1, i32::MAX, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        basis.express(unit).unwrap_err(),
+        BasisError::ExponentOutOfRange
+    );
+
+    // Intermediate results which do not fit into i64
+    let max = i32::MAX;
+    let units = [
+        Unit::from([max, max - 1, 3, 0, 0, 0, 0]),
+        Unit::from([max - 2, 5, max - 3, 0, 0, 0, 0]),
+        Unit::from([7, max - 4, max - 5, 0, 0, 0, 0]),
+    ];
+    assert_eq!(
+        UnitBasis::new(units).unwrap_err(),
+        BasisError::ExponentOutOfRange
+    );
+}
+
+#[test]
+fn test_display_error() {
+    let error = electrical_basis()
+        .express(PredefUnit::Temperature)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unit s^0 m^0 kg^0 A^0 K^1 mol^0 cd^0 cannot be expressed in the basis"
+    );
+}
+
+proptest! {
+    #[test]
+    fn express_products_of_basis_units(exponents in prop::array::uniform4(-4i32..=4)) {
+        let basis = electrical_basis();
+        let mut unit = Unit::default();
+        for (basis_unit, exponent) in basis.units().iter().zip(exponents) {
+            unit *= basis_unit.powi(exponent);
+        }
+        prop_assert_eq!(basis.express(unit).unwrap(), integers(exponents));
+    }
+}